@@ -0,0 +1,36 @@
+//! Rendering six indicators over 5,000 candles, run with and without `--features rayon` to compare working out the extensions in parallel
+
+#[macro_use]
+extern crate bencher;
+extern crate ohlc;
+
+use bencher::Bencher;
+
+use ohlc::{OHLCRenderOptions, Renderer};
+use ohlc::model::data::OHLC;
+use ohlc::model::rex::{BollingerBands, DEMA, EMA};
+
+fn candles(count: usize) -> Vec<OHLC> {
+	(0..count).map(|i| {
+		let (o, c) = (6000. + 500. * (i as f64 / 200.).sin(), 6000. + 500. * ((i + 1) as f64 / 200.).sin());
+		OHLC { o, h: o.max(c) + 10., l: o.min(c) - 10., c, t: None, v: None }
+	}).collect()
+}
+
+fn six_indicators(bench: &mut Bencher) {
+	let data = candles(5000);
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF))
+		.add_extension(EMA::new(50, 0.1, 0x00FF00FF))
+		.add_extension(EMA::new(200, 0.1, 0x0000FFFF).smooth(Some(0.)))
+		.add_extension(DEMA::new(EMA::new(50, 0.1, 0xFFFF00FF)))
+		.add_extension(BollingerBands::new(20, 2, 0xFF00FFFF))
+		.add_extension(BollingerBands::new(100, 2, 0x00FFFFFF));
+
+	let mut renderer = Renderer::new(options);
+	bench.iter(|| renderer.render(&data).unwrap().len());
+}
+
+benchmark_group!(extensions, six_indicators);
+benchmark_main!(extensions);
@@ -5,7 +5,10 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
-use image::GenericImage;
+use image::{DynamicImage, GenericImage};
+
+/// Characters in the last row of the extended table, the final one is the replacement for anything the font doesn't have
+const EXTRA_CHARS: &str = "€₿₹₽₩₺₫₴₦₪→←↑↓…□";
 
 fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
@@ -29,24 +32,52 @@ fn main() {
     for base_y in 2..8 {
         for base_x in 0..16 {
             if (base_y == 7 && base_x == 15) || (base_y == 2 && base_x == 0) { continue; }
-            output += "\t[\n";
-            // Write character into array.
-            for ptr_y in 0..17 {
-                output += "\t\t";
-                for ptr_x in 0..10 {
-                    let x = (base_x * 20) + 10 + ptr_x;
-                    let y = (base_y * 18) + ptr_y;
-
-                    output += &format!("{},{}", 255 - img.get_pixel(x, y).data[0], if ptr_x != 9 { " " } else { "" });
-                }
-                output += "\n";
-            }
-            output += "\t],\n";
+            output += &glyph(&img, base_x, base_y);
+        }
+    }
+
+    output += "];\n\n";
+
+    // Rasterised from DejaVu Sans Mono on the same grid as the ascii table, the bitcoin sign is composed from a B.
+    // Rows 0 - 5 are U+00A0 - U+00FF, row 6 are the extra characters
+    let img = image::open(Path::new("build/dejavu-sans-mono-latin1-table.png")).unwrap();
+
+    output += "pub const LATIN1_TABLE: [[u8; 170]; 96] = [\n";
+    for base_y in 0..6 {
+        for base_x in 0..16 {
+            output += &glyph(&img, base_x, base_y);
         }
     }
+    output += "];\n\n";
+
+    let extra_count = EXTRA_CHARS.chars().count();
 
+    output += &format!("pub const EXTRA_CHARS: [char; {}] = {:?};\n\n", extra_count, EXTRA_CHARS.chars().collect::<Vec<char>>());
+    output += &format!("pub const EXTRA_TABLE: [[u8; 170]; {}] = [\n", extra_count);
+    for i in 0..extra_count as u32 {
+        output += &glyph(&img, i % 16, 6 + i / 16);
+    }
     output += "];";
 
     let mut f = File::create(&Path::new(&out_dir).join("fonts.rs")).unwrap();
     let _ = f.write_all(output.as_bytes());
-}
\ No newline at end of file
+}
+
+/// Writes out the character in the cell of the table as an array
+fn glyph(img: &DynamicImage, base_x: u32, base_y: u32) -> String {
+    let mut output = "\t[\n".to_string();
+
+    // Write character into array.
+    for ptr_y in 0..17 {
+        output += "\t\t";
+        for ptr_x in 0..10 {
+            let x = (base_x * 20) + 10 + ptr_x;
+            let y = (base_y * 18) + ptr_y;
+
+            output += &format!("{},{}", 255 - img.get_pixel(x, y).data[0], if ptr_x != 9 { " " } else { "" });
+        }
+        output += "\n";
+    }
+
+    output + "\t],\n"
+}
@@ -0,0 +1,61 @@
+//! Renders a dashboard of the price with Bollinger Bands and an EMA, with volume, RSI and MACD panels stacked under it
+//!
+//! `cargo run --example dashboard -- examples/sample_data.csv dashboard.png`
+//!
+//! Each panel is an extension strip, they're stacked under the chart in the order the extensions are added.
+
+extern crate ohlc;
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+use ohlc::{read_csv, OHLCRenderOptions};
+use ohlc::model::FrameKind;
+use ohlc::model::data::OHLC;
+use ohlc::model::rex::{BollingerBands, ColourMode, EMA, MACD, RSI, Volume};
+
+const BACKGROUND: u32 = 0x1E2126FF;
+const TEXT: u32 = 0xD0D4DCFF;
+const UP: u32 = 0x26A69AFF;
+const DOWN: u32 = 0xEF5350FF;
+
+fn main() {
+	let args: Vec<String> = env::args().skip(1).collect();
+	let input = Path::new(args.first().map_or("examples/sample_data.csv", String::as_str));
+	let output = Path::new(args.get(1).map_or("dashboard.png", String::as_str));
+
+	let data = match read_csv(input) {
+		Ok(data) => data,
+		Err(err) => {
+			eprintln!("{}", err);
+			process::exit(1);
+		}
+	};
+
+	let mut options: OHLCRenderOptions<OHLC> = OHLCRenderOptions::new();
+	options.title("BTC", TEXT)
+		.append_title("USD", 0x8A8F98FF)
+		.subtitle("1h | sample data", 0x8A8F98FF)
+		.background_colour(BACKGROUND)
+		.plot_background_colour(0x23272EFF)
+		.line(0x3A3F47FF, 200., 24)
+		.start_time(data.first().and_then(|candle| candle.t))
+		.indicator_colours(0x2962FFFF, DOWN, UP)
+		.frame(FrameKind::Canvas, 0x3A3F47FF, 1)
+		// Overlays on the price
+		.add_extension(BollingerBands::new(20, 2, 0x5C6BC0FF))
+		.add_extension(EMA::new(50, 0.04, 0xFFB74DFF))
+		// Panels under it, from top to bottom
+		.add_extension(Volume::new(TEXT, UP, DOWN, 0x8A8F98FF).direction_colours(UP, DOWN, 0x8A8F98FF, ColourMode::PreviousClose))
+		.add_extension(RSI::new(TEXT, 0xBA68C8FF, UP, DOWN))
+		.add_extension(MACD::new(0x2962FFFF, 0xFF6D00FF, 0x8A8F98FF, TEXT, 0.1));
+
+	match options.render_and_save(data, output) {
+		Ok(()) => println!("Saved the dashboard to {}", output.display()),
+		Err(err) => {
+			eprintln!("{}", err);
+			process::exit(1);
+		}
+	}
+}
@@ -0,0 +1,48 @@
+//! Renders a chart with Bollinger Bands and an EMA from a CSV file of candles
+//!
+//! `cargo run --example from_csv -- examples/sample_data.csv chart.png`
+
+extern crate ohlc;
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+use ohlc::{read_csv, OHLCRenderOptions};
+use ohlc::model::data::OHLC;
+use ohlc::model::rex::{BollingerBands, EMA};
+
+fn main() {
+	let args: Vec<String> = env::args().skip(1).collect();
+	if args.is_empty() || args.len() > 2 {
+		eprintln!("Usage: from_csv <input.csv> [output.png]");
+		process::exit(2);
+	}
+
+	let input = Path::new(&args[0]);
+	let output = Path::new(args.get(1).map_or("chart.png", String::as_str));
+
+	let data = match read_csv(input) {
+		Ok(data) => data,
+		Err(err) => {
+			eprintln!("{}", err);
+			process::exit(1);
+		}
+	};
+
+	let mut options: OHLCRenderOptions<OHLC> = OHLCRenderOptions::new();
+	options.title(&format!("{} | ohlc-rs", input.display()), 0xFFFFFFFF)
+		.background_colour(0x36393EFF)
+		.line(0xCCCCCCFF, 200., 24)
+		.start_time(data.first().and_then(|candle| candle.t))
+		.add_extension(BollingerBands::new(20, 2, 0x00AAAAFF))
+		.add_extension(EMA::new(50, 0.04, 0xFF7F00FF));
+
+	match options.render_and_save(data, output) {
+		Ok(()) => println!("Saved the chart to {}", output.display()),
+		Err(err) => {
+			eprintln!("{}", err);
+			process::exit(1);
+		}
+	}
+}
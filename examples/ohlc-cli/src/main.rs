@@ -64,8 +64,7 @@ fn main() {
         ohlc.add_extension(MACD::new(0x00FF00FF, 0xFF0000FF, 0x7F9F00FF, 0xFFFFFFFF, 0.1));
     }
 
-    ohlc.render(get_data(&options.input), |p| fs::rename(p, &options.output)
-        .map_err(|err| format!("{:?}", err))).unwrap().unwrap();
+    ohlc.render(get_data(&options.input), |p| fs::rename(p, &options.output)).unwrap();
 }
 
 fn get_data(path: &str) -> Vec<OHLC> {
@@ -0,0 +1,72 @@
+//! Keeps the PNGs of charts rendered before, for charts asked for over and over with the same data, such as by a bot refreshing a chart every minute
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard};
+
+use lru::LruCache;
+
+use {Candle, OHLCError, OHLCRenderOptions};
+
+/// PNGs looked up by a hash of the options and data they were rendered from, dropping the least recently used once full
+///
+/// Set on options with `OHLCRenderOptions::cache`, then `render_to_png` uses it.
+pub struct RenderCache {
+	pngs: LruCache<u64, Vec<u8>>,
+}
+
+impl RenderCache {
+	/// Holds up to this many PNGs, at least one
+	pub fn new(capacity: usize) -> RenderCache {
+		RenderCache { pngs: LruCache::new(NonZeroUsize::new(capacity.max(1)).unwrap()) }
+	}
+
+	/// Number of PNGs held
+	pub fn len(&self) -> usize {
+		self.pngs.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.pngs.is_empty()
+	}
+
+	pub fn clear(&mut self) {
+		self.pngs.clear();
+	}
+}
+
+/// Hash of the options and the prices and volumes of the candles, which is all a render depends on
+pub(crate) fn key<C: Candle>(options: &OHLCRenderOptions<C>, data: &[C]) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	options.hash(&mut hasher);
+
+	data.len().hash(&mut hasher);
+	for candle in data {
+		for value in &[candle.open(), candle.high(), candle.low(), candle.close(), candle.total_volume()] {
+			value.to_bits().hash(&mut hasher);
+		}
+		candle.buy_volume().map(f64::to_bits).hash(&mut hasher);
+	}
+
+	hasher.finish()
+}
+
+pub(crate) fn render_cached<C: Candle>(options: &OHLCRenderOptions<C>, cache: &Mutex<RenderCache>, data: Vec<C>) -> Result<Vec<u8>, OHLCError> {
+	let key = key(options, &data);
+
+	if let Some(png) = lock(cache).pngs.get(&key) {
+		return Ok(png.clone());
+	}
+
+	// Not locked while rendering, so other charts can use the cache in the meantime
+	let png = options.render_to_png_with_stats(data)?.0;
+	lock(cache).pngs.put(key, png.clone());
+
+	Ok(png)
+}
+
+/// A panic while the cache was locked can't have left it half updated, so it's still used
+fn lock(cache: &Mutex<RenderCache>) -> MutexGuard<'_, RenderCache> {
+	cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,74 @@
+//! Reading candles from CSV files
+//!
+//! The first line is a header naming the columns, `open`, `high`, `low` and `close` are needed and `timestamp` and `volume` are optional, in any order.
+//! Columns can also be named by their first letter, like the fields of `OHLC`, and other columns are ignored. Values are separated by commas without quotes,
+//! and timestamps and volumes can be left empty for candles that don't have them.
+
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use OHLCError;
+use model::data::OHLC;
+
+/// Reads candles from a CSV file, see `parse_csv`
+pub fn read_csv(path: &Path) -> Result<Vec<OHLC>, OHLCError> {
+	let text = fs::read_to_string(path).map_err(|err| OHLCError::Csv(format!("{}: {}", path.display(), err)))?;
+
+	parse_csv(&text)
+}
+
+/// Parses candles from CSV text with a header line, skipping blank lines
+pub fn parse_csv(text: &str) -> Result<Vec<OHLC>, OHLCError> {
+	let mut lines = text.lines().enumerate().filter(|&(_, line)| !line.trim().is_empty());
+
+	let header = match lines.next() {
+		Some((_, header)) => header,
+		None => return Ok(vec![]),
+	};
+
+	// Index of each of timestamp, open, high, low, close and volume in the rows
+	let mut columns = [None; 6];
+	for (i, name) in header.split(',').enumerate() {
+		let column = match name.trim().to_lowercase().as_str() {
+			"timestamp" | "time" | "t" => 0,
+			"open" | "o" => 1,
+			"high" | "h" => 2,
+			"low" | "l" => 3,
+			"close" | "c" => 4,
+			"volume" | "v" => 5,
+			_ => continue,
+		};
+		columns[column] = Some(i);
+	}
+
+	if columns[1..5].iter().any(Option::is_none) {
+		return Err(OHLCError::Csv("The header is missing some of the open, high, low and close columns".to_string()));
+	}
+
+	let mut data = vec![];
+
+	for (number, line) in lines {
+		let values: Vec<&str> = line.split(',').map(str::trim).collect();
+		let value = |column: usize| columns[column].and_then(|i| values.get(i).cloned()).filter(|value| !value.is_empty());
+		let price = |column: usize| match value(column) {
+			Some(value) => parse(value, number),
+			None => Err(OHLCError::Csv(format!("Line {} is missing a price", number + 1))),
+		};
+
+		data.push(OHLC {
+			o: price(1)?,
+			h: price(2)?,
+			l: price(3)?,
+			c: price(4)?,
+			t: value(0).map(|value| parse(value, number)).transpose()?,
+			v: value(5).map(|value| parse(value, number)).transpose()?,
+		});
+	}
+
+	Ok(data)
+}
+
+fn parse<T: FromStr>(value: &str, line: usize) -> Result<T, OHLCError> {
+	value.parse().map_err(|_| OHLCError::Csv(format!("Line {} has {:?} where a number should be", line + 1, value)))
+}
@@ -8,4 +8,10 @@ pub trait Candle {
 		self.buy_volume().map(|buy| self.total_volume() - buy)
 	}
 	fn total_volume(&self) -> f64;
-}
\ No newline at end of file
+	/// Copy of the candle with its high and low replaced, for the lenient validation modes to repair or keep candles with
+	///
+	/// `None` by default, which leaves the data to be validated strictly.
+	fn with_high_low(&self, _high: f64, _low: f64) -> Option<Self> where Self: Sized {
+		None
+	}
+}
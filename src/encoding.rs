@@ -0,0 +1,64 @@
+use std::io::Write;
+
+use deflate::Compression;
+use deflate::write::ZlibEncoder;
+use png::{BitDepth, ColorType, Encoder, HasParameters};
+use png::chunk::IDAT;
+
+use OHLCError;
+
+/// How hard PNG encoding works at making the file small, the faster levels make bigger files
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompressionLevel {
+	/// The quickest to encode, what charts have always been encoded with
+	Fast,
+	/// deflate's default level
+	Default,
+	/// The smallest files, and the slowest to encode
+	Best,
+}
+
+fn write_error<E: ::std::fmt::Debug>(err: E) -> OHLCError {
+	OHLCError::ImageWrite(format!("{:?}", err))
+}
+
+/// Encodes the RGB(8) pixels as a PNG, every row goes through the Sub filter like the `png` crate's own encoder
+pub fn encode_png(pixels: &[u8], width: u32, height: u32, level: CompressionLevel) -> Result<Vec<u8>, OHLCError> {
+	let row_length = width as usize * 3;
+	if row_length == 0 || pixels.len() != row_length * height as usize {
+		return Err(OHLCError::ImageWrite("Chart buffer doesn't match its dimensions".to_string()));
+	}
+
+	let compression = match level {
+		CompressionLevel::Fast => Compression::Fast,
+		CompressionLevel::Default => Compression::Default,
+		CompressionLevel::Best => Compression::Best,
+	};
+
+	let mut zlib = ZlibEncoder::new(Vec::new(), compression);
+	// Each row starts with the filter type, 1 being Sub: the difference from the byte of the pixel to the left
+	let mut filtered = vec![1u8; row_length + 1];
+
+	for row in pixels.chunks(row_length) {
+		for (i, byte) in row.iter().enumerate() {
+			let left = if i >= 3 { row[i - 3] } else { 0 };
+			filtered[i + 1] = byte.wrapping_sub(left);
+		}
+
+		zlib.write_all(&filtered).map_err(write_error)?;
+	}
+
+	let data = zlib.finish().map_err(write_error)?;
+
+	let mut png = vec![];
+	{
+		let mut encoder = Encoder::new(&mut png, width, height);
+		encoder.set(ColorType::RGB).set(BitDepth::Eight);
+
+		// The end chunk is written when the writer is dropped
+		let mut writer = encoder.write_header().map_err(write_error)?;
+		writer.write_chunk(IDAT, &data).map_err(write_error)?;
+	}
+
+	Ok(png)
+}
@@ -0,0 +1,67 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors originating from rendering a chart
+#[derive(Debug, Clone, PartialEq)]
+pub enum OHLCError {
+	/// There were no candles to chart, or none were left once the invalid ones were skipped or in the window
+	EmptyData,
+	/// The data failed validation, contains the reason
+	Validation(String),
+	/// A candle of the data failed validation, contains which one and why
+	InvalidCandle(InvalidCandle),
+	/// The image couldn't be encoded or written, contains the underlying error
+	ImageWrite(String),
+	/// A temporary directory to render into couldn't be created
+	TempDir,
+	/// The callback given to `render` failed, contains its error
+	Callback(String),
+	/// The chart is bigger than the render limits allow, contains which limit
+	TooLarge(String),
+	/// The margins, such as those made room for large text, leave no room for the plot, contains which margins
+	Layout(String),
+	/// A CSV file couldn't be read or parsed, contains the line or the underlying error
+	Csv(String),
+	/// A Parquet file couldn't be read or written, contains the underlying error
+	#[cfg(feature = "parquet")]
+	Parquet(String),
+}
+
+impl fmt::Display for OHLCError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			OHLCError::EmptyData => write!(f, "There are no candles to chart."),
+			OHLCError::Validation(ref err) => write!(f, "Data validation error: {}", err),
+			OHLCError::InvalidCandle(ref err) => write!(f, "Data validation error: {}", err),
+			OHLCError::ImageWrite(ref err) => write!(f, "Image write error: {}", err),
+			OHLCError::TempDir => write!(f, "Failed to create a temporary directory."),
+			OHLCError::Callback(ref err) => write!(f, "Render callback error: {}", err),
+			OHLCError::TooLarge(ref err) => write!(f, "Chart too large: {}", err),
+			OHLCError::Layout(ref err) => write!(f, "Layout error: {}", err),
+			OHLCError::Csv(ref err) => write!(f, "CSV error: {}", err),
+			#[cfg(feature = "parquet")]
+			OHLCError::Parquet(ref err) => write!(f, "Parquet error: {}", err),
+		}
+	}
+}
+
+impl Error for OHLCError {}
+
+/// A candle that failed validation, with where it is in the data and its prices
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidCandle {
+	/// Index of the candle in the data
+	pub index: usize,
+	pub open: f64,
+	pub high: f64,
+	pub low: f64,
+	pub close: f64,
+	/// Which check it failed
+	pub reason: &'static str,
+}
+
+impl fmt::Display for InvalidCandle {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} Candle {} has open {}, high {}, low {} and close {}.", self.reason, self.index, self.open, self.high, self.low, self.close)
+	}
+}
@@ -1,1821 +1,241 @@
-pub const ASCII_TABLE: [[u8; 170]; 127] = [
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[0u8; 170],
-	[
-		0, 0, 0, 0, 107, 92, 0, 0, 0, 0,
-		0, 0, 0, 0, 249, 213, 0, 0, 0, 0,
-		0, 0, 0, 0, 242, 205, 0, 0, 0, 0,
-		0, 0, 0, 0, 234, 198, 0, 0, 0, 0,
-		0, 0, 0, 0, 227, 191, 0, 0, 0, 0,
-		0, 0, 0, 0, 219, 184, 0, 0, 0, 0,
-		0, 0, 0, 0, 212, 177, 0, 0, 0, 0,
-		0, 0, 0, 0, 204, 170, 0, 0, 0, 0,
-		0, 0, 0, 0, 197, 163, 0, 0, 0, 0,
-		0, 0, 0, 0, 122, 101, 0, 0, 0, 0,
-		0, 0, 0, 0, 16, 10, 0, 0, 0, 0,
-		0, 0, 0, 24, 241, 223, 3, 0, 0, 0,
-		0, 0, 0, 26, 243, 225, 3, 0, 0, 0,
-		0, 0, 0, 0, 16, 10, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 78, 108, 20, 32, 108, 66, 0, 0,
-		0, 0, 174, 255, 38, 66, 255, 146, 0, 0,
-		0, 0, 158, 255, 23, 50, 255, 131, 0, 0,
-		0, 0, 143, 255, 9, 35, 255, 117, 0, 0,
-		0, 0, 91, 177, 0, 15, 180, 73, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 115, 43, 0, 47, 112, 0, 0,
-		0, 0, 4, 253, 71, 0, 118, 212, 0, 0,
-		0, 0, 29, 255, 46, 0, 145, 184, 0, 0,
-		26, 152, 171, 255, 163, 152, 218, 219, 152, 78,
-		24, 144, 181, 250, 144, 144, 233, 199, 144, 74,
-		0, 0, 106, 224, 0, 0, 223, 107, 0, 0,
-		0, 0, 133, 199, 0, 0, 247, 81, 0, 0,
-		60, 96, 193, 208, 96, 103, 255, 133, 96, 4,
-		125, 200, 242, 229, 200, 212, 255, 204, 200, 9,
-		0, 0, 211, 121, 0, 71, 254, 6, 0, 0,
-		0, 0, 237, 95, 0, 97, 235, 0, 0, 0,
-		0, 7, 255, 69, 0, 123, 209, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 226, 101, 0, 0, 0,
-		0, 0, 0, 54, 106, 253, 140, 59, 1, 0,
-		0, 23, 195, 255, 239, 255, 238, 255, 8, 0,
-		0, 170, 243, 57, 71, 250, 3, 24, 1, 0,
-		0, 228, 192, 0, 106, 220, 0, 0, 0, 0,
-		0, 190, 249, 81, 142, 186, 0, 0, 0, 0,
-		0, 31, 216, 255, 250, 185, 11, 0, 0, 0,
-		0, 0, 7, 102, 243, 255, 238, 101, 0, 0,
-		0, 0, 0, 0, 243, 129, 191, 255, 99, 0,
-		0, 0, 0, 21, 255, 46, 6, 233, 200, 0,
-		0, 0, 0, 55, 255, 13, 12, 239, 185, 0,
-		6, 182, 127, 152, 247, 120, 210, 251, 70, 0,
-		4, 183, 224, 251, 253, 221, 164, 46, 0, 0,
-		0, 0, 0, 158, 170, 0, 0, 0, 0, 0,
-		0, 0, 0, 192, 135, 0, 0, 0, 0, 0,
-		0, 0, 0, 16, 9, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 47, 124, 96, 4, 0, 0, 0, 94, 62,
-		50, 248, 169, 228, 158, 0, 0, 86, 247, 38,
-		148, 184, 0, 87, 243, 0, 19, 233, 119, 0,
-		158, 173, 0, 81, 243, 1, 163, 204, 3, 0,
-		89, 246, 123, 209, 163, 74, 250, 46, 0, 0,
-		0, 102, 171, 129, 24, 227, 130, 0, 0, 0,
-		0, 0, 0, 0, 152, 213, 6, 0, 0, 0,
-		0, 0, 0, 64, 252, 55, 5, 21, 0, 0,
-		0, 0, 9, 219, 142, 82, 243, 255, 195, 10,
-		0, 0, 140, 221, 13, 237, 118, 23, 233, 103,
-		0, 54, 251, 65, 26, 255, 46, 0, 191, 137,
-		6, 212, 153, 0, 5, 245, 112, 30, 238, 91,
-		129, 228, 14, 0, 0, 100, 247, 254, 168, 3,
-		0, 0, 0, 0, 0, 0, 6, 14, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 3, 32, 8, 0, 0, 0, 0,
-		0, 0, 108, 244, 255, 250, 129, 0, 0, 0,
-		0, 52, 254, 152, 44, 148, 255, 58, 0, 0,
-		0, 114, 255, 31, 0, 40, 255, 98, 0, 0,
-		0, 92, 255, 77, 0, 119, 254, 44, 0, 0,
-		0, 14, 231, 217, 142, 253, 123, 0, 0, 0,
-		0, 0, 155, 255, 249, 72, 0, 0, 0, 0,
-		0, 144, 251, 186, 255, 122, 0, 147, 241, 0,
-		51, 255, 117, 2, 183, 253, 73, 171, 212, 0,
-		112, 255, 34, 0, 17, 220, 239, 236, 155, 0,
-		101, 255, 67, 0, 0, 44, 244, 255, 66, 0,
-		29, 243, 221, 84, 63, 139, 252, 255, 176, 1,
-		0, 65, 212, 255, 255, 225, 99, 140, 255, 126,
-		0, 0, 0, 12, 22, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 3, 108, 101, 0, 0, 0, 0,
-		0, 0, 0, 1, 253, 229, 0, 0, 0, 0,
-		0, 0, 0, 0, 240, 213, 0, 0, 0, 0,
-		0, 0, 0, 0, 226, 198, 0, 0, 0, 0,
-		0, 0, 0, 0, 150, 130, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 17, 200, 73, 0, 0,
-		0, 0, 0, 0, 4, 191, 230, 29, 0, 0,
-		0, 0, 0, 0, 130, 251, 52, 0, 0, 0,
-		0, 0, 0, 34, 250, 143, 0, 0, 0, 0,
-		0, 0, 0, 150, 246, 16, 0, 0, 0, 0,
-		0, 0, 3, 238, 172, 0, 0, 0, 0, 0,
-		0, 0, 43, 255, 102, 0, 0, 0, 0, 0,
-		0, 0, 70, 255, 72, 0, 0, 0, 0, 0,
-		0, 0, 85, 255, 59, 0, 0, 0, 0, 0,
-		0, 0, 60, 255, 85, 0, 0, 0, 0, 0,
-		0, 0, 27, 255, 127, 0, 0, 0, 0, 0,
-		0, 0, 0, 203, 211, 0, 0, 0, 0, 0,
-		0, 0, 0, 108, 255, 61, 0, 0, 0, 0,
-		0, 0, 0, 7, 216, 206, 2, 0, 0, 0,
-		0, 0, 0, 0, 64, 253, 136, 0, 0, 0,
-		0, 0, 0, 0, 0, 105, 255, 85, 0, 0,
-		0, 0, 0, 0, 0, 0, 98, 17, 0, 0,
-	],
-	[
-		0, 0, 91, 190, 10, 0, 0, 0, 0, 0,
-		0, 0, 53, 245, 174, 1, 0, 0, 0, 0,
-		0, 0, 0, 83, 255, 110, 0, 0, 0, 0,
-		0, 0, 0, 0, 177, 242, 22, 0, 0, 0,
-		0, 0, 0, 0, 39, 255, 129, 0, 0, 0,
-		0, 0, 0, 0, 0, 206, 218, 0, 0, 0,
-		0, 0, 0, 0, 0, 134, 255, 23, 0, 0,
-		0, 0, 0, 0, 0, 104, 255, 56, 0, 0,
-		0, 0, 0, 0, 0, 87, 255, 60, 0, 0,
-		0, 0, 0, 0, 0, 111, 255, 35, 0, 0,
-		0, 0, 0, 0, 0, 150, 247, 6, 0, 0,
-		0, 0, 0, 0, 2, 230, 182, 0, 0, 0,
-		0, 0, 0, 0, 80, 255, 79, 0, 0, 0,
-		0, 0, 0, 7, 221, 199, 1, 0, 0, 0,
-		0, 0, 0, 155, 245, 42, 0, 0, 0, 0,
-		0, 0, 110, 253, 84, 0, 0, 0, 0, 0,
-		0, 0, 33, 86, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 71, 60, 0, 0, 0, 0,
-		0, 0, 1, 0, 156, 130, 0, 2, 0, 0,
-		0, 74, 195, 35, 139, 112, 51, 210, 49, 0,
-		0, 14, 125, 228, 202, 197, 229, 118, 11, 0,
-		0, 0, 4, 110, 253, 248, 90, 1, 0, 0,
-		0, 62, 221, 176, 158, 141, 196, 213, 46, 0,
-		0, 30, 85, 0, 148, 121, 2, 103, 11, 0,
-		0, 0, 0, 0, 164, 138, 0, 0, 0, 0,
-		0, 0, 0, 0, 5, 4, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 3, 2, 0, 0, 0, 0,
-		0, 0, 0, 0, 208, 180, 0, 0, 0, 0,
-		0, 0, 0, 0, 208, 180, 0, 0, 0, 0,
-		0, 0, 0, 0, 208, 180, 0, 0, 0, 0,
-		30, 116, 116, 116, 229, 214, 116, 116, 116, 18,
-		59, 224, 224, 224, 250, 246, 224, 224, 224, 35,
-		0, 0, 0, 0, 208, 180, 0, 0, 0, 0,
-		0, 0, 0, 0, 208, 180, 0, 0, 0, 0,
-		0, 0, 0, 0, 208, 180, 0, 0, 0, 0,
-		0, 0, 0, 0, 97, 84, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 30, 191, 139, 0, 0, 0, 0,
-		0, 0, 0, 88, 255, 255, 51, 0, 0, 0,
-		0, 0, 0, 8, 219, 255, 79, 0, 0, 0,
-		0, 0, 0, 0, 171, 253, 30, 0, 0, 0,
-		0, 0, 56, 129, 252, 143, 0, 0, 0, 0,
-		0, 0, 224, 208, 104, 1, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 88, 132, 132, 132, 132, 76, 0, 0,
-		0, 0, 161, 240, 240, 240, 240, 138, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 25, 170, 144, 3, 0, 0, 0,
-		0, 0, 0, 132, 255, 255, 70, 0, 0, 0,
-		0, 0, 0, 73, 251, 234, 26, 0, 0, 0,
-		0, 0, 0, 0, 19, 10, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 5, 107, 44, 0,
-		0, 0, 0, 0, 0, 0, 88, 252, 31, 0,
-		0, 0, 0, 0, 0, 0, 196, 176, 0, 0,
-		0, 0, 0, 0, 0, 48, 255, 68, 0, 0,
-		0, 0, 0, 0, 0, 156, 215, 0, 0, 0,
-		0, 0, 0, 0, 17, 246, 108, 0, 0, 0,
-		0, 0, 0, 0, 115, 243, 14, 0, 0, 0,
-		0, 0, 0, 2, 220, 149, 0, 0, 0, 0,
-		0, 0, 0, 75, 255, 41, 0, 0, 0, 0,
-		0, 0, 0, 183, 189, 0, 0, 0, 0, 0,
-		0, 0, 36, 254, 81, 0, 0, 0, 0, 0,
-		0, 0, 143, 225, 3, 0, 0, 0, 0, 0,
-		0, 11, 239, 121, 0, 0, 0, 0, 0, 0,
-		0, 102, 248, 21, 0, 0, 0, 0, 0, 0,
-		0, 181, 149, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 1, 85, 149, 151, 89, 2, 0, 0,
-		0, 4, 178, 252, 188, 199, 255, 181, 3, 0,
-		0, 111, 255, 78, 0, 0, 108, 255, 102, 0,
-		0, 213, 188, 0, 0, 0, 5, 237, 198, 0,
-		17, 255, 119, 0, 0, 29, 196, 255, 248, 3,
-		46, 255, 91, 0, 78, 237, 214, 162, 255, 21,
-		52, 255, 90, 143, 255, 160, 11, 111, 255, 27,
-		39, 255, 241, 244, 94, 0, 0, 127, 255, 12,
-		6, 251, 223, 40, 0, 0, 0, 174, 225, 0,
-		0, 186, 234, 12, 0, 0, 24, 246, 146, 0,
-		0, 63, 253, 188, 60, 65, 203, 238, 28, 0,
-		0, 0, 89, 231, 255, 255, 206, 51, 0, 0,
-		0, 0, 0, 2, 30, 22, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 2, 91, 140, 28, 0, 0, 0,
-		0, 0, 78, 211, 255, 255, 52, 0, 0, 0,
-		0, 150, 255, 187, 169, 255, 52, 0, 0, 0,
-		0, 66, 72, 0, 128, 255, 52, 0, 0, 0,
-		0, 0, 0, 0, 128, 255, 52, 0, 0, 0,
-		0, 0, 0, 0, 128, 255, 52, 0, 0, 0,
-		0, 0, 0, 0, 128, 255, 52, 0, 0, 0,
-		0, 0, 0, 0, 128, 255, 52, 0, 0, 0,
-		0, 0, 0, 0, 128, 255, 52, 0, 0, 0,
-		0, 0, 0, 0, 128, 255, 52, 0, 0, 0,
-		0, 48, 108, 108, 182, 255, 138, 108, 82, 0,
-		0, 116, 255, 255, 255, 255, 255, 255, 196, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 21, 112, 154, 133, 56, 0, 0, 0,
-		0, 69, 239, 235, 189, 230, 254, 109, 0, 0,
-		0, 45, 153, 10, 0, 10, 205, 248, 17, 0,
-		0, 0, 0, 0, 0, 0, 117, 255, 56, 0,
-		0, 0, 0, 0, 0, 0, 129, 255, 32, 0,
-		0, 0, 0, 0, 0, 9, 221, 201, 0, 0,
-		0, 0, 0, 0, 0, 159, 249, 53, 0, 0,
-		0, 0, 0, 0, 143, 253, 91, 0, 0, 0,
-		0, 0, 0, 144, 253, 96, 0, 0, 0, 0,
-		0, 0, 145, 252, 93, 0, 0, 0, 0, 0,
-		0, 130, 255, 194, 116, 116, 116, 116, 99, 0,
-		0, 180, 255, 255, 255, 255, 255, 255, 220, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 15, 100, 146, 156, 127, 58, 0, 0, 0,
-		0, 64, 239, 194, 184, 231, 255, 110, 0, 0,
-		0, 7, 2, 0, 0, 6, 203, 245, 5, 0,
-		0, 0, 0, 0, 0, 0, 144, 255, 11, 0,
-		0, 0, 0, 0, 0, 8, 213, 193, 0, 0,
-		0, 0, 21, 140, 152, 223, 193, 24, 0, 0,
-		0, 0, 28, 180, 190, 230, 243, 128, 0, 0,
-		0, 0, 0, 0, 0, 3, 124, 255, 89, 0,
-		0, 0, 0, 0, 0, 0, 6, 253, 153, 0,
-		0, 0, 0, 0, 0, 0, 51, 255, 119, 0,
-		0, 48, 61, 47, 54, 103, 226, 224, 17, 0,
-		0, 144, 255, 255, 255, 240, 157, 24, 0, 0,
-		0, 5, 29, 38, 27, 1, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 108, 124, 58, 0, 0,
-		0, 0, 0, 0, 91, 255, 255, 120, 0, 0,
-		0, 0, 0, 20, 235, 169, 255, 120, 0, 0,
-		0, 0, 0, 166, 223, 35, 255, 120, 0, 0,
-		0, 0, 75, 255, 68, 24, 255, 120, 0, 0,
-		0, 13, 226, 155, 0, 24, 255, 120, 0, 0,
-		0, 150, 229, 15, 0, 24, 255, 120, 0, 0,
-		61, 254, 77, 0, 0, 24, 255, 120, 0, 0,
-		159, 254, 228, 228, 228, 230, 255, 241, 228, 99,
-		72, 116, 116, 116, 116, 129, 255, 181, 116, 50,
-		0, 0, 0, 0, 0, 24, 255, 120, 0, 0,
-		0, 0, 0, 0, 0, 24, 255, 120, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 29, 124, 124, 124, 124, 124, 118, 0, 0,
-		0, 60, 255, 224, 216, 216, 216, 205, 0, 0,
-		0, 60, 255, 52, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 52, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 52, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 240, 228, 202, 130, 25, 0, 0,
-		0, 23, 100, 100, 114, 160, 248, 229, 21, 0,
-		0, 0, 0, 0, 0, 0, 92, 255, 113, 0,
-		0, 0, 0, 0, 0, 0, 34, 255, 135, 0,
-		0, 0, 0, 0, 0, 0, 102, 255, 83, 0,
-		0, 39, 63, 47, 63, 129, 246, 186, 2, 0,
-		0, 112, 255, 255, 255, 230, 128, 8, 0, 0,
-		0, 3, 24, 37, 25, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 21, 88, 116, 124, 13, 0,
-		0, 0, 5, 148, 248, 248, 222, 216, 23, 0,
-		0, 0, 173, 239, 107, 7, 0, 0, 0, 0,
-		0, 65, 255, 78, 0, 0, 0, 0, 0, 0,
-		0, 153, 226, 0, 0, 12, 2, 0, 0, 0,
-		0, 207, 213, 178, 242, 255, 241, 130, 5, 0,
-		0, 227, 243, 135, 61, 55, 144, 255, 125, 0,
-		0, 230, 173, 0, 0, 0, 0, 202, 220, 0,
-		0, 209, 196, 0, 0, 0, 0, 162, 248, 0,
-		0, 154, 247, 17, 0, 0, 1, 210, 207, 0,
-		0, 47, 252, 181, 52, 41, 150, 255, 83, 0,
-		0, 0, 82, 229, 255, 255, 222, 78, 0, 0,
-		0, 0, 0, 1, 29, 28, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 120, 124, 124, 124, 124, 124, 124, 98, 0,
-		0, 232, 240, 240, 240, 240, 240, 252, 203, 0,
-		0, 0, 0, 0, 0, 0, 22, 245, 134, 0,
-		0, 0, 0, 0, 0, 0, 139, 246, 21, 0,
-		0, 0, 0, 0, 0, 21, 244, 146, 0, 0,
-		0, 0, 0, 0, 0, 137, 250, 29, 0, 0,
-		0, 0, 0, 0, 20, 244, 157, 0, 0, 0,
-		0, 0, 0, 0, 136, 253, 37, 0, 0, 0,
-		0, 0, 0, 19, 243, 168, 0, 0, 0, 0,
-		0, 0, 0, 134, 254, 46, 0, 0, 0, 0,
-		0, 0, 18, 242, 179, 0, 0, 0, 0, 0,
-		0, 0, 132, 255, 57, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 8, 103, 154, 148, 108, 15, 0, 0,
-		0, 21, 221, 238, 173, 177, 244, 230, 28, 0,
-		0, 139, 248, 25, 0, 0, 44, 255, 126, 0,
-		0, 167, 238, 3, 0, 0, 10, 253, 138, 0,
-		0, 91, 255, 162, 12, 7, 161, 250, 48, 0,
-		0, 0, 122, 250, 236, 226, 230, 71, 0, 0,
-		0, 0, 73, 229, 237, 251, 234, 85, 0, 0,
-		0, 82, 253, 163, 14, 35, 202, 254, 81, 0,
-		0, 208, 219, 3, 0, 0, 17, 241, 190, 0,
-		0, 230, 200, 0, 0, 0, 0, 224, 207, 0,
-		0, 160, 255, 135, 52, 55, 150, 255, 122, 0,
-		0, 12, 153, 248, 255, 255, 230, 117, 2, 0,
-		0, 0, 0, 2, 27, 19, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 7, 104, 155, 146, 74, 0, 0, 0,
-		0, 28, 216, 238, 171, 195, 255, 148, 0, 0,
-		0, 179, 233, 25, 0, 0, 118, 255, 57, 0,
-		13, 254, 147, 0, 0, 0, 10, 249, 143, 0,
-		17, 255, 138, 0, 0, 0, 0, 215, 186, 0,
-		0, 220, 219, 14, 0, 0, 22, 222, 199, 0,
-		0, 75, 247, 233, 178, 193, 248, 250, 188, 0,
-		0, 0, 37, 127, 144, 114, 41, 226, 157, 0,
-		0, 0, 0, 0, 0, 0, 40, 255, 92, 0,
-		0, 0, 0, 0, 0, 12, 189, 224, 7, 0,
-		0, 37, 88, 100, 142, 230, 233, 50, 0, 0,
-		0, 108, 254, 240, 204, 132, 22, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 27, 216, 203, 13, 0, 0, 0,
-		0, 0, 0, 87, 255, 255, 61, 0, 0, 0,
-		0, 0, 0, 9, 149, 136, 2, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 1, 104, 91, 0, 0, 0, 0,
-		0, 0, 0, 78, 255, 255, 51, 0, 0, 0,
-		0, 0, 0, 44, 246, 237, 26, 0, 0, 0,
-		0, 0, 0, 0, 17, 13, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 27, 216, 203, 13, 0, 0, 0,
-		0, 0, 0, 87, 255, 255, 61, 0, 0, 0,
-		0, 0, 0, 9, 149, 136, 2, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 18, 183, 157, 4, 0, 0, 0,
-		0, 0, 0, 64, 255, 255, 78, 0, 0, 0,
-		0, 0, 0, 2, 198, 255, 104, 0, 0, 0,
-		0, 0, 0, 0, 145, 255, 54, 0, 0, 0,
-		0, 0, 48, 117, 246, 171, 0, 0, 0, 0,
-		0, 0, 198, 215, 120, 4, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 60, 83, 0, 0,
-		0, 0, 0, 0, 0, 96, 249, 175, 0, 0,
-		0, 0, 0, 2, 139, 255, 137, 2, 0, 0,
-		0, 0, 13, 179, 247, 92, 0, 0, 0, 0,
-		0, 32, 211, 230, 55, 0, 0, 0, 0, 0,
-		0, 103, 253, 171, 6, 0, 0, 0, 0, 0,
-		0, 0, 76, 242, 196, 21, 0, 0, 0, 0,
-		0, 0, 0, 45, 224, 224, 45, 0, 0, 0,
-		0, 0, 0, 0, 22, 197, 243, 77, 0, 0,
-		0, 0, 0, 0, 0, 7, 162, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 5, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 205, 248, 248, 248, 248, 248, 248, 182, 0,
-		0, 69, 84, 84, 84, 84, 84, 84, 61, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 192, 232, 232, 232, 232, 232, 232, 170, 0,
-		0, 79, 96, 96, 96, 96, 96, 96, 70, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 99, 43, 0, 0, 0, 0, 0, 0,
-		0, 5, 196, 242, 75, 0, 0, 0, 0, 0,
-		0, 0, 7, 161, 253, 115, 0, 0, 0, 0,
-		0, 0, 0, 0, 119, 253, 157, 6, 0, 0,
-		0, 0, 0, 0, 0, 78, 243, 194, 20, 0,
-		0, 0, 0, 0, 0, 9, 185, 248, 79, 0,
-		0, 0, 0, 0, 28, 204, 232, 57, 0, 0,
-		0, 0, 0, 56, 231, 209, 30, 0, 0, 0,
-		0, 0, 94, 248, 177, 12, 0, 0, 0, 0,
-		0, 5, 200, 138, 2, 0, 0, 0, 0, 0,
-		0, 0, 5, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 36, 91, 57, 2, 0, 0, 0, 0,
-		0, 0, 84, 254, 255, 229, 86, 0, 0, 0,
-		0, 0, 0, 8, 58, 190, 255, 90, 0, 0,
-		0, 0, 0, 0, 0, 9, 216, 229, 0, 0,
-		0, 0, 0, 0, 0, 0, 152, 255, 17, 0,
-		0, 0, 0, 0, 0, 5, 202, 237, 0, 0,
-		0, 0, 0, 85, 191, 229, 249, 93, 0, 0,
-		0, 0, 0, 110, 255, 97, 31, 0, 0, 0,
-		0, 0, 0, 102, 252, 0, 0, 0, 0, 0,
-		0, 0, 0, 60, 157, 0, 0, 0, 0, 0,
-		0, 0, 0, 4, 22, 0, 0, 0, 0, 0,
-		0, 0, 0, 174, 253, 65, 0, 0, 0, 0,
-		0, 0, 0, 177, 253, 67, 0, 0, 0, 0,
-		0, 0, 0, 4, 22, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 2, 79, 127, 109, 27, 0, 0,
-		0, 0, 26, 202, 219, 149, 184, 243, 69, 0,
-		0, 7, 208, 170, 6, 0, 0, 119, 233, 11,
-		0, 118, 226, 13, 0, 0, 0, 4, 233, 91,
-		4, 228, 104, 0, 4, 23, 1, 12, 171, 150,
-		71, 246, 14, 45, 224, 255, 245, 117, 137, 183,
-		132, 193, 0, 208, 161, 45, 255, 78, 123, 196,
-		185, 138, 51, 255, 44, 59, 255, 38, 124, 191,
-		207, 111, 107, 244, 1, 97, 250, 4, 139, 174,
-		224, 95, 131, 225, 0, 136, 218, 0, 173, 137,
-		226, 93, 122, 234, 2, 206, 202, 8, 236, 71,
-		210, 109, 61, 255, 210, 177, 251, 211, 197, 2,
-		175, 149, 0, 68, 93, 4, 75, 97, 11, 0,
-		114, 222, 2, 0, 0, 0, 0, 0, 0, 0,
-		23, 246, 113, 0, 0, 0, 0, 6, 0, 0,
-		0, 96, 251, 170, 115, 135, 197, 115, 0, 0,
-		0, 0, 46, 136, 161, 142, 83, 7, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 21, 124, 124, 15, 0, 0, 0,
-		0, 0, 0, 108, 255, 255, 94, 0, 0, 0,
-		0, 0, 0, 193, 190, 233, 178, 0, 0, 0,
-		0, 0, 24, 253, 106, 154, 248, 13, 0, 0,
-		0, 0, 107, 254, 26, 73, 255, 90, 0, 0,
-		0, 0, 192, 201, 0, 6, 241, 174, 0, 0,
-		0, 24, 253, 120, 0, 0, 166, 246, 11, 0,
-		0, 106, 255, 39, 0, 0, 85, 255, 86, 0,
-		0, 191, 255, 232, 232, 232, 235, 255, 170, 0,
-		23, 253, 183, 128, 128, 128, 128, 207, 244, 9,
-		105, 255, 50, 0, 0, 0, 0, 97, 255, 82,
-		190, 224, 0, 0, 0, 0, 0, 18, 251, 166,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 75, 124, 124, 122, 101, 56, 0, 0, 0,
-		0, 156, 253, 212, 214, 242, 255, 186, 6, 0,
-		0, 156, 244, 0, 0, 3, 134, 255, 90, 0,
-		0, 156, 244, 0, 0, 0, 39, 255, 121, 0,
-		0, 156, 244, 0, 0, 0, 109, 255, 62, 0,
-		0, 156, 250, 148, 151, 188, 240, 106, 0, 0,
-		0, 156, 252, 184, 187, 218, 255, 190, 25, 0,
-		0, 156, 244, 0, 0, 0, 72, 250, 173, 0,
-		0, 156, 244, 0, 0, 0, 0, 203, 229, 0,
-		0, 156, 244, 0, 0, 0, 10, 233, 200, 0,
-		0, 156, 247, 80, 81, 106, 201, 251, 71, 0,
-		0, 156, 255, 255, 250, 223, 176, 57, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 20, 106, 149, 146, 112, 36, 0,
-		0, 0, 78, 240, 252, 209, 213, 246, 192, 0,
-		0, 44, 249, 203, 32, 0, 0, 9, 61, 0,
-		0, 170, 248, 29, 0, 0, 0, 0, 0, 0,
-		3, 244, 178, 0, 0, 0, 0, 0, 0, 0,
-		32, 255, 132, 0, 0, 0, 0, 0, 0, 0,
-		44, 255, 124, 0, 0, 0, 0, 0, 0, 0,
-		30, 255, 145, 0, 0, 0, 0, 0, 0, 0,
-		1, 240, 206, 0, 0, 0, 0, 0, 0, 0,
-		0, 156, 255, 85, 0, 0, 0, 0, 0, 0,
-		0, 25, 230, 249, 144, 76, 78, 119, 147, 0,
-		0, 0, 30, 169, 246, 255, 255, 237, 130, 0,
-		0, 0, 0, 0, 5, 28, 20, 1, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		5, 124, 124, 123, 113, 80, 16, 0, 0, 0,
-		12, 255, 234, 212, 235, 255, 243, 110, 0, 0,
-		12, 255, 132, 0, 0, 25, 170, 255, 88, 0,
-		12, 255, 132, 0, 0, 0, 6, 223, 214, 0,
-		12, 255, 132, 0, 0, 0, 0, 145, 255, 24,
-		12, 255, 132, 0, 0, 0, 0, 110, 255, 53,
-		12, 255, 132, 0, 0, 0, 0, 105, 255, 55,
-		12, 255, 132, 0, 0, 0, 0, 129, 255, 34,
-		12, 255, 132, 0, 0, 0, 0, 198, 229, 0,
-		12, 255, 132, 0, 0, 0, 99, 255, 122, 0,
-		12, 255, 176, 92, 110, 179, 255, 177, 5, 0,
-		12, 255, 255, 251, 227, 186, 93, 2, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 29, 124, 124, 124, 124, 124, 124, 36, 0,
-		0, 60, 255, 226, 212, 212, 212, 212, 62, 0,
-		0, 60, 255, 84, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 84, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 84, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 188, 156, 156, 156, 156, 17, 0,
-		0, 60, 255, 204, 180, 180, 180, 180, 19, 0,
-		0, 60, 255, 84, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 84, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 84, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 143, 88, 88, 88, 88, 26, 0,
-		0, 60, 255, 255, 255, 255, 255, 255, 76, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 25, 124, 124, 124, 124, 124, 124, 34, 0,
-		0, 52, 255, 232, 216, 216, 216, 216, 60, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 168, 108, 108, 108, 108, 3, 0,
-		0, 52, 255, 239, 228, 228, 228, 228, 7, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 52, 255, 104, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 26, 110, 152, 152, 120, 45, 0,
-		0, 0, 105, 247, 252, 210, 211, 244, 216, 0,
-		0, 80, 255, 183, 26, 0, 0, 6, 68, 0,
-		2, 219, 222, 9, 0, 0, 0, 0, 0, 0,
-		49, 255, 121, 0, 0, 0, 0, 0, 0, 0,
-		91, 255, 70, 0, 0, 104, 104, 104, 92, 0,
-		103, 255, 63, 0, 0, 228, 228, 246, 228, 0,
-		87, 255, 89, 0, 0, 0, 0, 168, 228, 0,
-		38, 255, 139, 0, 0, 0, 0, 168, 228, 0,
-		0, 203, 240, 32, 0, 0, 0, 168, 228, 0,
-		0, 53, 246, 232, 117, 65, 69, 203, 228, 0,
-		0, 0, 49, 189, 252, 255, 255, 236, 146, 0,
-		0, 0, 0, 0, 11, 34, 27, 1, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		3, 124, 67, 0, 0, 0, 0, 79, 116, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 212, 160, 160, 160, 160, 221, 240, 0,
-		8, 255, 223, 184, 184, 184, 184, 230, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		8, 255, 140, 0, 0, 0, 0, 164, 240, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 60, 124, 124, 124, 124, 124, 124, 48, 0,
-		0, 102, 212, 212, 249, 244, 212, 212, 82, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 42, 88, 88, 229, 211, 88, 88, 34, 0,
-		0, 124, 255, 255, 255, 255, 255, 255, 100, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 31, 124, 124, 124, 124, 124, 87, 0, 0,
-		0, 55, 220, 220, 220, 220, 251, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 224, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 224, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 224, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 224, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 224, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 224, 180, 0, 0,
-		0, 0, 0, 0, 0, 0, 228, 175, 0, 0,
-		0, 2, 0, 0, 0, 21, 253, 139, 0, 0,
-		0, 100, 160, 87, 77, 193, 250, 40, 0, 0,
-		0, 61, 224, 255, 255, 225, 83, 0, 0, 0,
-		0, 0, 0, 19, 23, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 77, 116, 0, 0, 0, 9, 120, 98, 0,
-		0, 160, 240, 0, 0, 0, 162, 247, 54, 0,
-		0, 160, 240, 0, 0, 109, 255, 90, 0, 0,
-		0, 160, 240, 0, 60, 250, 132, 0, 0, 0,
-		0, 160, 240, 25, 231, 174, 1, 0, 0, 0,
-		0, 160, 243, 197, 208, 11, 0, 0, 0, 0,
-		0, 160, 246, 226, 213, 12, 0, 0, 0, 0,
-		0, 160, 240, 56, 250, 170, 0, 0, 0, 0,
-		0, 160, 240, 0, 111, 255, 116, 0, 0, 0,
-		0, 160, 240, 0, 0, 172, 252, 65, 0, 0,
-		0, 160, 240, 0, 0, 14, 219, 233, 28, 0,
-		0, 160, 240, 0, 0, 0, 47, 246, 200, 6,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 118, 79, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 164, 0, 0, 0, 0, 0, 0,
-		0, 0, 244, 195, 88, 88, 88, 88, 57, 0,
-		0, 0, 244, 255, 255, 255, 255, 255, 168, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		1, 124, 115, 0, 0, 0, 11, 124, 113, 0,
-		13, 255, 255, 49, 0, 0, 95, 255, 244, 0,
-		25, 255, 221, 141, 0, 0, 191, 216, 254, 1,
-		38, 255, 139, 229, 2, 32, 253, 123, 255, 13,
-		50, 255, 52, 252, 68, 126, 198, 79, 255, 25,
-		63, 255, 23, 182, 157, 216, 102, 71, 255, 38,
-		75, 255, 16, 89, 248, 247, 15, 62, 255, 50,
-		88, 255, 8, 9, 241, 167, 0, 54, 255, 63,
-		100, 255, 1, 0, 48, 26, 0, 46, 255, 75,
-		113, 250, 0, 0, 0, 0, 0, 37, 255, 88,
-		125, 242, 0, 0, 0, 0, 0, 29, 255, 100,
-		138, 235, 0, 0, 0, 0, 0, 20, 255, 113,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 118, 124, 21, 0, 0, 0, 73, 106, 0,
-		0, 244, 255, 134, 0, 0, 0, 152, 220, 0,
-		0, 244, 244, 239, 13, 0, 0, 152, 220, 0,
-		0, 244, 156, 252, 118, 0, 0, 152, 220, 0,
-		0, 244, 128, 172, 231, 7, 0, 152, 220, 0,
-		0, 244, 128, 58, 255, 103, 0, 152, 220, 0,
-		0, 244, 128, 0, 193, 220, 3, 152, 220, 0,
-		0, 244, 128, 0, 73, 255, 86, 152, 220, 0,
-		0, 244, 128, 0, 0, 208, 199, 152, 220, 0,
-		0, 244, 128, 0, 0, 89, 255, 208, 220, 0,
-		0, 244, 128, 0, 0, 3, 221, 255, 220, 0,
-		0, 244, 128, 0, 0, 0, 104, 255, 220, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 3, 86, 149, 151, 99, 8, 0, 0,
-		0, 14, 196, 254, 201, 206, 255, 213, 21, 0,
-		0, 156, 250, 67, 0, 0, 75, 252, 166, 0,
-		21, 250, 163, 0, 0, 0, 0, 177, 251, 17,
-		83, 255, 82, 0, 0, 0, 0, 101, 255, 69,
-		115, 255, 49, 0, 0, 0, 0, 74, 255, 94,
-		124, 255, 38, 0, 0, 0, 0, 63, 255, 98,
-		110, 255, 59, 0, 0, 0, 0, 86, 255, 80,
-		70, 255, 108, 0, 0, 0, 0, 140, 255, 31,
-		9, 242, 205, 2, 0, 0, 13, 230, 199, 0,
-		0, 120, 255, 175, 67, 75, 199, 249, 56, 0,
-		0, 0, 122, 239, 255, 255, 211, 64, 0, 0,
-		0, 0, 0, 4, 31, 22, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 75, 124, 124, 121, 97, 45, 0, 0, 0,
-		0, 156, 253, 208, 211, 240, 255, 183, 15, 0,
-		0, 156, 244, 0, 0, 3, 112, 255, 150, 0,
-		0, 156, 244, 0, 0, 0, 0, 209, 225, 0,
-		0, 156, 244, 0, 0, 0, 0, 198, 226, 0,
-		0, 156, 244, 0, 0, 0, 43, 248, 155, 0,
-		0, 156, 249, 116, 121, 156, 244, 219, 23, 0,
-		0, 156, 254, 224, 220, 197, 126, 15, 0, 0,
-		0, 156, 244, 0, 0, 0, 0, 0, 0, 0,
-		0, 156, 244, 0, 0, 0, 0, 0, 0, 0,
-		0, 156, 244, 0, 0, 0, 0, 0, 0, 0,
-		0, 156, 244, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 3, 86, 149, 151, 99, 8, 0, 0,
-		0, 13, 195, 255, 209, 213, 255, 213, 23, 0,
-		0, 153, 251, 73, 0, 0, 79, 253, 168, 0,
-		19, 249, 164, 0, 0, 0, 0, 167, 251, 18,
-		80, 255, 82, 0, 0, 0, 0, 99, 255, 70,
-		114, 255, 49, 0, 0, 0, 0, 74, 255, 95,
-		122, 255, 39, 0, 0, 0, 0, 63, 255, 98,
-		100, 255, 59, 0, 0, 0, 0, 86, 255, 78,
-		71, 255, 110, 0, 0, 0, 0, 140, 255, 29,
-		11, 243, 206, 2, 0, 0, 13, 230, 195, 0,
-		0, 121, 255, 177, 71, 78, 199, 248, 53, 0,
-		0, 1, 122, 239, 255, 255, 208, 61, 0, 0,
-		0, 0, 0, 3, 207, 184, 0, 0, 0, 0,
-		0, 0, 0, 0, 124, 254, 123, 57, 108, 90,
-		0, 0, 0, 0, 5, 157, 253, 255, 247, 126,
-		0, 0, 0, 0, 0, 0, 10, 34, 5, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 62, 124, 124, 122, 105, 44, 0, 0, 0,
-		0, 128, 255, 214, 215, 245, 255, 143, 0, 0,
-		0, 128, 255, 16, 0, 12, 179, 255, 43, 0,
-		0, 128, 255, 16, 0, 0, 81, 255, 84, 0,
-		0, 128, 255, 16, 0, 0, 129, 255, 45, 0,
-		0, 128, 255, 106, 103, 155, 251, 151, 0, 0,
-		0, 128, 255, 230, 249, 255, 113, 0, 0, 0,
-		0, 128, 255, 16, 15, 195, 232, 19, 0, 0,
-		0, 128, 255, 16, 0, 45, 254, 141, 0, 0,
-		0, 128, 255, 16, 0, 0, 180, 244, 19, 0,
-		0, 128, 255, 16, 0, 0, 60, 255, 131, 0,
-		0, 128, 255, 16, 0, 0, 0, 196, 239, 14,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 8, 89, 146, 160, 143, 104, 2, 0,
-		0, 31, 220, 252, 202, 186, 206, 248, 8, 0,
-		0, 171, 244, 42, 0, 0, 0, 6, 0, 0,
-		0, 221, 205, 0, 0, 0, 0, 0, 0, 0,
-		0, 171, 254, 106, 1, 0, 0, 0, 0, 0,
-		0, 22, 208, 255, 213, 105, 11, 0, 0, 0,
-		0, 0, 5, 100, 211, 255, 237, 97, 0, 0,
-		0, 0, 0, 0, 0, 67, 210, 255, 83, 0,
-		0, 0, 0, 0, 0, 0, 16, 244, 183, 0,
-		0, 0, 0, 0, 0, 0, 9, 247, 174, 0,
-		5, 155, 95, 71, 61, 87, 194, 254, 72, 0,
-		6, 226, 255, 255, 255, 254, 206, 78, 0, 0,
-		0, 0, 12, 33, 31, 5, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		31, 124, 124, 124, 124, 124, 124, 124, 124, 17,
-		54, 216, 216, 216, 249, 245, 216, 216, 216, 30,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		5, 124, 63, 0, 0, 0, 0, 75, 118, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		12, 255, 132, 0, 0, 0, 0, 156, 244, 0,
-		4, 253, 142, 0, 0, 0, 0, 169, 233, 0,
-		0, 223, 192, 0, 0, 0, 4, 224, 184, 0,
-		0, 132, 255, 142, 57, 62, 171, 254, 68, 0,
-		0, 5, 143, 245, 255, 255, 221, 74, 0, 0,
-		0, 0, 0, 5, 32, 26, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		110, 108, 0, 0, 0, 0, 0, 0, 110, 96,
-		164, 254, 29, 0, 0, 0, 0, 33, 255, 133,
-		79, 255, 111, 0, 0, 0, 0, 116, 255, 46,
-		8, 242, 193, 0, 0, 0, 0, 198, 215, 0,
-		0, 165, 253, 21, 0, 0, 25, 254, 128, 0,
-		0, 80, 255, 101, 0, 0, 106, 255, 41, 0,
-		0, 8, 243, 183, 0, 0, 188, 210, 0, 0,
-		0, 0, 166, 250, 15, 19, 251, 123, 0, 0,
-		0, 0, 81, 255, 91, 96, 255, 36, 0, 0,
-		0, 0, 8, 243, 171, 178, 205, 0, 0, 0,
-		0, 0, 0, 167, 243, 247, 118, 0, 0, 0,
-		0, 0, 0, 82, 255, 255, 32, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		73, 110, 0, 0, 0, 0, 0, 0, 112, 59,
-		139, 238, 0, 0, 0, 0, 0, 0, 242, 110,
-		121, 252, 0, 0, 0, 0, 0, 2, 254, 93,
-		103, 255, 11, 0, 0, 0, 0, 15, 255, 76,
-		86, 255, 26, 0, 110, 89, 0, 29, 255, 59,
-		68, 255, 41, 7, 241, 219, 0, 44, 255, 42,
-		50, 255, 56, 76, 244, 255, 54, 58, 255, 25,
-		32, 255, 71, 160, 158, 216, 144, 72, 255, 8,
-		15, 255, 91, 238, 78, 131, 231, 89, 247, 0,
-		1, 252, 166, 241, 7, 42, 255, 164, 230, 0,
-		0, 235, 245, 163, 0, 0, 209, 245, 213, 0,
-		0, 217, 255, 78, 0, 0, 120, 255, 196, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		47, 124, 58, 0, 0, 0, 0, 68, 124, 29,
-		10, 223, 225, 10, 0, 0, 20, 237, 194, 1,
-		0, 74, 255, 135, 0, 0, 158, 248, 40, 0,
-		0, 0, 169, 249, 40, 59, 254, 127, 0, 0,
-		0, 0, 24, 240, 190, 210, 214, 6, 0, 0,
-		0, 0, 0, 105, 255, 254, 60, 0, 0, 0,
-		0, 0, 0, 69, 255, 251, 45, 0, 0, 0,
-		0, 0, 11, 222, 210, 238, 201, 2, 0, 0,
-		0, 0, 144, 254, 57, 104, 255, 112, 0, 0,
-		0, 57, 253, 157, 0, 2, 203, 244, 31, 0,
-		7, 214, 238, 20, 0, 0, 54, 253, 184, 0,
-		133, 255, 105, 0, 0, 0, 0, 153, 255, 93,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		107, 121, 5, 0, 0, 0, 0, 5, 121, 93,
-		113, 255, 108, 0, 0, 0, 0, 106, 255, 85,
-		5, 219, 234, 13, 0, 0, 10, 231, 196, 0,
-		0, 80, 255, 130, 0, 0, 122, 254, 54, 0,
-		0, 0, 191, 244, 24, 17, 239, 165, 0, 0,
-		0, 0, 49, 253, 144, 134, 246, 29, 0, 0,
-		0, 0, 0, 157, 247, 243, 132, 0, 0, 0,
-		0, 0, 0, 24, 244, 232, 12, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 216, 188, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 106, 124, 124, 124, 124, 124, 124, 98, 0,
-		0, 206, 240, 240, 240, 240, 242, 255, 198, 0,
-		0, 0, 0, 0, 0, 0, 128, 255, 76, 0,
-		0, 0, 0, 0, 0, 47, 250, 161, 0, 0,
-		0, 0, 0, 0, 4, 206, 229, 16, 0, 0,
-		0, 0, 0, 0, 126, 255, 76, 0, 0, 0,
-		0, 0, 0, 45, 250, 161, 0, 0, 0, 0,
-		0, 0, 4, 205, 230, 17, 0, 0, 0, 0,
-		0, 0, 125, 255, 76, 0, 0, 0, 0, 0,
-		0, 45, 249, 162, 0, 0, 0, 0, 0, 0,
-		1, 204, 255, 133, 116, 116, 116, 116, 105, 0,
-		8, 255, 255, 255, 255, 255, 255, 255, 232, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 182, 188, 188, 188, 67, 0, 0,
-		0, 0, 0, 248, 190, 128, 128, 46, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 124, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 206, 160, 160, 57, 0, 0,
-		0, 0, 0, 151, 156, 156, 156, 56, 0, 0,
-	],
-	[
-		0, 54, 101, 0, 0, 0, 0, 0, 0, 0,
-		0, 53, 255, 63, 0, 0, 0, 0, 0, 0,
-		0, 0, 201, 171, 0, 0, 0, 0, 0, 0,
-		0, 0, 93, 251, 27, 0, 0, 0, 0, 0,
-		0, 0, 7, 234, 131, 0, 0, 0, 0, 0,
-		0, 0, 0, 133, 232, 6, 0, 0, 0, 0,
-		0, 0, 0, 29, 252, 90, 0, 0, 0, 0,
-		0, 0, 0, 0, 174, 198, 0, 0, 0, 0,
-		0, 0, 0, 0, 66, 255, 49, 0, 0, 0,
-		0, 0, 0, 0, 0, 213, 158, 0, 0, 0,
-		0, 0, 0, 0, 0, 106, 246, 18, 0, 0,
-		0, 0, 0, 0, 0, 12, 241, 118, 0, 0,
-		0, 0, 0, 0, 0, 0, 146, 223, 2, 0,
-		0, 0, 0, 0, 0, 0, 39, 254, 77, 0,
-		0, 0, 0, 0, 0, 0, 0, 171, 159, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 88, 188, 188, 188, 161, 0, 0, 0,
-		0, 0, 60, 128, 128, 206, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 0, 0, 0, 156, 220, 0, 0, 0,
-		0, 0, 75, 160, 160, 218, 220, 0, 0, 0,
-		0, 0, 73, 156, 156, 156, 134, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 106, 83, 0, 0, 0, 0,
-		0, 0, 0, 73, 244, 249, 37, 0, 0, 0,
-		0, 0, 4, 215, 107, 181, 184, 0, 0, 0,
-		0, 0, 110, 219, 5, 38, 250, 83, 0, 0,
-		0, 19, 238, 85, 0, 0, 141, 226, 10, 0,
-		0, 148, 201, 0, 0, 0, 15, 233, 135, 0,
-		0, 28, 15, 0, 0, 0, 0, 20, 27, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		168, 168, 168, 168, 168, 168, 168, 168, 168, 149,
-		156, 156, 156, 156, 156, 156, 156, 156, 156, 138,
-	],
-	[
-		0, 21, 107, 100, 3, 0, 0, 0, 0, 0,
-		0, 0, 80, 239, 168, 3, 0, 0, 0, 0,
-		0, 0, 0, 33, 168, 94, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 10, 154, 214, 244, 235, 187, 58, 0, 0,
-		0, 22, 197, 125, 92, 104, 219, 242, 30, 0,
-		0, 0, 0, 0, 0, 0, 56, 255, 104, 0,
-		0, 0, 0, 5, 31, 47, 67, 255, 116, 0,
-		0, 6, 149, 247, 255, 252, 252, 255, 116, 0,
-		0, 134, 252, 95, 11, 0, 24, 255, 116, 0,
-		0, 198, 211, 0, 0, 0, 56, 255, 116, 0,
-		0, 169, 248, 79, 40, 122, 240, 253, 116, 0,
-		0, 35, 209, 255, 255, 214, 68, 239, 116, 0,
-		0, 0, 0, 21, 26, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 64, 101, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 233, 32, 172, 240, 227, 108, 0, 0,
-		0, 152, 237, 228, 180, 105, 191, 255, 78, 0,
-		0, 152, 255, 141, 0, 0, 9, 239, 178, 0,
-		0, 152, 242, 1, 0, 0, 0, 180, 217, 0,
-		0, 152, 240, 0, 0, 0, 0, 161, 237, 0,
-		0, 152, 240, 0, 0, 0, 0, 180, 216, 0,
-		0, 152, 240, 0, 0, 0, 18, 242, 148, 0,
-		0, 152, 248, 87, 44, 70, 204, 238, 30, 0,
-		0, 87, 208, 254, 255, 252, 187, 42, 0, 0,
-		0, 0, 0, 4, 22, 8, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 81, 191, 235, 229, 188, 38, 0,
-		0, 0, 124, 255, 195, 111, 111, 165, 59, 0,
-		0, 38, 253, 174, 2, 0, 0, 0, 0, 0,
-		0, 120, 255, 48, 0, 0, 0, 0, 0, 0,
-		0, 150, 255, 7, 0, 0, 0, 0, 0, 0,
-		0, 140, 255, 27, 0, 0, 0, 0, 0, 0,
-		0, 85, 255, 118, 0, 0, 0, 0, 0, 0,
-		0, 5, 212, 250, 136, 65, 65, 119, 47, 0,
-		0, 0, 23, 168, 247, 255, 255, 226, 48, 0,
-		0, 0, 0, 0, 5, 26, 16, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 10, 108, 48, 0,
-		0, 0, 0, 0, 0, 0, 24, 255, 116, 0,
-		0, 0, 0, 0, 0, 0, 24, 255, 116, 0,
-		0, 0, 0, 0, 0, 0, 24, 255, 116, 0,
-		0, 0, 26, 151, 220, 234, 209, 255, 116, 0,
-		0, 25, 228, 223, 116, 94, 152, 255, 116, 0,
-		0, 154, 243, 23, 0, 0, 24, 255, 116, 0,
-		0, 233, 168, 0, 0, 0, 24, 255, 116, 0,
-		6, 255, 139, 0, 0, 0, 24, 255, 116, 0,
-		0, 248, 149, 0, 0, 0, 28, 255, 116, 0,
-		0, 214, 200, 0, 0, 1, 165, 255, 116, 0,
-		0, 120, 255, 124, 56, 172, 211, 251, 116, 0,
-		0, 8, 176, 255, 255, 191, 27, 241, 116, 0,
-		0, 0, 0, 22, 23, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 29, 165, 236, 241, 185, 53, 0, 0,
-		0, 20, 227, 210, 86, 87, 205, 246, 30, 0,
-		0, 142, 244, 24, 0, 0, 19, 242, 152, 0,
-		0, 217, 191, 24, 24, 24, 24, 205, 207, 0,
-		0, 243, 255, 255, 255, 255, 255, 255, 216, 0,
-		0, 231, 184, 20, 20, 20, 20, 20, 16, 0,
-		0, 180, 233, 14, 0, 0, 0, 0, 0, 0,
-		0, 67, 255, 197, 71, 34, 41, 76, 45, 0,
-		0, 0, 86, 221, 255, 255, 255, 240, 80, 0,
-		0, 0, 0, 0, 23, 35, 20, 1, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 54, 110, 126, 100, 19,
-		0, 0, 0, 0, 143, 255, 221, 198, 224, 58,
-		0, 0, 0, 47, 255, 135, 0, 0, 0, 0,
-		0, 0, 0, 97, 255, 38, 0, 0, 0, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		41, 140, 140, 192, 255, 150, 140, 140, 135, 0,
-		55, 188, 188, 218, 255, 194, 188, 188, 182, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		0, 0, 0, 116, 255, 24, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 56, 187, 237, 233, 212, 212, 212, 36,
-		0, 40, 246, 181, 61, 85, 229, 242, 109, 18,
-		0, 140, 252, 14, 0, 0, 99, 255, 49, 0,
-		0, 149, 250, 7, 0, 0, 91, 255, 57, 0,
-		0, 63, 254, 154, 28, 53, 217, 212, 5, 0,
-		0, 73, 249, 221, 255, 249, 180, 33, 0, 0,
-		0, 193, 177, 0, 14, 3, 0, 0, 0, 0,
-		0, 176, 247, 165, 137, 129, 108, 52, 0, 0,
-		0, 74, 255, 213, 206, 215, 243, 255, 151, 0,
-		8, 234, 179, 1, 0, 0, 5, 175, 254, 17,
-		30, 255, 140, 0, 0, 0, 0, 180, 246, 10,
-		0, 199, 253, 180, 142, 151, 208, 252, 104, 0,
-		0, 7, 93, 152, 172, 166, 127, 41, 0, 0,
-	],
-	[
-		0, 64, 101, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 236, 34, 181, 243, 217, 90, 0, 0,
-		0, 152, 242, 230, 170, 96, 196, 252, 38, 0,
-		0, 152, 255, 135, 0, 0, 42, 255, 103, 0,
-		0, 152, 241, 0, 0, 0, 9, 255, 126, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 99, 83, 0, 0, 0, 0,
-		0, 0, 0, 48, 255, 254, 23, 0, 0, 0,
-		0, 0, 0, 12, 191, 174, 3, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 43, 212, 212, 212, 202, 0, 0, 0, 0,
-		0, 22, 112, 112, 197, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 34, 72, 72, 181, 247, 72, 72, 42, 0,
-		0, 124, 255, 255, 255, 255, 255, 255, 152, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 34, 122, 24, 0, 0,
-		0, 0, 0, 0, 0, 174, 255, 154, 0, 0,
-		0, 0, 0, 0, 0, 92, 215, 75, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 102, 212, 212, 212, 212, 212, 82, 0, 0,
-		0, 54, 112, 112, 112, 134, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 100, 0, 0,
-		0, 0, 0, 0, 0, 40, 255, 99, 0, 0,
-		0, 0, 0, 0, 0, 60, 255, 84, 0, 0,
-		0, 13, 0, 0, 0, 156, 247, 26, 0, 0,
-		0, 209, 191, 160, 203, 254, 114, 0, 0, 0,
-		0, 81, 151, 171, 150, 58, 0, 0, 0, 0,
-	],
-	[
-		0, 43, 108, 13, 0, 0, 0, 0, 0, 0,
-		0, 104, 255, 32, 0, 0, 0, 0, 0, 0,
-		0, 104, 255, 32, 0, 0, 0, 0, 0, 0,
-		0, 104, 255, 32, 0, 0, 0, 0, 0, 0,
-		0, 104, 255, 32, 0, 0, 67, 210, 144, 1,
-		0, 104, 255, 32, 0, 64, 245, 185, 8, 0,
-		0, 104, 255, 32, 61, 244, 182, 8, 0, 0,
-		0, 104, 255, 91, 243, 179, 7, 0, 0, 0,
-		0, 104, 255, 190, 255, 66, 0, 0, 0, 0,
-		0, 104, 255, 40, 197, 239, 46, 0, 0, 0,
-		0, 104, 255, 32, 19, 218, 231, 34, 0, 0,
-		0, 104, 255, 32, 0, 36, 235, 221, 24, 0,
-		0, 104, 255, 32, 0, 0, 57, 246, 209, 16,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 21, 108, 108, 108, 102, 0, 0, 0, 0,
-		0, 43, 216, 216, 239, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 0, 0, 0, 152, 244, 0, 0, 0, 0,
-		0, 34, 72, 72, 181, 247, 72, 72, 42, 0,
-		0, 124, 255, 255, 255, 255, 255, 255, 152, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		43, 205, 44, 219, 215, 25, 155, 238, 120, 0,
-		52, 255, 200, 112, 237, 188, 189, 159, 245, 4,
-		52, 255, 156, 0, 195, 246, 27, 78, 255, 23,
-		52, 255, 58, 0, 192, 170, 0, 76, 255, 28,
-		52, 255, 52, 0, 192, 164, 0, 76, 255, 28,
-		52, 255, 52, 0, 192, 164, 0, 76, 255, 28,
-		52, 255, 52, 0, 192, 164, 0, 76, 255, 28,
-		52, 255, 52, 0, 192, 164, 0, 76, 255, 28,
-		52, 255, 52, 0, 192, 164, 0, 76, 255, 28,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 125, 166, 35, 181, 243, 218, 94, 0, 0,
-		0, 152, 231, 233, 164, 95, 195, 252, 39, 0,
-		0, 152, 255, 129, 0, 0, 41, 255, 104, 0,
-		0, 152, 241, 0, 0, 0, 9, 255, 127, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 50, 180, 239, 241, 190, 63, 0, 0,
-		0, 55, 246, 205, 96, 109, 216, 251, 63, 0,
-		0, 199, 223, 11, 0, 0, 17, 238, 194, 0,
-		19, 255, 132, 0, 0, 0, 0, 157, 251, 5,
-		43, 255, 103, 0, 0, 0, 0, 128, 255, 19,
-		32, 255, 123, 0, 0, 0, 0, 150, 250, 4,
-		1, 234, 200, 0, 0, 0, 5, 228, 189, 0,
-		0, 118, 255, 161, 57, 56, 185, 252, 61, 0,
-		0, 1, 123, 239, 255, 255, 217, 73, 0, 0,
-		0, 0, 0, 4, 32, 24, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 125, 167, 32, 174, 240, 227, 108, 0, 0,
-		0, 152, 231, 227, 180, 105, 191, 255, 78, 0,
-		0, 152, 255, 141, 0, 0, 9, 239, 178, 0,
-		0, 152, 242, 1, 0, 0, 0, 180, 217, 0,
-		0, 152, 240, 0, 0, 0, 0, 163, 237, 0,
-		0, 152, 240, 0, 0, 0, 0, 186, 216, 0,
-		0, 152, 240, 0, 0, 0, 22, 245, 147, 0,
-		0, 152, 248, 86, 44, 73, 205, 237, 29, 0,
-		0, 152, 254, 251, 255, 252, 187, 42, 0, 0,
-		0, 152, 240, 6, 24, 8, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 152, 240, 0, 0, 0, 0, 0, 0, 0,
-		0, 92, 146, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 26, 151, 219, 232, 198, 190, 107, 0,
-		0, 26, 228, 222, 112, 91, 150, 255, 116, 0,
-		0, 154, 243, 22, 0, 0, 24, 255, 116, 0,
-		0, 232, 168, 0, 0, 0, 24, 255, 116, 0,
-		6, 255, 139, 0, 0, 0, 24, 255, 116, 0,
-		0, 248, 149, 0, 0, 0, 28, 255, 116, 0,
-		0, 214, 204, 0, 0, 1, 165, 255, 116, 0,
-		0, 120, 255, 128, 56, 172, 216, 255, 116, 0,
-		0, 9, 178, 255, 255, 188, 43, 255, 116, 0,
-		0, 0, 0, 23, 22, 0, 23, 255, 116, 0,
-		0, 0, 0, 0, 0, 0, 24, 255, 116, 0,
-		0, 0, 0, 0, 0, 0, 24, 255, 116, 0,
-		0, 0, 0, 0, 0, 0, 14, 156, 70, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 49, 212, 38, 95, 214, 238, 174, 21, 0,
-		0, 60, 255, 176, 236, 121, 117, 248, 163, 0,
-		0, 60, 255, 231, 36, 0, 0, 167, 236, 0,
-		0, 60, 255, 91, 0, 0, 0, 77, 138, 0,
-		0, 60, 255, 80, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 80, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 80, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 80, 0, 0, 0, 0, 0, 0,
-		0, 60, 255, 80, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 39, 172, 234, 247, 232, 164, 0, 0,
-		0, 12, 227, 208, 101, 81, 102, 117, 0, 0,
-		0, 58, 255, 87, 0, 0, 0, 0, 0, 0,
-		0, 17, 239, 227, 99, 15, 0, 0, 0, 0,
-		0, 0, 52, 199, 255, 250, 179, 44, 0, 0,
-		0, 0, 0, 0, 40, 126, 233, 244, 31, 0,
-		0, 0, 0, 0, 0, 0, 72, 255, 88, 0,
-		0, 55, 98, 57, 40, 57, 171, 251, 40, 0,
-		0, 89, 248, 255, 255, 255, 216, 78, 0, 0,
-		0, 0, 3, 25, 36, 21, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 92, 79, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 140, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 140, 0, 0, 0, 0, 0,
-		96, 212, 212, 254, 236, 212, 212, 212, 109, 0,
-		52, 116, 116, 251, 192, 116, 116, 116, 59, 0,
-		0, 0, 0, 248, 140, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 140, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 140, 0, 0, 0, 0, 0,
-		0, 0, 0, 248, 140, 0, 0, 0, 0, 0,
-		0, 0, 0, 231, 164, 0, 0, 0, 0, 0,
-		0, 0, 0, 172, 249, 105, 50, 64, 51, 0,
-		0, 0, 0, 28, 187, 254, 255, 255, 121, 0,
-		0, 0, 0, 0, 0, 7, 28, 13, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 125, 198, 0, 0, 0, 6, 212, 106, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 152, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 151, 240, 0, 0, 0, 8, 255, 128, 0,
-		0, 135, 253, 8, 0, 0, 111, 255, 128, 0,
-		0, 83, 255, 146, 50, 131, 239, 247, 128, 0,
-		0, 2, 162, 255, 255, 215, 53, 228, 128, 0,
-		0, 0, 0, 13, 31, 1, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		55, 212, 101, 0, 0, 0, 0, 122, 210, 20,
-		3, 228, 209, 0, 0, 0, 3, 230, 186, 0,
-		0, 131, 255, 47, 0, 0, 72, 255, 86, 0,
-		0, 32, 254, 142, 0, 0, 167, 235, 6, 0,
-		0, 0, 187, 232, 4, 14, 246, 142, 0, 0,
-		0, 0, 87, 255, 75, 99, 255, 42, 0, 0,
-		0, 0, 6, 236, 164, 189, 198, 0, 0, 0,
-		0, 0, 0, 143, 243, 252, 98, 0, 0, 0,
-		0, 0, 0, 42, 255, 242, 11, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		130, 183, 0, 0, 0, 0, 0, 1, 207, 109,
-		123, 248, 1, 0, 0, 0, 0, 22, 255, 98,
-		87, 255, 26, 0, 116, 92, 0, 50, 255, 61,
-		50, 255, 58, 9, 244, 221, 0, 79, 255, 23,
-		13, 255, 90, 80, 232, 253, 57, 107, 242, 0,
-		0, 233, 122, 162, 147, 195, 148, 136, 205, 0,
-		0, 196, 159, 239, 62, 108, 234, 167, 168, 0,
-		0, 159, 241, 229, 2, 23, 252, 244, 131, 0,
-		0, 122, 255, 146, 0, 0, 188, 255, 94, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		8, 185, 198, 16, 0, 0, 10, 191, 184, 8,
-		0, 64, 253, 163, 0, 0, 145, 252, 61, 0,
-		0, 0, 127, 255, 81, 63, 254, 122, 0, 0,
-		0, 0, 2, 191, 234, 222, 185, 1, 0, 0,
-		0, 0, 0, 41, 255, 253, 29, 0, 0, 0,
-		0, 0, 0, 176, 240, 248, 154, 0, 0, 0,
-		0, 0, 119, 255, 89, 112, 255, 99, 0, 0,
-		0, 63, 252, 165, 0, 1, 186, 248, 52, 0,
-		25, 231, 225, 15, 0, 0, 26, 236, 225, 20,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		54, 212, 102, 0, 0, 0, 0, 120, 210, 21,
-		2, 225, 212, 0, 0, 0, 1, 227, 190, 0,
-		0, 126, 255, 53, 0, 0, 64, 255, 93, 0,
-		0, 27, 252, 151, 0, 0, 155, 241, 10, 0,
-		0, 0, 177, 239, 8, 7, 239, 154, 0, 0,
-		0, 0, 75, 255, 89, 81, 255, 57, 0, 0,
-		0, 0, 2, 225, 181, 174, 215, 0, 0, 0,
-		0, 0, 0, 126, 250, 249, 118, 0, 0, 0,
-		0, 0, 0, 27, 252, 249, 22, 0, 0, 0,
-		0, 0, 0, 46, 253, 155, 0, 0, 0, 0,
-		0, 0, 18, 208, 241, 27, 0, 0, 0, 0,
-		91, 186, 237, 244, 71, 0, 0, 0, 0, 0,
-		79, 163, 127, 30, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 92, 212, 212, 212, 212, 212, 212, 56, 0,
-		0, 50, 116, 116, 116, 116, 226, 247, 37, 0,
-		0, 0, 0, 0, 0, 99, 255, 100, 0, 0,
-		0, 0, 0, 0, 49, 247, 157, 0, 0, 0,
-		0, 0, 0, 16, 222, 206, 8, 0, 0, 0,
-		0, 0, 1, 179, 239, 33, 0, 0, 0, 0,
-		0, 0, 122, 254, 76, 0, 0, 0, 0, 0,
-		0, 65, 252, 189, 76, 76, 76, 76, 41, 0,
-		0, 144, 255, 255, 255, 255, 255, 255, 140, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 1, 96, 171, 166, 0, 0,
-		0, 0, 0, 0, 144, 253, 171, 116, 0, 0,
-		0, 0, 0, 2, 247, 143, 0, 0, 0, 0,
-		0, 0, 0, 24, 255, 88, 0, 0, 0, 0,
-		0, 0, 0, 28, 255, 84, 0, 0, 0, 0,
-		0, 0, 0, 31, 255, 80, 0, 0, 0, 0,
-		0, 0, 0, 83, 255, 41, 0, 0, 0, 0,
-		0, 99, 189, 233, 123, 0, 0, 0, 0, 0,
-		0, 81, 165, 241, 160, 0, 0, 0, 0, 0,
-		0, 0, 0, 81, 255, 55, 0, 0, 0, 0,
-		0, 0, 0, 30, 255, 86, 0, 0, 0, 0,
-		0, 0, 0, 28, 255, 88, 0, 0, 0, 0,
-		0, 0, 0, 28, 255, 88, 0, 0, 0, 0,
-		0, 0, 0, 21, 255, 95, 0, 0, 0, 0,
-		0, 0, 0, 0, 240, 165, 0, 0, 0, 0,
-		0, 0, 0, 0, 119, 255, 204, 144, 0, 0,
-		0, 0, 0, 0, 0, 65, 131, 138, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 196, 172, 0, 0, 0, 0,
-		0, 0, 0, 0, 119, 104, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 183, 158, 82, 0, 0, 0, 0, 0,
-		0, 0, 130, 183, 255, 116, 0, 0, 0, 0,
-		0, 0, 0, 0, 176, 222, 0, 0, 0, 0,
-		0, 0, 0, 0, 120, 253, 2, 0, 0, 0,
-		0, 0, 0, 0, 116, 255, 4, 0, 0, 0,
-		0, 0, 0, 0, 112, 255, 7, 0, 0, 0,
-		0, 0, 0, 0, 75, 255, 67, 0, 0, 0,
-		0, 0, 0, 0, 2, 153, 239, 191, 83, 0,
-		0, 0, 0, 0, 3, 170, 227, 156, 67, 0,
-		0, 0, 0, 0, 74, 255, 41, 0, 0, 0,
-		0, 0, 0, 0, 106, 255, 5, 0, 0, 0,
-		0, 0, 0, 0, 108, 255, 4, 0, 0, 0,
-		0, 0, 0, 0, 108, 255, 4, 0, 0, 0,
-		0, 0, 0, 0, 115, 251, 1, 0, 0, 0,
-		0, 0, 0, 1, 183, 216, 0, 0, 0, 0,
-		0, 0, 160, 210, 255, 98, 0, 0, 0, 0,
-		0, 0, 152, 137, 64, 0, 0, 0, 0, 0,
-	],
-	[
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 26, 128, 137, 37, 0, 0, 2, 32, 10,
-		7, 223, 232, 234, 245, 74, 0, 37, 255, 64,
-		76, 255, 37, 12, 182, 250, 139, 178, 240, 13,
-		47, 119, 0, 0, 5, 137, 231, 214, 75, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-	],
-];
\ No newline at end of file
+#[cfg(feature = "ttf")]
+use std::sync::Arc;
+
+#[cfg(feature = "ttf")]
+use ab_glyph::{Font, FontVec, PxScale, ScaleFont};
+
+use model::painting::TextStyle;
+
+include!(concat!(env!("OUT_DIR"), "/fonts.rs"));
+
+/// Width of a character in the bitmap font, in pixels
+pub const CHAR_WIDTH: usize = 10;
+/// Height of a line in the bitmap font, in pixels
+pub const CHAR_HEIGHT: usize = 17;
+
+/// Bitmap of the character, anything the font doesn't cover is drawn as a replacement box
+pub fn glyph(c: char) -> &'static [u8; 170] {
+	let code = c as usize;
+
+	if code < ASCII_TABLE.len() {
+		&ASCII_TABLE[code]
+	} else if (0xA0..=0xFF).contains(&code) {
+		&LATIN1_TABLE[code - 0xA0]
+	} else {
+		let i = EXTRA_CHARS.iter().position(|extra| *extra == c).unwrap_or(EXTRA_CHARS.len() - 1);
+
+		&EXTRA_TABLE[i]
+	}
+}
+
+/// Width in pixels of the longest line of the text when drawn at the scale in the bitmap font
+pub fn text_width(text: &str, scale: u8) -> usize {
+	text.split('\n').map(|line| line.chars().count()).max().unwrap_or(0) * CHAR_WIDTH * scale.max(1) as usize
+}
+
+/// Height in pixels of all lines of the text when drawn at the scale in the bitmap font
+pub fn text_height(text: &str, scale: u8) -> usize {
+	text.split('\n').count() * CHAR_HEIGHT * scale.max(1) as usize
+}
+
+/// Width and height in pixels of the text when drawn in the style at the scale in the bitmap font, which is what charts use unless a font is set
+///
+/// Glyphs are drawn in fixed cells with the spacing between characters included, so this is exactly the area `text_styled` paints in.
+pub fn measure_text(text: &str, scale: u8, style: TextStyle) -> (usize, usize) {
+	BITMAP.measure_styled(text, scale, style)
+}
+
+/// The font text is drawn in
+#[derive(Clone)]
+pub enum Typeface {
+	/// The built in bitmap font
+	Bitmap,
+	/// The built in bitmap font with each of its pixels drawn as a square this many pixels wide, for supersampling
+	ScaledBitmap(u8),
+	/// A TrueType font rasterised at a size in pixels
+	#[cfg(feature = "ttf")]
+	TrueType(Arc<FontVec>, f32),
+}
+
+pub(crate) static BITMAP: Typeface = Typeface::Bitmap;
+
+impl Typeface {
+	/// Parses a TrueType font, falls back to the bitmap font if the `ttf` feature is off or the font fails to parse
+	pub fn from_bytes(bytes: Vec<u8>, size_px: f32) -> Typeface {
+		#[cfg(feature = "ttf")] {
+			match FontVec::try_from_vec(bytes) {
+				Ok(font) => return Typeface::TrueType(Arc::new(font), size_px),
+				Err(err) => warn!("Failed to parse font, falling back to the bitmap font: {}", err),
+			}
+		}
+		#[cfg(not(feature = "ttf"))] {
+			let _ = (bytes, size_px);
+			warn!("The ttf feature is disabled, falling back to the bitmap font");
+		}
+
+		Typeface::Bitmap
+	}
+
+	/// The same font at a fraction of its size, the bitmap font only has the one size so is returned as is
+	pub fn resized(&self, factor: f32) -> Typeface {
+		#[cfg(not(feature = "ttf"))] {
+			let _ = factor;
+		}
+
+		match *self {
+			Typeface::Bitmap => Typeface::Bitmap,
+			Typeface::ScaledBitmap(n) => Typeface::ScaledBitmap(n),
+			#[cfg(feature = "ttf")]
+			Typeface::TrueType(ref font, size) => Typeface::TrueType(font.clone(), size * factor),
+		}
+	}
+
+	/// The same font drawn the factor times bigger, for rendering at a multiple of the size of the finished chart
+	pub fn supersampled(&self, factor: u8) -> Typeface {
+		match *self {
+			Typeface::Bitmap if factor > 1 => Typeface::ScaledBitmap(factor),
+			Typeface::Bitmap => Typeface::Bitmap,
+			Typeface::ScaledBitmap(n) => Typeface::ScaledBitmap(n.saturating_mul(factor.max(1))),
+			#[cfg(feature = "ttf")]
+			Typeface::TrueType(ref font, size) => Typeface::TrueType(font.clone(), size * factor.max(1) as f32),
+		}
+	}
+
+	/// Height of a line of text at the scale, in pixels
+	pub fn line_height(&self, scale: u8) -> usize {
+		let scale = scale.max(1) as usize;
+
+		match *self {
+			Typeface::Bitmap => CHAR_HEIGHT * scale,
+			Typeface::ScaledBitmap(n) => CHAR_HEIGHT * scale * n.max(1) as usize,
+			#[cfg(feature = "ttf")]
+			Typeface::TrueType(ref font, size) => {
+				let font = font.as_scaled(PxScale::from(size * scale as f32));
+
+				(font.height() + font.line_gap()).ceil() as usize
+			}
+		}
+	}
+
+	/// Width and height in pixels of the text when drawn at the scale
+	pub fn measure(&self, text: &str, scale: u8) -> (usize, usize) {
+		match *self {
+			Typeface::Bitmap => (text_width(text, scale), text_height(text, scale)),
+			Typeface::ScaledBitmap(n) => BITMAP.measure(text, scale.max(1).saturating_mul(n)),
+			#[cfg(feature = "ttf")]
+			Typeface::TrueType(ref font, size) => {
+				let font = font.as_scaled(PxScale::from(size * scale.max(1) as f32));
+				let width = text.split('\n')
+					.map(|line| line.chars().map(|c| font.h_advance(font.glyph_id(c))).sum::<f32>())
+					.fold(0., f32::max);
+
+				(width.ceil() as usize, text.split('\n').count() * self.line_height(scale))
+			}
+		}
+	}
+
+	/// Width and height in pixels of the text when drawn in the style at the scale
+	pub fn measure_styled(&self, text: &str, scale: u8, style: TextStyle) -> (usize, usize) {
+		let (width, height) = self.measure(text, scale);
+
+		match style {
+			TextStyle::Regular => (width, height),
+			TextStyle::Bold => (width + self.bold_offset(), height),
+		}
+	}
+
+	/// Calls back with the coverage of every pixel of the text drawn in the style, bold text is struck twice a pixel of the finished chart apart
+	pub fn rasterise_styled<F: FnMut(usize, usize, u8)>(&self, text: &str, scale: u8, style: TextStyle, mut f: F) {
+		if style == TextStyle::Regular {
+			return self.rasterise(text, scale, f);
+		}
+
+		let (width, height) = self.measure_styled(text, scale, style);
+		let offset = self.bold_offset();
+		let mut coverage = vec![0u8; width * height];
+
+		self.rasterise(text, scale, |x, y, a| {
+			for &x in [x, x + offset].iter() {
+				if x < width && y < height {
+					let i = x + y * width;
+					coverage[i] = coverage[i].max(a);
+				}
+			}
+		});
+
+		for y in 0..height {
+			for x in 0..width {
+				f(x, y, coverage[x + y * width]);
+			}
+		}
+	}
+
+	/// How far apart bold text is struck, a pixel of the finished chart so that bold looks the same when supersampling
+	fn bold_offset(&self) -> usize {
+		match *self {
+			Typeface::ScaledBitmap(n) => n.max(1) as usize,
+			_ => 1,
+		}
+	}
+
+	/// Calls back with the coverage (0 - 255) of every pixel of the text, relative to the top left corner of the text
+	pub fn rasterise<F: FnMut(usize, usize, u8)>(&self, text: &str, scale: u8, mut f: F) {
+		let scale = match *self {
+			Typeface::ScaledBitmap(n) => scale.max(1) as usize * n.max(1) as usize,
+			_ => scale.max(1) as usize,
+		};
+
+		match *self {
+			Typeface::Bitmap | Typeface::ScaledBitmap(_) => {
+				let (mut column, mut row) = (0, 0);
+
+				for c in text.chars() {
+					if c == '\n' {
+						column = 0;
+						row += 1;
+						continue;
+					}
+
+					let (left, top) = (column * CHAR_WIDTH * scale, row * CHAR_HEIGHT * scale);
+
+					let font_face = glyph(c);
+					for delta_x in 0..CHAR_WIDTH * scale {
+						for delta_y in 0..CHAR_HEIGHT * scale {
+							f(left + delta_x, top + delta_y, font_face[delta_x / scale + (delta_y / scale) * CHAR_WIDTH]);
+						}
+					}
+
+					column += 1;
+				}
+			}
+			#[cfg(feature = "ttf")]
+			Typeface::TrueType(ref font, size) => {
+				let scaled = font.as_scaled(PxScale::from(size * scale as f32));
+				let line_height = self.line_height(scale as u8) as f32;
+
+				for (row, line) in text.split('\n').enumerate() {
+					let baseline = scaled.ascent() + row as f32 * line_height;
+					let mut caret = 0.;
+
+					for c in line.chars() {
+						let id = scaled.glyph_id(c);
+						let glyph = id.with_scale_and_position(scaled.scale, ab_glyph::point(caret, baseline));
+						caret += scaled.h_advance(id);
+
+						if let Some(outline) = font.outline_glyph(glyph) {
+							let bounds = outline.px_bounds();
+
+							outline.draw(|x, y, coverage| {
+								let (x, y) = (bounds.min.x + x as f32, bounds.min.y + y as f32);
+
+								if x >= 0. && y >= 0. {
+									f(x as usize, y as usize, (coverage.min(1.) * 255.).round() as u8);
+								}
+							});
+						}
+					}
+				}
+			}
+		}
+	}
+}
@@ -1,33 +1,135 @@
+#[cfg(feature = "ttf")]
+extern crate ab_glyph;
+extern crate deflate;
 extern crate image;
 #[macro_use]
 extern crate log;
+#[cfg(feature = "cache")]
+extern crate lru;
+#[cfg(feature = "parquet")]
+extern crate parquet;
+extern crate png;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate tempdir;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 
 use std::boxed::Box;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
 use std::path::*;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
+#[cfg(feature = "cache")]
+use std::sync::Mutex;
+use std::time::Instant;
 
 use tempdir::*;
 
+#[cfg(feature = "cache")]
+pub use cache::RenderCache;
+pub use csv_io::{parse_csv, read_csv};
 pub use data::*;
+pub use encoding::CompressionLevel;
+pub use error::{InvalidCandle, OHLCError};
+pub use manifest::{DrawnElement, RenderManifest};
+#[cfg(feature = "parquet")]
+pub use parquet_io::{read_parquet, write_parquet};
+pub use renderer::Renderer;
+pub use stats::RenderStats;
+use encoding::encode_png;
+use fonts::Typeface;
 use model::*;
-use model::painting::Point;
+use model::painting::{Point, TextStyle};
 use model::rex::*;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use stats::Phase;
 pub use utils::*;
 
-pub mod fonts {
-	include!(concat!(env!("OUT_DIR"), "/fonts.rs"));
+/// Span of a part of the render with the `tracing` feature, `()` without it
+#[cfg(feature = "tracing")]
+macro_rules! render_span {
+	($name:expr) => { ::tracing::debug_span!($name) };
+}
+#[cfg(not(feature = "tracing"))]
+macro_rules! render_span {
+	($name:expr) => { () };
 }
 
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod csv_io;
 pub mod data;
+pub mod encoding;
+pub mod error;
+pub mod fonts;
+pub mod manifest;
 pub mod model;
+pub mod renderer;
+#[cfg(feature = "parquet")]
+pub mod parquet_io;
+#[cfg(test)]
+mod snapshots;
+pub mod stats;
+pub mod testing;
 #[cfg(test)]
 mod tests;
 pub mod utils;
 
+/// Returns: (width, height) of charts in pixels, not counting extension strips
+const CHART_SIZE: (usize, usize) = (1310, 650);
+
+/// Size of the subtitle's font relative to the title's
+const SUBTITLE_SIZE: f32 = 0.75;
+
+/// Drawn in the title's colour between the title and each part appended to it
+const TITLE_SEPARATOR: &str = " / ";
+
+/// Most pixels a supersampled chart is drawn with before being scaled down, not counting extension strips. 3 times a 1310x650 chart fits.
+pub const MAX_SUPERSAMPLED_PIXELS: usize = 8_000_000;
+
+/// Largest charts that are rendered, bigger ones fail before anything is allocated, eg. to keep requests to a public bot from running the host out of memory
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RenderLimits {
+	/// Most candles rendered, counted after `max_candles` leaves off the older ones
+	pub max_candles: usize,
+	/// Most pixels drawn, not counting extension strips. Supersampling is lowered to fit before this fails.
+	pub max_pixels: usize,
+}
+
+impl Default for RenderLimits {
+	fn default() -> RenderLimits {
+		RenderLimits { max_candles: 50_000, max_pixels: MAX_SUPERSAMPLED_PIXELS }
+	}
+}
+
+/// What's done with candles that fail validation, such as a high a rounding error below the close
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValidationMode {
+	/// Fail the render with the first invalid candle
+	Strict,
+	/// Raise the high and lower the low to take in the open and close, failing on candles that can't be repaired that way, such as ones with prices that aren't finite
+	Clamp,
+	/// Leave the invalid candles off the chart
+	Skip,
+}
+
+/// Which candles of the data are charted
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataWindow {
+	/// Candles from the `start` index up to but not including the `end` index
+	Candles { start: usize, end: usize },
+	/// Candles starting from `from` up to but not including `to`, in Unix seconds, placed by `start_time` or `reference_time` and the time units
+	Time { from: i64, to: i64 },
+}
+
 /// OHLC Chart Configuration, mutate through the methods
 #[derive(Serialize, Deserialize)]
 pub struct OHLCRenderOptions<C> {
@@ -35,25 +137,110 @@ pub struct OHLCRenderOptions<C> {
 	pub title: String,
 	/// Colour for the title of the chart
 	pub title_colour: u32,
+	/// Parts appended to the title after a separator, each in its own colour
+	pub title_parts: Vec<(String, u32)>,
+	/// Second line of the header drawn in a smaller font below the title, eg. "1h | Binance"
+	pub subtitle: Option<String>,
+	/// Colour for the subtitle
+	pub subtitle_colour: u32,
 	/// Background tint of the entire chart (the tint is the value for all of R, G and B)
 	pub background_colour: u32,
+	/// Caption drawn vertically along the left edge of the chart, eg. "Price, USDT"
+	pub axis_caption: String,
+	/// Colour for the axis caption
+	pub axis_caption_colour: u32,
+	/// Top and bottom colours of a vertical gradient replacing the flat background, if any
+	pub background_gradient: Option<(u32, u32)>,
+	/// Colour of the plot area inside the margins, if it's to differ from the background
+	pub plot_background_colour: Option<u32>,
 	/// Colour for the "current value" dot and line across the chart
 	pub current_value_colour: u32,
 	/// The amount of time, in seconds, each OHLC objects represent
 	pub time_units: u64,
 	/// Colour for axes labels and grid lines
 	pub line_colour: u32,
-	/// Intervals for drawing price lines in currency units
+	/// Intervals for drawing price lines in currency units, on the multiples of it so that charts crossing zero, like spreads, have a line at zero
 	pub price_line_interval: f64,
+	/// Picks the prices of the price lines instead of stepping by `price_line_interval`
+	#[serde(skip)]
+	pub(crate) price_ticks_fn: Option<PriceTicksFn>,
+	/// How the price and time lines are stroked
+	pub grid_style: GridStyle,
+	/// Whether the prices of the price lines are marked by lines across the plot, ticks by the labels or both
+	pub price_line_marks: PriceLineMarks,
+	/// Decimal places of the price labels, detected from the data if unset
+	pub price_decimal_places: Option<u8>,
+	/// How the decimal places of the price labels are detected when `price_decimal_places` is unset
+	pub auto_decimals: AutoDecimals,
 	/// Intervals for time lines in time_units
 	pub time_line_interval: i64,
+	/// Unix timestamp of the start of the chart in seconds, aligns the time lines to round times if set
+	pub start_time: Option<i64>,
+	/// Unix timestamp of the end of the chart in seconds, aligns the time lines like `start_time` when that isn't set
+	pub reference_time: Option<i64>,
 	/// RGBA(8) Colour for when the OHLC indicates fall
 	pub down_colour: u32,
 	/// RGBA(8) Colour for when the OHLC indicates rise
 	pub up_colour: u32,
+	/// Draw the standard candles, disable to draw candles with an extension instead
+	pub candles: bool,
+	/// How candles are drawn, and the length in pixels of the ticks of OHLC bars
+	pub candle_style: (CandleStyle, usize),
+	/// Colour of the area under the line when the candle style is `CandleStyle::Area`
+	pub area_fill_colour: u32,
+	/// Price the line is split at when the candle style is `CandleStyle::Baseline`, and the colours above and below it
+	pub baseline: (f64, u32, u32),
+	/// Colours of the wicks of rising and falling candles, the body colours if unset
+	pub wick_colours: (Option<u32>, Option<u32>),
+	/// Which parts of the wicks of standard candles are drawn
+	pub wick_style: WickStyle,
+	/// Caps drawn at the ends of the wicks and their width in pixels
+	pub wick_cap: (WickCap, usize),
+	/// Blend the edges of candle bodies that fall between pixels instead of rounding them to whole pixels
+	pub antialias: bool,
+	/// Which candlestick bodies are outlined instead of filled
+	pub hollow_mode: HollowMode,
+	/// Picks the colours of each candle instead of `up_colour` and `down_colour`
+	#[serde(skip)]
+	pub(crate) candle_colour_fn: Option<CandleColourFn<C>>,
+	/// Frame drawn over everything else, if any
+	pub frame: Option<Frame>,
+	/// Invert the colours of the finished chart
+	pub invert_colours: bool,
+	/// Convert the finished chart to greyscale
+	pub grayscale: bool,
+	/// Draw the title and the current value's label in bold
+	pub bold_text: bool,
+	/// Limit extensions to drawing inside the plot area
+	pub clip_extensions: bool,
 	/// Additional rendering extensions
 	#[serde(skip)]
 	pub(crate) render_extensions: Vec<Box<dyn RendererExtension<Candle=C>>>,
+	/// Names of the extensions to skip when rendering
+	pub disabled_extensions: HashSet<String>,
+	/// TrueType font file and pixel size to draw text in, the bitmap font is used if unset
+	#[serde(skip)]
+	pub(crate) font: Option<(Vec<u8>, f32)>,
+	/// How hard PNG encoding works at making the file small
+	pub png_compression: CompressionLevel,
+	/// Times larger the chart is drawn before being scaled down to its size, 1 to draw it at its size
+	pub supersample: u8,
+	/// Decimal and grouping characters of the numbers in labels
+	pub number_locale: NumberLocale,
+	/// Most candles to render, the latest ones are kept if there are more
+	pub max_candles: Option<usize>,
+	/// The candles charted, all of them if unset
+	pub window: Option<DataWindow>,
+	/// Whether the extensions are drawn from the candles before those charted as well
+	pub warm_up_on_history: bool,
+	/// Largest charts rendered
+	pub limits: RenderLimits,
+	/// What's done with invalid candles
+	pub validation_mode: ValidationMode,
+	/// PNGs rendered before, looked up by the options and data
+	#[cfg(feature = "cache")]
+	#[serde(skip)]
+	pub(crate) cache: Option<Arc<Mutex<RenderCache>>>,
 }
 
 impl<C: Candle> OHLCRenderOptions<C> {
@@ -62,22 +249,88 @@ impl<C: Candle> OHLCRenderOptions<C> {
 		OHLCRenderOptions {
 			title: String::new(),
 			title_colour: 0,
+			title_parts: vec![],
+			subtitle: None,
+			subtitle_colour: 0,
 			background_colour: 0xDDDDDDFF,
+			axis_caption: String::new(),
+			axis_caption_colour: 0,
+			background_gradient: None,
+			plot_background_colour: None,
 			current_value_colour: 0x2E44EAFF,
 			// Default is 1 hour
 			time_units: 3600,
 			line_colour: 0xFFFFFFAA,
 			price_line_interval: 1.0,
+			price_ticks_fn: None,
+			grid_style: GridStyle::Solid,
+			price_line_marks: PriceLineMarks::Lines,
+			price_decimal_places: None,
+			auto_decimals: AutoDecimals::Data,
 			time_line_interval: 24,
+			start_time: None,
+			reference_time: None,
 			down_colour: 0xD33040FF,
 			up_colour: 0x27A819FF,
+			candles: true,
+			candle_style: (CandleStyle::Candlestick, 0),
+			area_fill_colour: 0x27A81940,
+			baseline: (0., 0x27A819FF, 0xD33040FF),
+			wick_colours: (None, None),
+			wick_style: WickStyle::Full,
+			wick_cap: (WickCap::None, 0),
+			antialias: false,
+			hollow_mode: HollowMode::Filled,
+			candle_colour_fn: None,
+			frame: None,
+			invert_colours: false,
+			grayscale: false,
+			bold_text: true,
+			clip_extensions: true,
 			render_extensions: vec![],
+			disabled_extensions: HashSet::new(),
+			font: None,
+			png_compression: CompressionLevel::Fast,
+			supersample: 1,
+			number_locale: NumberLocale::default(),
+			max_candles: None,
+			window: None,
+			warm_up_on_history: false,
+			limits: RenderLimits::default(),
+			validation_mode: ValidationMode::Strict,
+			#[cfg(feature = "cache")]
+			cache: None,
 		}
 	}
 
+	/// Sets the title, replacing any parts appended to it
 	pub fn title(&mut self, title: &str, colour: u32) -> &mut Self {
 		self.title = title.to_string();
 		self.title_colour = colour;
+		self.title_parts.clear();
+
+		self
+	}
+
+	/// Appends a part in its own colour to the title after a " / ", eg. "BTC / USDT", for building the title up from several sources
+	pub fn append_title(&mut self, part: &str, colour: u32) -> &mut Self {
+		self.title_parts.push((part.to_string(), colour));
+
+		self
+	}
+
+	/// Sets a second line of the header, drawn in a smaller font under the title
+	pub fn subtitle(&mut self, subtitle: &str, colour: u32) -> &mut Self {
+		self.subtitle = Some(subtitle.to_string());
+		self.subtitle_colour = colour;
+
+		self
+	}
+
+	/// Sets a caption to be drawn vertically along the left edge of the chart, the left margin grows to fit it
+	pub fn axis_caption(&mut self, caption: &str, colour: u32) -> &mut Self {
+		self.axis_caption = caption.to_string();
+		self.axis_caption_colour = colour;
 
 		self
 	}
@@ -98,12 +351,117 @@ impl<C: Candle> OHLCRenderOptions<C> {
 		self
 	}
 
+	/// Draws the price lines and their labels at the prices the function returns, given the `(low, high)` prices of the chart, instead of every price interval
+	///
+	/// Prices outside the chart are left off, and labels get as many decimal places as the prices need unless set with `price_decimal_places`.
+	pub fn price_ticks_fn<F>(&mut self, price_ticks: F) -> &mut Self
+		where F: Fn(f64, f64) -> Vec<f64> + Send + Sync + 'static {
+		self.price_ticks_fn = Some(Arc::new(price_ticks));
+
+		self
+	}
+
+	/// Strokes the price and time lines dashed or dotted instead of solid
+	pub fn grid_style(&mut self, style: GridStyle) -> &mut Self {
+		self.grid_style = style;
+
+		self
+	}
+
+	/// Marks the prices with short ticks out of the right edge of the plot by the labels instead of, or as well as, lines across it
+	pub fn price_line_marks(&mut self, marks: PriceLineMarks) -> &mut Self {
+		self.price_line_marks = marks;
+
+		self
+	}
+
+	/// Puts the time lines on multiples of their interval since the epoch, eg. on the hour or at midnight, given the Unix timestamp of the start of the first candle in seconds
+	pub fn start_time(&mut self, start_time: Option<i64>) -> &mut Self {
+		self.start_time = start_time;
+
+		self
+	}
+
+	/// Sets the time the chart ends at, in Unix seconds, for data without a known start such as the latest candles up to now
+	///
+	/// Rendering never reads the clock, the time lines come from this, `start_time` or the end of the data. With the options fixed, the rendered image is a pure function of the data, so renders can be cached or compared byte for byte.
+	pub fn reference_time(&mut self, reference_time: Option<i64>) -> &mut Self {
+		self.reference_time = reference_time;
+
+		self
+	}
+
+	/// Sets the decimal places of the price labels, `None` uses as many as the prices need
+	pub fn price_decimal_places(&mut self, places: Option<u8>) -> &mut Self {
+		self.price_decimal_places = places;
+
+		self
+	}
+
+	/// Picks the decimal places of the price labels from the prices of the data, the default, or from the order of magnitude of the price line interval so that data with many places, such as a forex pair, doesn't get needlessly long labels
+	///
+	/// Places set with `price_decimal_places` are always used instead.
+	pub fn auto_decimals(&mut self, auto_decimals: AutoDecimals) -> &mut Self {
+		self.auto_decimals = auto_decimals;
+
+		self
+	}
+
 	pub fn background_colour(&mut self, colour: u32) -> &mut Self {
 		self.background_colour = colour;
 
 		self
 	}
 
+	/// Fills the background with a vertical gradient from the top colour to the bottom colour instead of the flat background colour
+	pub fn background_gradient(&mut self, top: u32, bottom: u32) -> &mut Self {
+		self.background_gradient = Some((top, bottom));
+
+		self
+	}
+
+	/// Fills the plot area inside the margins with a colour of its own, on top of the background
+	pub fn plot_background_colour(&mut self, colour: u32) -> &mut Self {
+		self.plot_background_colour = Some(colour);
+
+		self
+	}
+
+	/// Draws a frame of the given thickness around either the plot area or the whole canvas, once everything else has rendered
+	pub fn frame(&mut self, kind: FrameKind, colour: u32, thickness: usize) -> &mut Self {
+		self.frame = Some(Frame { kind, colour, thickness });
+
+		self
+	}
+
+	/// Inverts every colour of the finished chart before it's saved, alpha isn't involved as the image has none
+	pub fn invert_colours(&mut self, enabled: bool) -> &mut Self {
+		self.invert_colours = enabled;
+
+		self
+	}
+
+	/// Converts the finished chart to greyscale before it's saved, for print output
+	pub fn grayscale(&mut self, enabled: bool) -> &mut Self {
+		self.grayscale = enabled;
+
+		self
+	}
+
+	/// Limits extensions to drawing inside the plot area, enabled by default. Extensions can still draw in the margins with `ChartBuffer::without_clip`.
+	pub fn clip_extensions(&mut self, enabled: bool) -> &mut Self {
+		self.clip_extensions = enabled;
+
+		self
+	}
+
+	/// Draws the title and the current value's label in bold, enabled by default
+	pub fn bold_text(&mut self, enabled: bool) -> &mut Self {
+		self.bold_text = enabled;
+
+		self
+	}
+
 	pub fn time_units(&mut self, time_units: u64) -> &mut Self {
 		self.time_units = time_units;
 
@@ -122,19 +480,238 @@ impl<C: Candle> OHLCRenderOptions<C> {
 		self
 	}
 
+	/// Number of extensions added, including disabled ones
+	pub fn extensions_count(&self) -> usize {
+		self.render_extensions.len()
+	}
+
+	/// Removes every extension added
+	pub fn clear_extensions(&mut self) -> &mut Self {
+		self.render_extensions.clear();
+
+		self
+	}
+
+	/// Removes the first extension with the name, returning whether there was one
+	pub fn remove_extension_by_name(&mut self, name: &str) -> bool {
+		match self.render_extensions.iter().position(|ext| ext.name() == name) {
+			Some(i) => {
+				self.render_extensions.remove(i);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Enables or disables every extension with the name, without removing it from the options. Extensions are enabled unless disabled here.
+	pub fn set_extension_enabled(&mut self, name: &str, enabled: bool) -> &mut Self {
+		if enabled {
+			self.disabled_extensions.remove(name);
+		} else {
+			self.disabled_extensions.insert(name.to_string());
+		}
+
+		self
+	}
+
+	/// Skips drawing the standard candles, so an extension like `GradientCandles` can draw them instead
+	pub fn no_candles(&mut self) -> &mut Self {
+		self.candles = false;
+
+		self
+	}
+
+	/// Draws the standard candles in the style, the tick length in pixels is only used by `CandleStyle::OhlcBar`
+	pub fn candle_style(&mut self, style: CandleStyle, tick_length: usize) -> &mut Self {
+		self.candle_style = (style, tick_length);
+
+		self
+	}
+
+	/// Sets the colour under the line when the candle style is `CandleStyle::Area`, the line itself is drawn in the up colour
+	pub fn area_fill_colour(&mut self, colour: u32) -> &mut Self {
+		self.area_fill_colour = colour;
+
+		self
+	}
+
+	/// Sets the price and colours for `CandleStyle::Baseline`, the line and the area up to the baseline are coloured by which side of it they are on
+	pub fn baseline(&mut self, baseline: f64, above_colour: u32, below_colour: u32) -> &mut Self {
+		self.baseline = (baseline, above_colour, below_colour);
+
+		self
+	}
+
+	/// Colours the wicks of rising and falling candles separately from their bodies, `None` keeps the body colour
+	pub fn wick_colours(&mut self, up_wick_colour: Option<u32>, down_wick_colour: Option<u32>) -> &mut Self {
+		self.wick_colours = (up_wick_colour, down_wick_colour);
+
+		self
+	}
+
+	/// Draws only some of the standard candles' wicks, eg. `WickStyle::BodyOnly` for plain blocks from the open to the close
+	pub fn wick_style(&mut self, wick_style: WickStyle) -> &mut Self {
+		self.wick_style = wick_style;
+
+		self
+	}
+
+	/// Caps the ends of the standard candles' wicks, eg. `WickCap::Tick` with a thin body makes an OHLC bar chart
+	pub fn wick_cap(&mut self, wick_cap: WickCap, cap_width: usize) -> &mut Self {
+		self.wick_cap = (wick_cap, cap_width);
+
+		self
+	}
+
+	/// Blends the edges of candle bodies so they all look the same width on dense charts, disabled by default for hard pixel edges
+	pub fn antialias(&mut self, antialias: bool) -> &mut Self {
+		self.antialias = antialias;
+
+		self
+	}
+
+	/// Outlines the bodies of candlesticks instead of filling them, eg. `HollowMode::PrevClose` for candles only filled when they closed lower than the one before
+	pub fn hollow_mode(&mut self, mode: HollowMode) -> &mut Self {
+		self.hollow_mode = mode;
+
+		self
+	}
+
+	/// Colours each candle with the function, which is given the candle and its index and returns `(body_colour, wick_colour)`
+	pub fn candle_colour_fn<F>(&mut self, colour_fn: F) -> &mut Self
+		where F: Fn(&C, usize) -> (u32, u32) + Send + Sync + 'static {
+		self.candle_colour_fn = Some(Arc::new(colour_fn));
+
+		self
+	}
+
+	/// Sets a TrueType font to draw all text in, needs the `ttf` feature. Falls back to the bitmap font if the feature is disabled or the font can't be parsed.
+	pub fn font_bytes(&mut self, bytes: Vec<u8>, size_px: f32) -> &mut Self {
+		self.font = Some((bytes, size_px));
+
+		self
+	}
+
+	/// Hash of everything that changes how the chart looks, equal for options that render the same charts, eg. to tell whether a chart's options changed
+	///
+	/// It's the same between runs of a build, but not between Rust versions. Extensions are hashed by their `config_key`, z-index and lore colour, so an extension of your own that doesn't override `config_key` hashes the same whatever its settings.
+	pub fn config_hash(&self) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		self.hash(&mut hasher);
+
+		hasher.finish()
+	}
+
+	pub fn is_extension_enabled(&self, name: &str) -> bool {
+		!self.disabled_extensions.contains(name)
+	}
+
+	/// Names of all extensions added, in the order they were added
+	pub fn extension_names(&self) -> Vec<String> {
+		self.render_extensions.iter().map(|ext| ext.name()).collect()
+	}
+
+	/// Trades the speed of encoding PNGs against their size, `CompressionLevel::Fast` by default
+	pub fn png_compression(&mut self, level: CompressionLevel) -> &mut Self {
+		self.png_compression = level;
+
+		self
+	}
+
+	/// Draws the chart 2 or 3 times larger and scales it down to its size, smoothing lines and text at the cost of the time and memory of the larger chart. 1 turns it off.
+	///
+	/// Each factor multiplies the pixels drawn by its square, so the factor is lowered as far as needed to keep within `MAX_SUPERSAMPLED_PIXELS`.
+	pub fn supersample(&mut self, factor: u8) -> &mut Self {
+		self.supersample = match factor {
+			0 => 1,
+			1..=3 => factor,
+			_ => 3,
+		};
+
+		self
+	}
+
+	/// Characters the numbers in labels are written with, eg. `NumberLocale::european()` for `7.630,79`
+	pub fn number_locale(&mut self, number_locale: NumberLocale) -> &mut Self {
+		self.number_locale = number_locale;
+
+		self
+	}
+
+	/// Renders only the last `max` candles of the data, so that long series don't make the candles too narrow to read
+	///
+	/// Everything is drawn from just those candles, including the extensions and the price range. The whole data is still validated. At least one candle is always kept.
+	pub fn max_candles(&mut self, max: usize) -> &mut Self {
+		self.max_candles = Some(max.max(1));
+
+		self
+	}
+
+	/// Charts only the candles from the `start` index up to but not including the `end` index, eg. to zoom into part of a long history
+	///
+	/// The window is taken before `max_candles`, which then keeps the latest candles of it. Renders of windows without any candles fail with `OHLCError::EmptyData`.
+	pub fn window(&mut self, start: usize, end: usize) -> &mut Self {
+		self.window = Some(DataWindow::Candles { start, end });
+
+		self
+	}
+
+	/// Charts only the candles starting from `from` up to but not including `to`, in Unix seconds
+	///
+	/// The candles are placed in time by `start_time`, or `reference_time` as the end of the data, and the time units, so renders fail with `OHLCError::Validation` without either.
+	pub fn time_window(&mut self, from: i64, to: i64) -> &mut Self {
+		self.window = Some(DataWindow::Time { from, to });
+
+		self
+	}
+
+	/// Draws the extensions from every candle before those charted as well, so that indicators like moving averages are already warmed up at the left edge of a window or of `max_candles`
+	///
+	/// Only the part over the charted candles is drawn. Extensions are given the candles from the start of the data, so the candle indices of `ArrowAnnotation` and `RSIDivergenceAnnotations` count from there,
+	/// and the times given to `HighlightBox`, `TrendLine` and `Annotation` are in seconds from the start of the data rather than of the chart.
+	pub fn warm_up_on_history(&mut self, warm_up: bool) -> &mut Self {
+		self.warm_up_on_history = warm_up;
+
+		self
+	}
+
+	/// Raises or lowers the largest charts that are rendered, renders beyond them fail with `OHLCError::TooLarge`
+	pub fn limits(&mut self, limits: RenderLimits) -> &mut Self {
+		self.limits = limits;
+
+		self
+	}
+
+	/// Repairs or leaves off invalid candles instead of failing, the extensions are given the data as it's drawn
+	///
+	/// Only applies to candle types that implement `Candle::with_high_low`, such as `OHLC`, others are validated strictly. A warning is logged for each candle repaired or left off and they're counted in the `RenderStats`.
+	pub fn validation_mode(&mut self, mode: ValidationMode) -> &mut Self {
+		self.validation_mode = mode;
+
+		self
+	}
+
+	/// Returns PNGs from the cache when `render_to_png` is given the same options and data again, the cache can be shared between charts
+	#[cfg(feature = "cache")]
+	pub fn cache(&mut self, cache: Arc<Mutex<RenderCache>>) -> &mut Self {
+		self.cache = Some(cache);
+
+		self
+	}
+
 	/// Renders the OHLC Chart by the data, using the configs provided.
 	///
 	/// Takes a lambda function for processing the image once it's rendered, do not do anything asynchronous with the image as it will be deleted as soon as the function finishes.
 	///
-	/// Returns an error string originating from OHLC if an error occurs, and the result of the callback function otherwise.
-	pub fn render<F, R>(&self, data: Vec<C>, callback: F) -> Result<R, String>
-		where F: Fn(&Path) -> R + Sized {
-		// Create temporary directory
-		if let Ok(dir) = TempDir::new(&format!("ohlc_render_{}", SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos())) {
+	/// Returns an error originating from OHLC if an error occurs, including the callback's error, and what the callback returned otherwise, eg. the image's bytes from `|path| fs::read(path)`.
+	pub fn render<F, R, E>(&self, data: Vec<C>, callback: F) -> Result<R, OHLCError>
+		where F: FnOnce(&Path) -> Result<R, E>, E: fmt::Display {
+		// Create temporary directory, TempDir adds a random suffix to the prefix so concurrent renders don't collide
+		if let Ok(dir) = TempDir::new("ohlc_render") {
 			let file_path = dir.path().join("chart.png");
 
 			let result = match self.render_and_save(data, &file_path) {
-				Ok(_) => Ok((callback)(&file_path)),
+				Ok(_) => (callback)(&file_path).map_err(|err| OHLCError::Callback(err.to_string())),
 				Err(err) => Err(err)
 			};
 
@@ -142,133 +719,660 @@ impl<C: Candle> OHLCRenderOptions<C> {
 
 			result
 		} else {
-			Err("Failed to create a temporary directory.".to_string())
+			Err(OHLCError::TempDir)
+		}
+	}
+
+	/// Renders the chart and saves it to the specified path, in the format of its extension. The compression level is used for PNGs.
+	pub fn render_and_save(&self, data: Vec<C>, path: &Path) -> Result<(), OHLCError> {
+		self.render_and_save_with_stats(data, path).map(|_| ())
+	}
+
+	/// Same as `render_and_save`, also returning how long each part of the render took
+	pub fn render_and_save_with_stats(&self, data: Vec<C>, path: &Path) -> Result<RenderStats, OHLCError> {
+		let mut stats = RenderStats::default();
+		let chart_buffer = self.render_into(&data, &mut Vec::new(), &mut stats)?;
+		let (width, height) = (chart_buffer.width() as u32, chart_buffer.height() as u32);
+
+		let is_png = path.extension().map(|ext| ext.to_string_lossy().eq_ignore_ascii_case("png")) == Some(true);
+
+		let phase = Phase::start(render_span!("encode"));
+
+		// File save occurs here
+		if is_png {
+			let png = encode_png(&chart_buffer.buffer, width, height, self.png_compression)?;
+			stats.bytes = png.len();
+
+			fs::write(path, png).map_err(|err| OHLCError::ImageWrite(format!("{:?}", err)))?;
+		} else {
+			image::save_buffer(path, &chart_buffer.buffer[..], width, height, image::RGB(8))
+				.map_err(|err| OHLCError::ImageWrite(format!("{:?}", err)))?;
+			stats.bytes = fs::metadata(path).map(|metadata| metadata.len() as usize).unwrap_or(0);
 		}
+
+		stats.encode = phase.end();
+		stats.log();
+
+		Ok(stats)
 	}
 
-	/// Renders the chart and saves it to the specified path
+	/// Renders the chart into the bytes of a PNG in memory, encoded at the compression level
 	///
-	/// Returns an error string if an error occurs
-	pub fn render_and_save(&self, data: Vec<C>, path: &Path) -> Result<(), String> {
-		let start_time = SystemTime::now();
+	/// With a cache set, the PNG rendered before for the same options and data is returned instead of rendering it again.
+	pub fn render_to_png(&self, data: Vec<C>) -> Result<Vec<u8>, OHLCError> {
+		#[cfg(feature = "cache")] {
+			if let Some(ref cache) = self.cache {
+				return cache::render_cached(self, cache, data);
+			}
+		}
+
+		self.render_to_png_with_stats(data).map(|(png, _)| png)
+	}
+
+	/// Same as `render_to_png`, also returning how long each part of the render took
+	pub fn render_to_png_with_stats(&self, data: Vec<C>) -> Result<(Vec<u8>, RenderStats), OHLCError> {
+		let mut stats = RenderStats::default();
+		let chart_buffer = self.render_into(&data, &mut Vec::new(), &mut stats)?;
+
+		let phase = Phase::start(render_span!("encode"));
+		let png = encode_png(&chart_buffer.buffer, chart_buffer.width() as u32, chart_buffer.height() as u32, self.png_compression)?;
+		stats.encode = phase.end();
+		stats.bytes = png.len();
+		stats.log();
 
-		if let Err(err) = validate(&data) {
-			return Err(format!("Data validation error: {}", err));
+		Ok((png, stats))
+	}
+
+	/// Same as `render_to_png`, also returning what was drawn where on the chart, in its pixels, for hit-testing such as tooltips over the image
+	///
+	/// The candles and the labels of the grid lines are recorded, along with the lines extensions draw through `draw_job`. Extensions drawing themselves in `apply` can record what they draw with `ChartBuffer::record`.
+	pub fn render_to_png_with_manifest(&self, data: Vec<C>) -> Result<(Vec<u8>, RenderManifest), OHLCError> {
+		let mut stats = RenderStats::default();
+		let mut chart_buffer = self.render_recording(&data, &mut Vec::new(), &mut stats, true, CHART_SIZE)?;
+
+		let phase = Phase::start(render_span!("encode"));
+		let png = encode_png(&chart_buffer.buffer, chart_buffer.width() as u32, chart_buffer.height() as u32, self.png_compression)?;
+		stats.encode = phase.end();
+		stats.bytes = png.len();
+		stats.log();
+
+		Ok((png, RenderManifest { elements: chart_buffer.manifest.take().unwrap_or_default() }))
+	}
+
+	/// Renders the chart into an image in memory, skipping the PNG encoding so it can be processed further with `image`
+	pub fn render_to_image(&self, data: Vec<C>) -> Result<image::DynamicImage, OHLCError> {
+		let chart_buffer = self.render_buffer(data)?;
+		let (width, height) = (chart_buffer.width() as u32, chart_buffer.height() as u32);
+
+		match image::ImageBuffer::from_raw(width, height, chart_buffer.buffer) {
+			Some(buffer) => Ok(image::DynamicImage::ImageRgb8(buffer)),
+			None => Err(OHLCError::ImageWrite("Chart buffer doesn't match its dimensions".to_string()))
+		}
+	}
+
+	/// Renders the chart into a region of a bigger RGB(8) image that's `full_width` by `full_height` pixels, such as a dashboard of several charts, skipping the PNG encoding
+	///
+	/// The chart is drawn at the size of the region, extension strips included, and pixels outside the region are left as they were. Fails with `OHLCError::Layout` if the region is too small for the margins and the strips.
+	pub fn render_into_region(&self, data: Vec<C>, buffer: &mut [u8], full_width: usize, full_height: usize, region: Rect) -> Result<RenderStats, OHLCError> {
+		if buffer.len() != full_width * full_height * 3 {
+			return Err(OHLCError::ImageWrite("Buffer doesn't match its dimensions".to_string()));
+		}
+		if region.left > region.right || region.top > region.bottom || region.right >= full_width || region.bottom >= full_height {
+			return Err(OHLCError::ImageWrite("Region doesn't fit in the buffer".to_string()));
+		}
+
+		let size = (region.right - region.left + 1, region.bottom - region.top + 1);
+		let mut stats = RenderStats::default();
+		let mut chart_buffer = self.render_recording(&data, &mut Vec::new(), &mut stats, false, size)?;
+
+		// How tall the strips are is only known once the extensions have added them, so the chart is drawn again that much shorter to fit them in
+		if chart_buffer.height() > size.1 {
+			let strips = chart_buffer.height() - size.1;
+			let mut pixels = chart_buffer.buffer;
+
+			stats = RenderStats::default();
+			chart_buffer = self.render_recording(&data, &mut pixels, &mut stats, false, (size.0, size.1.saturating_sub(strips)))?;
+		}
+		stats.log();
+
+		let row_length = chart_buffer.width().min(region.right - region.left + 1) * 3;
+		let rows = chart_buffer.height().min(region.bottom - region.top + 1);
+
+		for y in 0..rows {
+			let from = y * chart_buffer.width() * 3;
+			let to = ((region.top + y) * full_width + region.left) * 3;
+			buffer[to..to + row_length].copy_from_slice(&chart_buffer.buffer[from..from + row_length]);
+		}
+
+		Ok(stats)
+	}
+
+	/// Renders the chart into a buffer of raw RGB(8) pixels
+	pub(crate) fn render_buffer(&self, data: Vec<C>) -> Result<ChartBuffer, OHLCError> {
+		let mut stats = RenderStats::default();
+		let chart_buffer = self.render_into(&data, &mut Vec::new(), &mut stats)?;
+		stats.log();
+
+		Ok(chart_buffer)
+	}
+
+	/// Renders the chart into the vector of pixels, which is cleared first so that its allocation can be reused
+	///
+	/// The vector is only taken once the chart is sure to render, so it's left as it was on an error. Records the time taken by each part in the stats, leaving encoding to the caller.
+	pub(crate) fn render_into(&self, data: &[C], pixels: &mut Vec<u8>, stats: &mut RenderStats) -> Result<ChartBuffer, OHLCError> {
+		self.render_recording(data, pixels, stats, false, CHART_SIZE)
+	}
+
+	/// Same as `render_into`, also recording what's drawn where in the chart buffer's manifest if `record` is set
+	fn render_recording(&self, data: &[C], pixels: &mut Vec<u8>, stats: &mut RenderStats, record: bool, (width, height): (usize, usize)) -> Result<ChartBuffer, OHLCError> {
+		#[cfg(feature = "tracing")]
+		let _render = ::tracing::debug_span!("render", candles = data.len()).entered();
+		let mut phase = Phase::start(render_span!("validation"));
+
+		if data.is_empty() {
+			return Err(OHLCError::EmptyData);
+		}
+
+		let (_, first, end) = self.charted_range(data.len())?;
+		let candles = end - first;
+		if candles > self.limits.max_candles {
+			return Err(OHLCError::TooLarge(format!("{} candles is more than the limit of {}", candles, self.limits.max_candles)));
+		}
+		if width * height > self.limits.max_pixels {
+			return Err(OHLCError::TooLarge(format!("{}x{} pixels is more than the limit of {}", width, height, self.limits.max_pixels)));
+		}
+
+		let valid_data;
+		let data = match apply_validation_mode(data, self.validation_mode, stats).map_err(OHLCError::InvalidCandle)? {
+			Some(kept) => {
+				valid_data = kept;
+				&valid_data[..]
+			}
+			None => data,
+		};
+		if data.is_empty() {
+			return Err(OHLCError::EmptyData);
+		}
+
+		// All of the data is validated so that nothing wrong is hidden by leaving it off
+		let (history_start, first, end) = self.charted_range(data.len())?;
+		let after = data.len() - end;
+		let history = &data[history_start..end];
+		let data = &data[first..end];
+		stats.candle_count = data.len();
+
+		let timeframe = match seconds(self.time_units, data.len() as u64) {
+			Some(timeframe) => timeframe,
+			None => return Err(OHLCError::Validation(format!("{} candles of {} seconds is too long a time to chart.", data.len(), self.time_units))),
+		};
+		let lead = match seconds(self.time_units, (first - history_start) as u64) {
+			Some(lead) if lead.checked_add(timeframe).is_some() => lead,
+			_ => return Err(OHLCError::Validation(format!("{} candles of {} seconds is too long a time to warm up on.", first - history_start, self.time_units))),
+		};
+		// Time lines are laid out on multiples of their interval, which there are none of for an interval of nothing
+		let time_line_interval = match seconds(self.time_units, self.time_line_interval as u64) {
+			Some(interval) if self.time_line_interval >= 0 && interval > 0 => interval,
+			Some(_) if self.time_line_interval >= 0 => return Err(OHLCError::Validation(format!("Time lines every {} candles of {} seconds is not a step forward in time.", self.time_line_interval, self.time_units))),
+			_ => return Err(OHLCError::Validation(format!("Time lines every {} candles of {} seconds is too long a time.", self.time_line_interval, self.time_units))),
+		};
+		// Price lines are stepped up from the bottom of the chart until they pass the top, which they never would going down or by nothing
+		if !(self.price_line_interval.is_finite() && self.price_line_interval > 0.) {
+			return Err(OHLCError::Validation(format!("Price lines every {} is not a step up in price.", self.price_line_interval)));
 		}
 
-		#[cfg(test)] {
-			debug!("Validated input data @ {:?}", start_time.elapsed());
+		stats.validation = phase.next(|| render_span!("background"));
+
+		let ohlc_of_set = aggregate(data);
+		// A flat range, such as a stablecoin in a quiet hour, has no height to scale the prices to, so it's opened up by half a percent either way to keep the candles in the middle with price lines about them
+		let (max_price, min_price) = if ohlc_of_set.h == ohlc_of_set.l {
+			let padding = if ohlc_of_set.h == 0. { 1. } else { ohlc_of_set.h.abs() * 0.005 };
+
+			(ohlc_of_set.h + padding, ohlc_of_set.l - padding)
+		} else {
+			(ohlc_of_set.h, ohlc_of_set.l)
+		};
+
+		let max_pixels = self.limits.max_pixels.min(MAX_SUPERSAMPLED_PIXELS);
+		let mut scale = self.supersample.max(1) as usize;
+		while scale > 1 && width * height * scale * scale > max_pixels {
+			scale -= 1;
 		}
 
-		let ohlc_of_set = aggregate(&data[..]);
+		let typeface = match self.font {
+			Some((ref bytes, size_px)) => Typeface::from_bytes(bytes.clone(), size_px),
+			None => Typeface::Bitmap,
+		}.supersampled(scale as u8);
 
-		let margin = Margin {
-			top: 60,
-			bottom: 35,
-			left: 12,
-			right: 113,
+		let mut margin = Margin {
+			top: 60 * scale,
+			bottom: 35 * scale,
+			left: 12 * scale,
+			right: 113 * scale,
 		};
 
-		let width = 1310;
-		let height = 650;
+		if self.subtitle.is_some() {
+			// Room for the title and subtitle above the plot
+			let header = 8 * scale + typeface.line_height(1) + 2 * scale + typeface.resized(SUBTITLE_SIZE).line_height(1) + 8 * scale;
+			margin.top = margin.top.max(header);
+		}
+
+		if !self.axis_caption.is_empty() {
+			// Room for a line of rotated text
+			margin.left += typeface.line_height(1) + 4 * scale;
+		}
+
+		let (width, height) = (width * scale, height * scale);
+		check_plot_area(width, height, &margin)?;
 
-		#[cfg(test)] {
-			debug!("Allocated vector @ {:?}", start_time.elapsed());
+		let mut chart_buffer = ChartBuffer::reusing(::std::mem::take(pixels), width, height, margin, (min_price, max_price), timeframe, self.background_colour);
+		chart_buffer.typeface = typeface;
+		chart_buffer.scale = scale;
+		chart_buffer.locale = self.number_locale;
+		if record {
+			chart_buffer.manifest = Some(vec![]);
 		}
 
-		let mut chart_buffer = ChartBuffer::new(width, height, margin, ohlc_of_set.h, ohlc_of_set.l, (self.time_units * data.len() as u64) as i64, self.background_colour);
+		match self.background_gradient {
+			Some((top, bottom)) => chart_buffer.background_gradient(top, bottom),
+			None => chart_buffer.fill_background(),
+		}
 
-		#[cfg(test)] {
-			debug!("Allocated image and populated background @ {:?}", start_time.elapsed());
+		if let Some(colour) = self.plot_background_colour {
+			let plot = chart_buffer.plot_area();
+			chart_buffer.rect(plot.left, plot.top, plot.right, plot.bottom, colour);
 		}
 
-		GridLines::new(
+		stats.price_range = (chart_buffer.min_price, chart_buffer.max_price);
+		stats.background = phase.next(|| render_span!("grid"));
+
+		let mut grid_lines = GridLines::new(
 			self.line_colour,
 			true,
 			self.price_line_interval,
-			self.time_line_interval * self.time_units as i64).apply(&mut chart_buffer, &data[..]);
+			time_line_interval)
+			.decimal_places(self.price_decimal_places)
+			.auto_decimals(self.auto_decimals)
+			.style(self.grid_style)
+			.marks(self.price_line_marks)
+			.start_time(self.chart_start_time(first, after, timeframe));
+		if let Some(ref price_ticks) = self.price_ticks_fn {
+			grid_lines = grid_lines.price_ticks(price_ticks.clone());
+		}
+		grid_lines.apply(&mut chart_buffer, data);
 
-		#[cfg(test)] {
-			debug!("Rendered grid lines @ {:?}", start_time.elapsed());
+		stats.grid = phase.next(|| render_span!("candles"));
+
+		if self.candles {
+			let mut candles = OHLCCandles::new(self.up_colour, self.down_colour, self.wick_colours.0, self.wick_colours.1, self.wick_style)
+				.style(self.candle_style.0, self.candle_style.1)
+				.fill_colour(self.area_fill_colour)
+				.baseline(self.baseline.0, self.baseline.1, self.baseline.2)
+				.wick_cap(self.wick_cap.0, self.wick_cap.1)
+				.antialias(self.antialias)
+				.hollow(self.hollow_mode);
+			if let Some(ref colour_fn) = self.candle_colour_fn {
+				candles = candles.colour_fn(colour_fn.clone());
+			}
+			candles.apply(&mut chart_buffer, data);
 		}
 
-		OHLCCandles::new(self.up_colour, self.down_colour).apply(&mut chart_buffer, &data[..]);
+		stats.candles = phase.next(|| render_span!("labels"));
+
+		let emphasis = if self.bold_text { TextStyle::Bold } else { TextStyle::Regular };
 
-		#[cfg(test)] {
-			debug!("Rendered candles @ {:?}", start_time.elapsed());
+		BasicIndicativeLines::new(self.up_colour, self.down_colour, self.current_value_colour)
+			.current_style(emphasis)
+			.decimal_places(self.price_decimal_places)
+			.auto_decimals(self.auto_decimals, self.price_line_interval)
+			.apply(&mut chart_buffer, data);
+
+		chart_buffer.text_styled((8 * scale, 8 * scale), &self.title, self.title_colour, 1, emphasis);
+
+		let mut title_end = 8 * scale + chart_buffer.typeface.measure(&self.title, 1).0;
+		for &(ref part, colour) in &self.title_parts {
+			if title_end > 8 * scale {
+				chart_buffer.text_styled((title_end, 8 * scale), TITLE_SEPARATOR, self.title_colour, 1, emphasis);
+				title_end += chart_buffer.typeface.measure(TITLE_SEPARATOR, 1).0;
+			}
+
+			chart_buffer.text_styled((title_end, 8 * scale), part, colour, 1, emphasis);
+			title_end += chart_buffer.typeface.measure(part, 1).0;
 		}
 
-		BasicIndicativeLines::new(self.up_colour, self.down_colour, self.current_value_colour).apply(&mut chart_buffer, &data[..]);
+		if let Some(ref subtitle) = self.subtitle {
+			let typeface = chart_buffer.typeface.clone();
+			let top = 8 * scale + typeface.line_height(1) + 2 * scale;
 
-		#[cfg(test)] {
-			debug!("Rendered basic indicator lines @ {:?}", start_time.elapsed());
+			chart_buffer.typeface = typeface.resized(SUBTITLE_SIZE);
+			chart_buffer.text((8 * scale, top), subtitle, self.subtitle_colour);
+			chart_buffer.typeface = typeface;
 		}
 
-		chart_buffer.text((8, 8), &self.title, self.title_colour);
+		if !self.axis_caption.is_empty() {
+			let caption_length = chart_buffer.typeface.measure(&self.axis_caption, 1).0;
+			let plot_middle = (chart_buffer.margin.top + chart_buffer.height() - chart_buffer.margin.bottom) / 2;
 
-		#[cfg(test)] {
-			debug!("Added title text @ {:?}", start_time.elapsed());
+			chart_buffer.text_rotated((8 * scale, plot_middle.saturating_sub(caption_length / 2)), &self.axis_caption, self.axis_caption_colour, Rotation90::Deg270);
 		}
 
-		let mut lore_cursor: Point = (chart_buffer.margin.left + 4, chart_buffer.margin.top + 4);
+		stats.labels = phase.next(|| render_span!("extensions"));
 
-		for ext in &self.render_extensions {
-			ext.apply(&mut chart_buffer, &data[..]);
+		let mut lore_cursor: Point = (chart_buffer.margin.left + 4 * scale, chart_buffer.margin.top + 4 * scale);
 
-			if let Some(rgba) = ext.lore_colour() {
-				chart_buffer.text_with_background(lore_cursor, &ext.name(), rgba, 0x7F7F7F7F);
+		let mut extensions: Vec<&Box<dyn RendererExtension<Candle=C>>> = self.render_extensions.iter()
+			.filter(|ext| self.is_extension_enabled(&ext.name()))
+			.filter(|ext| {
+				let warmup = ext.warmup();
+				if history.len() < warmup {
+					#[cfg(feature = "tracing")]
+					::tracing::warn!(extension = %ext.name(), warmup, candles = history.len(), "Skipped extension without enough candles");
+					#[cfg(not(feature = "tracing"))]
+					warn!("Skipped {} as it needs {} candles and there are {}", ext.name(), warmup, history.len());
+				}
+
+				history.len() >= warmup
+			})
+			.collect();
+		extensions.sort_by_key(|ext| ext.z_index());
+
+		// The extensions are drawn over the history, which is left of the plot area where nothing is drawn
+		chart_buffer.timeframe += lead;
+		chart_buffer.lead = lead;
+		chart_buffer.lead_candles = first - history_start;
 
-				lore_cursor.1 += 17; // Move down 1 row, 17 is the char height
+		if self.clip_extensions {
+			let plot_area = chart_buffer.plot_area();
+			chart_buffer.set_clip(Some(plot_area));
+		}
+
+		// Working out what to draw doesn't touch the buffer, so with rayon it's done for all the extensions at once before they're drawn in order
+		// The jobs own what they need, so neither the extensions nor the candles have to be shared between threads. That time is counted towards the first extension's
+		let mut extension_start = Instant::now();
+		let jobs: Vec<Option<DrawJob>> = extensions.iter().map(|ext| ext.draw_job(history)).collect();
+		#[cfg(feature = "rayon")]
+		let commands: Vec<Option<Vec<DrawCommand>>> = {
+			let chart_buffer = &chart_buffer;
+
+			jobs.into_par_iter().map(|job| job.map(|job| job(chart_buffer))).collect()
+		};
+		#[cfg(not(feature = "rayon"))]
+		let commands: Vec<Option<Vec<DrawCommand>>> = jobs.into_iter().map(|job| job.map(|job| job(&chart_buffer))).collect();
+
+		for (ext, commands) in extensions.into_iter().zip(commands) {
+			#[cfg(feature = "tracing")]
+			let _span = ::tracing::debug_span!("extension", name = %ext.name()).entered();
+
+			match commands {
+				Some(commands) => {
+					if chart_buffer.is_recording() {
+						for command in &commands {
+							match *command {
+								DrawCommand::Polyline { ref points, .. } | DrawCommand::Spline { ref points, .. } => {
+									// Only where it's drawn, leaving off the history pinned left of the plot
+									let points = points.iter().cloned().filter(|&(x, y)| !chart_buffer.is_clipped(x, y)).collect();
+									chart_buffer.record(DrawnElement::ExtensionLine { extension: ext.name(), points });
+								}
+								_ => {}
+							}
+						}
+					}
+
+					chart_buffer.draw_all(&commands);
+				}
+				None => ext.apply(&mut chart_buffer, history),
 			}
 
-			#[cfg(test)] {
-				debug!("Rendered extension: {} @ {:?}", ext.name(), start_time.elapsed());
+			if let Some(rgba) = ext.lore_colour() {
+				chart_buffer.text_with_background(lore_cursor, &ext.name(), rgba, 0x7F7F7F7F, TextStyle::Regular);
+
+				lore_cursor.1 += chart_buffer.typeface.line_height(1); // Move down 1 row
 			}
+
+			stats.extensions.push((ext.name(), extension_start.elapsed()));
+			extension_start = Instant::now();
 		}
 
-		#[cfg(test)] {
-			debug!("Rendered extensions lores @ {:?}", start_time.elapsed());
+		chart_buffer.set_clip(None);
+		chart_buffer.timeframe -= lead;
+		chart_buffer.lead = 0;
+		chart_buffer.lead_candles = 0;
+
+		// The extensions' own times are already in the stats
+		phase.next(|| render_span!("finishing"));
+
+		chart_buffer.downsample();
+
+		if let Some(ref frame) = self.frame {
+			chart_buffer.frame(frame);
 		}
 
-		#[cfg(test)] {
-			debug!("Completed all rendering @ {:?}", start_time.elapsed());
+		if self.grayscale {
+			chart_buffer.grayscale();
+		}
+		if self.invert_colours {
+			chart_buffer.invert_colours();
 		}
 
-		// File save occurs here
-		if let Err(err) = image::save_buffer(path, &chart_buffer.buffer[..], chart_buffer.width() as u32, chart_buffer.height() as u32, image::RGB(8)) {
-			Err(format!("Image write error: {:?}", err))
-		} else {
-			#[cfg(test)] {
-				debug!("Chart PNG compression finished {:?}", start_time.elapsed());
+		stats.width = chart_buffer.width() as u32;
+		stats.height = chart_buffer.height() as u32;
+		stats.finishing = phase.end();
+
+		Ok(chart_buffer)
+	}
+
+	/// Returns: (first candle the extensions are drawn from, first candle charted, end of the candles charted) out of the number of candles
+	fn charted_range(&self, len: usize) -> Result<(usize, usize, usize), OHLCError> {
+		let (start, end) = match self.window {
+			None => (0, len),
+			Some(DataWindow::Candles { start, end }) => (start.min(len), end.min(len)),
+			Some(DataWindow::Time { from, to }) => {
+				let data_start = match (self.start_time, self.reference_time) {
+					(Some(start), _) => start,
+					(None, Some(end)) => end.saturating_sub(seconds(self.time_units, len as u64).unwrap_or(i64::MAX)),
+					(None, None) => return Err(OHLCError::Validation("A time window needs the start_time or reference_time of the data to know when the candles are.".to_string())),
+				};
+				// The first candle starting at or after the time
+				let index = |time: i64| ((time as f64 - data_start as f64) / self.time_units as f64).ceil().max(0.).min(len as f64) as usize;
+
+				(index(from), index(to))
 			}
+		};
+		if start >= end {
+			return Err(OHLCError::EmptyData);
+		}
 
-			debug!("Chart rendered in {:?}", start_time.elapsed());
+		let first = match self.max_candles {
+			Some(max) if end - start > max => end - max,
+			_ => start,
+		};
+
+		Ok((if self.warm_up_on_history { 0 } else { first }, first, end))
+	}
 
-			Ok(())
+	/// Unix timestamp of the start of the charted candles, from `start_time` or `reference_time` which are those of all the data, given how many candles are left off before and after them
+	fn chart_start_time(&self, before: usize, after: usize, timeframe: i64) -> Option<i64> {
+		let duration = |candles: usize| seconds(self.time_units, candles as u64).unwrap_or(i64::MAX);
+
+		match (self.start_time, self.reference_time) {
+			(Some(start), _) => Some(start.saturating_add(duration(before))),
+			(None, Some(end)) => Some(end.saturating_sub(duration(after)).saturating_sub(timeframe)),
+			(None, None) => None,
 		}
 	}
 }
 
-fn validate<C: Candle>(data: &Vec<C>) -> Result<(), &'static str> {
-	for elem in data {
-		let open = elem.open();
-		let high = elem.high();
-		let close = elem.close();
-		let low = elem.low();
-
-		return if open > high {
-			Err("Opening value is higher than high value.")
-		} else if close > high {
-			Err("Closing value is higher than high value.")
-		} else if low > high {
-			Err("Low value is higher than high value.")
-		} else if open < low {
-			Err("Opening value is lower than low value.")
-		} else if close < low {
-			Err("Closing value is lower than low value.")
-		} else {
-			continue;
-		};
+/// Hashes everything that changes how the chart looks, such as for keying cached renders
+///
+/// Extensions are told apart by their `config_key`, z-index and lore colour, and candle colour and price tick functions by being the same function.
+impl<C: Candle> Hash for OHLCRenderOptions<C> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.title.hash(state);
+		self.title_colour.hash(state);
+		self.title_parts.hash(state);
+		self.subtitle.hash(state);
+		self.subtitle_colour.hash(state);
+		self.background_colour.hash(state);
+		self.axis_caption.hash(state);
+		self.axis_caption_colour.hash(state);
+		self.background_gradient.hash(state);
+		self.plot_background_colour.hash(state);
+		self.current_value_colour.hash(state);
+		self.time_units.hash(state);
+		self.line_colour.hash(state);
+		self.price_line_interval.to_bits().hash(state);
+		self.grid_style.hash(state);
+		self.price_line_marks.hash(state);
+		self.price_decimal_places.hash(state);
+		self.auto_decimals.hash(state);
+		self.time_line_interval.hash(state);
+		self.start_time.hash(state);
+		self.reference_time.hash(state);
+		self.down_colour.hash(state);
+		self.up_colour.hash(state);
+		self.candles.hash(state);
+		self.candle_style.hash(state);
+		self.area_fill_colour.hash(state);
+		(self.baseline.0.to_bits(), self.baseline.1, self.baseline.2).hash(state);
+		self.wick_colours.hash(state);
+		self.wick_style.hash(state);
+		self.wick_cap.hash(state);
+		self.antialias.hash(state);
+		self.hollow_mode.hash(state);
+		self.candle_colour_fn.as_ref().map(|colour_fn| Arc::as_ptr(colour_fn) as *const () as usize).hash(state);
+		self.price_ticks_fn.as_ref().map(|price_ticks| Arc::as_ptr(price_ticks) as *const () as usize).hash(state);
+		self.frame.hash(state);
+		self.invert_colours.hash(state);
+		self.grayscale.hash(state);
+		self.bold_text.hash(state);
+		self.clip_extensions.hash(state);
+
+		self.render_extensions.len().hash(state);
+		for ext in &self.render_extensions {
+			ext.config_key().hash(state);
+			ext.z_index().hash(state);
+			ext.lore_colour().hash(state);
+		}
+
+		// Sorted as the order of a HashSet changes between sets
+		let mut disabled_extensions: Vec<&String> = self.disabled_extensions.iter().collect();
+		disabled_extensions.sort();
+		disabled_extensions.hash(state);
+
+		self.font.as_ref().map(|&(ref bytes, size_px)| (bytes, size_px.to_bits())).hash(state);
+		self.png_compression.hash(state);
+		self.supersample.hash(state);
+		self.number_locale.hash(state);
+		self.max_candles.hash(state);
+		self.window.hash(state);
+		self.warm_up_on_history.hash(state);
+		self.limits.hash(state);
+		self.validation_mode.hash(state);
+	}
+}
+
+/// Checks that the margins leave at least a pixel of the chart to plot in, as the plot area runs backwards otherwise
+fn check_plot_area(width: usize, height: usize, margin: &Margin) -> Result<(), OHLCError> {
+	if margin.left + margin.right >= width {
+		Err(OHLCError::Layout(format!("Left and right margins of {} and {} pixels leave no room for the plot in a width of {}", margin.left, margin.right, width)))
+	} else if margin.top + margin.bottom >= height {
+		Err(OHLCError::Layout(format!("Top and bottom margins of {} and {} pixels leave no room for the plot in a height of {}", margin.top, margin.bottom, height)))
+	} else {
+		Ok(())
+	}
+}
+
+/// Seconds in the number of time units, `None` if it doesn't fit in an `i64`
+fn seconds(time_units: u64, count: u64) -> Option<i64> {
+	time_units.checked_mul(count)
+		.filter(|&seconds| seconds <= i64::MAX as u64)
+		.map(|seconds| seconds as i64)
+}
+
+/// Checks every candle, failing with the first one that's invalid
+fn validate<C: Candle>(data: &[C]) -> Result<(), InvalidCandle> {
+	for (index, candle) in data.iter().enumerate() {
+		if let Err(reason) = validate_candle(candle) {
+			return Err(InvalidCandle { index, open: candle.open(), high: candle.high(), low: candle.low(), close: candle.close(), reason });
+		}
+	}
+
+	Ok(())
+}
+
+/// Repairs or leaves off the invalid candles as the mode says, counting them in the stats, `None` when the data is drawn as it is
+///
+/// Fails as in `Strict` with the first invalid candle if the candles can't be copied with `Candle::with_high_low`.
+fn apply_validation_mode<C: Candle>(data: &[C], mode: ValidationMode, stats: &mut RenderStats) -> Result<Option<Vec<C>>, InvalidCandle> {
+	let first_invalid = match validate(data) {
+		Ok(()) => return Ok(None),
+		Err(invalid) => invalid,
+	};
+	if mode == ValidationMode::Strict {
+		return Err(first_invalid);
+	}
+
+	let mut kept = Vec::with_capacity(data.len());
+	for (index, candle) in data.iter().enumerate() {
+		let (open, high, low, close) = (candle.open(), candle.high(), candle.low(), candle.close());
+
+		match validate_candle(candle) {
+			Ok(()) => match candle.with_high_low(high, low) {
+				Some(copy) => kept.push(copy),
+				None => return Err(first_invalid),
+			},
+			Err(reason) if mode == ValidationMode::Skip => {
+				warn!("Skipped candle {}: {}", index, reason);
+				stats.skipped += 1;
+			}
+			Err(reason) => {
+				// `max` and `min` pass over NaN, which would hide it
+				let finite = open.is_finite() && high.is_finite() && low.is_finite() && close.is_finite();
+				let repaired = candle.with_high_low(high.max(open).max(close), low.min(open).min(close))
+					.filter(|repaired| finite && validate_candle(repaired).is_ok());
+
+				match repaired {
+					Some(repaired) => {
+						warn!("Repaired candle {}: {} High and low moved from {} and {} to {} and {}", index, reason, high, low, repaired.high(), repaired.low());
+						stats.repaired += 1;
+						kept.push(repaired);
+					}
+					None => return Err(InvalidCandle { index, open, high, low, close, reason }),
+				}
+			}
+		}
+	}
+
+	Ok(Some(kept))
+}
+
+/// Checks that the prices and volumes of a candle are finite and that the open, close and low lie within the high and low
+pub(crate) fn validate_candle<C: Candle>(candle: &C) -> Result<(), &'static str> {
+	let open = candle.open();
+	let high = candle.high();
+	let close = candle.close();
+	let low = candle.low();
+
+	if !(open.is_finite() && high.is_finite() && close.is_finite() && low.is_finite()) {
+		return Err("Data contains a price that isn't a finite number.");
+	}
+	if !(candle.total_volume().is_finite() && candle.buy_volume().iter().all(|volume| volume.is_finite())) {
+		return Err("Data contains a volume that isn't a finite number.");
+	}
+	if open > high {
+		return Err("Opening value is higher than high value.");
 	}
+	if close > high {
+		return Err("Closing value is higher than high value.");
+	}
+	if low > high {
+		return Err("Low value is higher than high value.");
+	}
+	if open < low {
+		return Err("Opening value is lower than low value.");
+	}
+	if close < low {
+		return Err("Closing value is lower than low value.");
+	}
+
 	Ok(())
 }
@@ -0,0 +1,61 @@
+//! What was drawn where on a chart, for hit-testing the image, such as tooltips over a chart on a web page.
+
+use model::{Point, Rect};
+
+/// Something drawn on the chart, with where it is in pixels of the finished chart and what it shows
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind")]
+pub enum DrawnElement {
+	/// A candle's body, wicks and ticks, or the point at its close for the line styles
+	Candle { index: usize, open: f64, high: f64, low: f64, close: f64, bounds: Rect },
+	/// The label of a price line
+	PriceLabel { price: f64, text: String, bounds: Rect },
+	/// The label of a time line, with its time in seconds from the start of the chart
+	TimeLabel { time: i64, text: String, bounds: Rect },
+	/// The points of a line drawn by an extension, such as a moving average
+	ExtensionLine { extension: String, points: Vec<Point> },
+}
+
+impl DrawnElement {
+	/// Smallest area holding the element
+	pub fn bounds(&self) -> Rect {
+		match *self {
+			DrawnElement::Candle { bounds, .. } | DrawnElement::PriceLabel { bounds, .. } | DrawnElement::TimeLabel { bounds, .. } => bounds,
+			DrawnElement::ExtensionLine { ref points, .. } => points.iter().fold(
+				Rect { left: usize::MAX, top: usize::MAX, right: 0, bottom: 0 },
+				|bounds, &(x, y)| Rect { left: bounds.left.min(x), top: bounds.top.min(y), right: bounds.right.max(x), bottom: bounds.bottom.max(y) },
+			),
+		}
+	}
+
+	/// The element as drawn on a chart the factor times smaller, for scaling down from supersampling
+	pub(crate) fn scaled_down(self, factor: usize) -> DrawnElement {
+		let rect = |bounds: Rect| Rect { left: bounds.left / factor, top: bounds.top / factor, right: bounds.right / factor, bottom: bounds.bottom / factor };
+
+		match self {
+			DrawnElement::Candle { index, open, high, low, close, bounds } => DrawnElement::Candle { index, open, high, low, close, bounds: rect(bounds) },
+			DrawnElement::PriceLabel { price, text, bounds } => DrawnElement::PriceLabel { price, text, bounds: rect(bounds) },
+			DrawnElement::TimeLabel { time, text, bounds } => DrawnElement::TimeLabel { time, text, bounds: rect(bounds) },
+			DrawnElement::ExtensionLine { extension, points } => DrawnElement::ExtensionLine {
+				extension,
+				points: points.into_iter().map(|(x, y)| (x / factor, y / factor)).collect(),
+			},
+		}
+	}
+}
+
+/// Everything recorded while rendering a chart, in the order it was drawn
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RenderManifest {
+	pub elements: Vec<DrawnElement>,
+}
+
+impl RenderManifest {
+	/// Index of the candle drawn over the pixel, if any
+	pub fn candle_at(&self, x: usize, y: usize) -> Option<usize> {
+		self.elements.iter().filter_map(|element| match *element {
+			DrawnElement::Candle { index, bounds, .. } if bounds.contains(x, y) => Some(index),
+			_ => None,
+		}).next()
+	}
+}
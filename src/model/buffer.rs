@@ -1,7 +1,15 @@
 pub use OHLCRenderOptions;
 
+use fonts::Typeface;
+use manifest::DrawnElement;
+use utils::NumberLocale;
+
 use super::*;
 
+/// The image a chart is drawn on
+///
+/// The canvas area is the whole image. The chart area, also called the plot area, is the part of the canvas inside the margins where prices are plotted.
+/// Labels and extension strips go in the margins around it.
 pub struct ChartBuffer {
 	/// Total width for the graph
 	width: usize,
@@ -15,14 +23,39 @@ pub struct ChartBuffer {
 	pub min_price: f64,
 	/// The amount of time the graph covers, in seconds
 	pub timeframe: i64,
+	/// The first part of the timeframe, in seconds, which is left of the plot area, eg. the candles before a window that the extensions are warmed up on
+	pub lead: i64,
+	/// How many of the candles the extensions are given are in the lead, before those on the chart
+	pub lead_candles: usize,
 	/// Default background colour, alpha channel is ignored
 	pub background: u32,
 	/// Byte buffer of the actual image
 	pub buffer: Vec<u8>,
+	/// Font for all text drawn on the chart
+	pub typeface: Typeface,
+	/// Area that drawing is limited to, if any
+	clip: Option<Rect>,
+	/// Pixels drawn for each pixel of the finished chart when supersampling, offsets and sizes in pixels are multiplied by it
+	pub scale: usize,
+	/// How numbers in labels are written
+	pub locale: NumberLocale,
+	/// What's been drawn where, only recorded when rendering with a manifest
+	pub(crate) manifest: Option<Vec<DrawnElement>>,
 }
 
 impl ChartBuffer {
+	#[cfg(test)]
 	pub(crate) fn new(width: usize, height: usize, margin: Margin, max_price: f64, min_price: f64, timeframe: i64, background: u32) -> ChartBuffer {
+		let mut buffer = Self::reusing(Vec::with_capacity(width * height * 3), width, height, margin, (min_price, max_price), timeframe, background);
+		buffer.fill_background();
+
+		buffer
+	}
+
+	/// Creates the chart of the (min_price, max_price) in the vector, which is cleared so that its allocation can be reused
+	///
+	/// It's left without any pixels, for `fill_background` or `background_gradient` to fill in.
+	pub(crate) fn reusing(mut buffer: Vec<u8>, width: usize, height: usize, margin: Margin, (min_price, max_price): (f64, f64), timeframe: i64, background: u32) -> ChartBuffer {
 		if max_price < min_price {
 			panic!("max < min... wut?");
 		}
@@ -35,64 +68,320 @@ impl ChartBuffer {
 			panic!("margins cannot be bigger than the image itself")
 		}
 
-		let mut buffer = Vec::with_capacity(width * height * 3);
-
-		Self::colour_buffer(&mut buffer, width * height, background);
+		buffer.clear();
 
-		ChartBuffer { width, height, margin, max_price, min_price, timeframe, background: background | 0xFF, buffer }
+		ChartBuffer { width, height, margin, max_price, min_price, timeframe, lead: 0, lead_candles: 0, background: background | 0xFF, buffer, typeface: Typeface::Bitmap, clip: None, scale: 1, locale: NumberLocale::default(), manifest: None }
 	}
 
-	/// Returns: (x, y)
+	/// Returns: (x, y), pinned to the edges of the plot area for prices and times outside of the chart
+	///
+	/// Times in the lead are pinned a line's width left of the plot area instead, where nothing is drawn while there's a lead.
 	pub fn data_to_coords(&self, price: f64, time: i64) -> Point {
+		let (x, y) = self.data_to_coords_signed(price, time);
+		let plot = self.plot_area();
+
+		let left = if self.lead > 0 && time < self.lead { plot.left.saturating_sub(self.scale) } else { plot.left };
+		let x = x.max(left as isize).min(plot.right as isize) as usize;
+		let y = y.max(plot.top as isize).min(plot.bottom as isize) as usize;
+
+		(x, y)
+	}
+
+	/// Returns: (x, y), which is off the plot area or even the image for prices and times outside of the chart
+	///
+	/// Coordinates are saturated at the limits of `isize` for extreme prices.
+	pub fn data_to_coords_signed(&self, price: f64, time: i64) -> (isize, isize) {
 		let x = {
-			let prog = time as f64 / self.timeframe as f64;
+			let prog = (time as f64 - self.lead as f64) / (self.timeframe - self.lead) as f64;
+			let plot_width = (self.width - (self.margin.right + self.margin.left)) as f64;
 
-			if prog <= 0. {
-				self.margin.left
-			} else if prog >= 1. {
-				self.width - self.margin.right
-			} else {
-				self.margin.left + (prog * (self.width - (self.margin.right + self.margin.left)) as f64) as usize
-			}
+			(self.margin.left as f64 + prog * plot_width).floor() as isize
 		};
 
 		let y = {
-			let prog = (price - self.min_price) / (self.max_price - self.min_price);
-
-			if prog >= 1. {
-				self.margin.top
+			let prog = if self.max_price > self.min_price {
+				(price - self.min_price) / (self.max_price - self.min_price)
 			} else {
-				let bottom = self.height - self.margin.bottom;
+				// A flat range has nowhere to go, keep it in the middle
+				0.5
+			};
+			let bottom = self.height - self.margin.bottom;
 
-				if prog <= 0. {
-					bottom
-				} else {
-					(bottom as f64 - (prog * (bottom - self.margin.top) as f64)) as usize
-				}
-			}
+			(bottom as f64 - prog * (bottom - self.margin.top) as f64).floor() as isize
 		};
 
 		(x, y)
 	}
 
+	/// The x of the time before it's rounded down to a column, for drawing at sub-pixel positions
+	pub fn time_to_x(&self, time: f64) -> f64 {
+		let plot_width = (self.width - (self.margin.right + self.margin.left)) as f64;
+
+		self.margin.left as f64 + (time - self.lead as f64) / (self.timeframe - self.lead) as f64 * plot_width
+	}
+
+	/// The inverse of `data_to_coords`, returns: (price, time)
+	///
+	/// Points outside of the plot area give prices and times outside of the chart's range.
+	pub fn coords_to_data(&self, point: Point) -> (f64, i64) {
+		let plot_width = (self.width - (self.margin.right + self.margin.left)) as f64;
+		let time = self.lead as f64 + (point.0 as f64 - self.margin.left as f64) / plot_width * (self.timeframe - self.lead) as f64;
+
+		let bottom = self.height - self.margin.bottom;
+		let plot_height = (bottom - self.margin.top) as f64;
+		let price = self.min_price + (bottom as f64 - point.1 as f64) / plot_height * (self.max_price - self.min_price);
+
+		(price, time.round() as i64)
+	}
+
+	/// Where the last candle's close is drawn in the middle of its slot, for placing overlays at the latest price, returns `None` without candles
+	pub fn last_candle_coords<C: Candle>(&self, data: &[C]) -> Option<Point> {
+		let last = data.last()?;
+		let period = self.timeframe / data.len() as i64;
+		let time = period * (data.len() - 1) as i64 + (2. * period as f64 / 5.) as i64;
+
+		Some(self.data_to_coords(last.close(), time))
+	}
+
+	/// Fewest decimal places, down to `min_places`, that it takes to bring the widest label of the prices within the right margin less the padding
+	///
+	/// Minus signs and prices detected to 8 places can make labels wider than the margin, which would run them off the chart.
+	pub(crate) fn price_places_to_fit(&self, prices: &[f64], places: usize, min_places: usize, padding: usize) -> usize {
+		let room = self.margin.right.saturating_sub(padding);
+		let widest = |places: usize| prices.iter().map(|price| self.typeface.measure(&self.locale.format(*price, places), 1).0).max().unwrap_or(0);
+
+		let mut places = places;
+		while places > min_places && widest(places) > room {
+			places -= 1;
+		}
+
+		places
+	}
+
+	/// The area inside the margins, where the prices are plotted
+	pub fn plot_area(&self) -> Rect {
+		Rect {
+			left: self.margin.left,
+			top: self.margin.top,
+			right: self.width - self.margin.right,
+			bottom: self.height - self.margin.bottom,
+		}
+	}
+
+	/// Width of the chart area in pixels, from the left edge at the end of the lead to the right edge at the end of the timeframe
+	pub fn chart_width(&self) -> u32 {
+		(self.width - self.margin.left - self.margin.right) as u32
+	}
+
+	/// Height of the chart area in pixels, from the top edge at the maximum price to the bottom edge at the minimum price
+	pub fn chart_height(&self) -> u32 {
+		(self.height - self.margin.top - self.margin.bottom) as u32
+	}
+
+	/// Returns: (min_price, max_price) of the chart area
+	pub fn price_range(&self) -> (f64, f64) {
+		(self.min_price, self.max_price)
+	}
+
+	/// Returns: (start, end) of the chart area, in seconds from the start of the chart
+	pub fn time_range(&self) -> (i64, i64) {
+		(self.lead, self.timeframe)
+	}
+
+	/// The candles on the chart, leaving off those in the lead that the extensions are only warmed up on
+	pub fn charted<'a, C>(&self, data: &'a [C]) -> &'a [C] {
+		&data[self.lead_candles.min(data.len())..]
+	}
+
+	/// Space between the edges of the canvas and the chart area
+	pub fn margin(&self) -> Margin {
+		self.margin
+	}
+
+	/// Whether what's drawn is being recorded, so that working out where things are can be skipped when it isn't
+	pub fn is_recording(&self) -> bool {
+		self.manifest.is_some()
+	}
+
+	/// Adds the element to the manifest when rendering with one, in the pixels of the chart as it's drawn
+	pub fn record(&mut self, element: DrawnElement) {
+		if let Some(ref mut manifest) = self.manifest {
+			manifest.push(element);
+		}
+	}
+
+	/// Limits drawing to the area, or lifts the limit with `None`
+	pub fn set_clip(&mut self, clip: Option<Rect>) {
+		self.clip = clip;
+	}
+
+	/// Draws without the clip area, for things that belong in the margins such as axis labels
+	pub fn without_clip<F, R>(&mut self, f: F) -> R where F: FnOnce(&mut ChartBuffer) -> R {
+		let clip = self.clip.take();
+		let result = (f)(self);
+		self.clip = clip;
+
+		result
+	}
+
+	/// Draws a line across the plot area at the price, nothing is drawn if the price is off the chart
+	pub fn hline(&mut self, price: f64, rgba: u32, style: LineStyle) {
+		// data_to_coords would pin the line to the edge of the chart, where it'd be misleading
+		if !(price >= self.min_price && price <= self.max_price) {
+			return;
+		}
+
+		let p1 = self.data_to_coords(price, 0);
+		let p2 = self.data_to_coords(price, self.timeframe);
+
+		self.styled_line(p1, p2, style, rgba);
+	}
+
+	/// Draws a line up the plot area at the time, nothing is drawn if the time is off the chart
+	pub fn vline(&mut self, time: i64, rgba: u32, style: LineStyle) {
+		if time < self.lead || time > self.timeframe {
+			return;
+		}
+
+		let p1 = self.data_to_coords(self.min_price, time);
+		let p2 = self.data_to_coords(self.max_price, time);
+
+		self.styled_line(p1, p2, style, rgba);
+	}
+
 	pub fn create_extension_strip<F>(&mut self, height: usize, f: F) where F: Fn(&mut ExtensionStrip) {
 		// Have enough room for labels on the top, bottom and right
-		let margin = Margin { top: 40, bottom: 35, left: self.margin.left, right: self.margin.right };
+		let margin = Margin { top: 40 * self.scale, bottom: 35 * self.scale, left: self.margin.left, right: self.margin.right };
+		let height = height * self.scale;
 
 		self.height += height;
 		self.margin.bottom += height;
 
 		let mut es = ExtensionStrip::new(self.width, height, self.background, self.timeframe, margin);
+		es.lead = self.lead;
+		es.typeface = self.typeface.clone();
+		es.scale = self.scale;
 
 		(f)(&mut es);
 
 		self.buffer.extend(es.buffer);
 	}
 
+	/// Scales the chart down by its scale, each square of pixels becoming their average, which leaves it at a scale of 1
+	pub(crate) fn downsample(&mut self) {
+		let factor = self.scale;
+		if factor <= 1 {
+			return;
+		}
+
+		let (width, height) = (self.width / factor, self.height / factor);
+		let area = factor * factor;
+
+		// Every pixel is written before or where its square starts, so the squares still to come are left intact
+		for y in 0..height {
+			for x in 0..width {
+				for j in 0..3 {
+					let mut sum = 0;
+					for dy in 0..factor {
+						for dx in 0..factor {
+							sum += self.buffer[(x * factor + dx + (y * factor + dy) * self.width) * 3 + j] as usize;
+						}
+					}
+
+					self.buffer[(x + y * width) * 3 + j] = ((sum + area / 2) / area) as u8;
+				}
+			}
+		}
+
+		self.buffer.truncate(width * height * 3);
+		self.width = width;
+		self.height = height;
+		self.margin = Margin {
+			top: self.margin.top / factor,
+			bottom: self.margin.bottom / factor,
+			left: self.margin.left / factor,
+			right: self.margin.right / factor,
+		};
+		self.clip = None;
+		self.scale = 1;
+
+		if let Some(manifest) = self.manifest.take() {
+			self.manifest = Some(manifest.into_iter().map(|element| element.scaled_down(factor)).collect());
+		}
+	}
+
 	pub fn put(&mut self, price: f64, time: i64, rgba: u32) {
 		let (x, y) = self.data_to_coords(price, time);
 		self.colour(x, y, rgba);
 	}
+
+	/// Replace the whole chart with the background colour
+	pub(crate) fn fill_background(&mut self) {
+		self.buffer.clear();
+		Self::colour_buffer(&mut self.buffer, self.width * self.height, self.background);
+	}
+
+	/// Replace the background with a vertical gradient between the two colours, alpha channels are ignored
+	pub fn background_gradient(&mut self, top: u32, bottom: u32) {
+		let (width, height) = (self.width, self.height);
+
+		// Every pixel is replaced, so the rows are written out afresh
+		self.buffer.clear();
+		for y in 0..height {
+			let prog = if height > 1 { y as f64 / (height - 1) as f64 } else { 0. };
+
+			let mut row = [0u8; 3];
+			for (j, channel) in row.iter_mut().enumerate() {
+				let from = (top >> (24 - 8 * j)) as u8 as f64;
+				let to = (bottom >> (24 - 8 * j)) as u8 as f64;
+
+				*channel = (from + (to - from) * prog).round() as u8;
+			}
+
+			for _ in 0..width {
+				self.buffer.extend_from_slice(&row);
+			}
+		}
+	}
+
+	/// Invert every colour in the image, handy for a quick dark mode version of a chart
+	pub fn invert_colours(&mut self) {
+		for byte in self.buffer.iter_mut() {
+			*byte = 255 - *byte;
+		}
+	}
+
+	/// Convert the image to greyscale using the Rec. 601 luma weights
+	pub fn grayscale(&mut self) {
+		for pixel in self.buffer.chunks_mut(3) {
+			let luma = (0.299 * pixel[0] as f64 + 0.587 * pixel[1] as f64 + 0.114 * pixel[2] as f64).round() as u8;
+
+			pixel[0] = luma;
+			pixel[1] = luma;
+			pixel[2] = luma;
+		}
+	}
+
+	/// Draw a frame around the region the frame specifies
+	///
+	/// A plot frame grows outwards into the margins so it doesn't cover the candles, a canvas frame grows inwards from the image edge.
+	pub fn frame(&mut self, frame: &Frame) {
+		let (width, height) = (self.width, self.height);
+
+		match frame.kind {
+			FrameKind::Plot => {
+				let x1 = self.margin.left.saturating_sub(frame.thickness);
+				let y1 = self.margin.top.saturating_sub(frame.thickness);
+				let x2 = (width - self.margin.right + frame.thickness).min(width - 1);
+				let y2 = (height - self.margin.bottom + frame.thickness).min(height - 1);
+
+				self.rect_outline(x1, y1, x2, y2, frame.thickness, frame.colour);
+			}
+			FrameKind::Canvas => {
+				self.rect_outline(0, 0, width - 1, height - 1, frame.thickness, frame.colour);
+			}
+		}
+	}
 }
 
 impl Painter for ChartBuffer {
@@ -111,6 +400,30 @@ impl Painter for ChartBuffer {
 	fn background(&self) -> u32 {
 		self.background
 	}
+
+	fn typeface(&self) -> &Typeface {
+		&self.typeface
+	}
+
+	fn clip(&self) -> Option<Rect> {
+		self.clip
+	}
+
+	fn is_clipped(&self, x: usize, y: usize) -> bool {
+		match self.clip {
+			Some(clip) if !clip.contains(x, y) => true,
+			_ => in_lead(self.lead, &self.margin, self.height, x, y),
+		}
+	}
+
+	fn line_width(&self) -> usize {
+		self.scale
+	}
+}
+
+/// Whether the pixel is in the margin left of the plot rows, where the lead is pinned, so that it isn't drawn there but labels above and below still are
+fn in_lead(lead: i64, margin: &Margin, height: usize, x: usize, y: usize) -> bool {
+	lead > 0 && x < margin.left && y >= margin.top && y <= height - margin.bottom
 }
 
 pub struct ExtensionStrip {
@@ -118,8 +431,13 @@ pub struct ExtensionStrip {
 	height: usize,
 	pub background: u32,
 	pub timeframe: i64,
+	/// The first part of the timeframe, which is left of the plot area, the same as the chart it's a strip of
+	pub lead: i64,
 	pub margin: Margin,
 	pub buffer: Vec<u8>,
+	pub typeface: Typeface,
+	/// Pixels drawn for each pixel of the finished chart, the same as the chart it's a strip of
+	pub scale: usize,
 }
 
 impl ExtensionStrip {
@@ -133,16 +451,22 @@ impl ExtensionStrip {
 			height,
 			background,
 			timeframe,
+			lead: 0,
 			margin,
 			buffer,
+			typeface: Typeface::Bitmap,
+			scale: 1,
 		}
 	}
 
 	pub fn data_to_coords(&self, up_progress: f64, time: i64) -> Point {
 		let x = {
-			let prog = time as f64 / self.timeframe as f64;
+			let prog = (time as f64 - self.lead as f64) / (self.timeframe - self.lead) as f64;
 
-			if prog <= 0. {
+			if self.lead > 0 && time < self.lead {
+				// Where nothing is drawn, like the chart's
+				self.margin.left.saturating_sub(self.scale)
+			} else if prog <= 0. {
 				self.margin.left
 			} else if prog >= 1. {
 				self.width - self.margin.right
@@ -185,4 +509,16 @@ impl Painter for ExtensionStrip {
 	fn background(&self) -> u32 {
 		self.background
 	}
+
+	fn typeface(&self) -> &Typeface {
+		&self.typeface
+	}
+
+	fn is_clipped(&self, x: usize, y: usize) -> bool {
+		in_lead(self.lead, &self.margin, self.height, x, y)
+	}
+
+	fn line_width(&self) -> usize {
+		self.scale
+	}
 }
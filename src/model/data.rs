@@ -1,13 +1,20 @@
 use std::hash::{Hash, Hasher};
 
-use Candle;
+use {Candle, OHLCError};
+use validate_candle;
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
 pub struct OHLC {
 	pub o: f64,
 	pub h: f64,
 	pub l: f64,
 	pub c: f64,
+	/// Unix timestamp of the start of the candle in seconds, if known
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub t: Option<i64>,
+	/// Volume traded over the candle, if known
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub v: Option<f64>,
 }
 
 impl Hash for OHLC {
@@ -16,6 +23,8 @@ impl Hash for OHLC {
 		state.write_u64(self.h as u64);
 		state.write_u64(self.l as u64);
 		state.write_u64(self.c as u64);
+		self.t.hash(state);
+		state.write_u64(self.v.unwrap_or(0.) as u64);
 	}
 }
 
@@ -47,21 +56,96 @@ impl Candle for OHLC {
 
 	#[inline]
 	fn total_volume(&self) -> f64 {
-		0.0
+		self.v.unwrap_or(0.0)
+	}
+
+	#[inline]
+	fn with_high_low(&self, high: f64, low: f64) -> Option<OHLC> {
+		Some(OHLC { h: high, l: low, ..*self })
 	}
 }
 
 impl OHLC {
-	pub fn new() -> OHLC {
-		OHLC {
-			o: 0.0,
-			h: 0.0,
-			l: 0.0,
-			c: 0.0,
-		}
+	/// A candle without a timestamp or volume, failing if the prices aren't a valid candle
+	pub fn new(o: f64, h: f64, l: f64, c: f64) -> Result<OHLC, OHLCError> {
+		OHLC::builder().open(o).high(h).low(l).close(c).build()
+	}
+
+	pub fn builder() -> OHLCBuilder {
+		OHLCBuilder::default()
 	}
 
 	pub fn range(&self) -> f64 {
 		(self.h - self.l).abs()
 	}
-}
\ No newline at end of file
+}
+
+/// Builds an `OHLC`, validating it once all four prices are set
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OHLCBuilder {
+	o: Option<f64>,
+	h: Option<f64>,
+	l: Option<f64>,
+	c: Option<f64>,
+	t: Option<i64>,
+	v: Option<f64>,
+}
+
+impl OHLCBuilder {
+	pub fn open(&mut self, open: f64) -> &mut Self {
+		self.o = Some(open);
+
+		self
+	}
+
+	pub fn high(&mut self, high: f64) -> &mut Self {
+		self.h = Some(high);
+
+		self
+	}
+
+	pub fn low(&mut self, low: f64) -> &mut Self {
+		self.l = Some(low);
+
+		self
+	}
+
+	pub fn close(&mut self, close: f64) -> &mut Self {
+		self.c = Some(close);
+
+		self
+	}
+
+	pub fn volume(&mut self, volume: f64) -> &mut Self {
+		self.v = Some(volume);
+
+		self
+	}
+
+	/// Unix timestamp of the start of the candle in seconds
+	pub fn timestamp(&mut self, timestamp: i64) -> &mut Self {
+		self.t = Some(timestamp);
+
+		self
+	}
+
+	pub fn build(&self) -> Result<OHLC, OHLCError> {
+		let candle = match (self.o, self.h, self.l, self.c) {
+			(Some(o), Some(h), Some(l), Some(c)) => OHLC { o, h, l, c, t: self.t, v: self.v },
+			_ => return Err(OHLCError::Validation("Candle is missing some of the open, high, low and close.".to_string())),
+		};
+
+		validate_candle(&candle).map_err(|err| OHLCError::Validation(err.to_string()))?;
+
+		Ok(candle)
+	}
+}
+
+/// A single trade, such as from an exchange's trade stream
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+	/// Unix timestamp of the trade in seconds
+	pub timestamp: i64,
+	pub price: f64,
+	pub volume: f64,
+}
@@ -0,0 +1,222 @@
+//! The maths behind the indicator extensions, free of any drawing so it can be used on its own
+
+use data::Candle;
+use utils::Lookback;
+
+#[cfg(test)]
+mod tests;
+
+/// Upper, middle and lower lines of the Bollinger bands at one point in time
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BandPoints {
+	pub higher: f64,
+	pub median: f64,
+	pub lower: f64,
+}
+
+/// The lines of the MACD, with one value for each price
+#[derive(Clone, Debug, PartialEq)]
+pub struct MACDSeries {
+	/// The 12 period EMA less the 26 period EMA
+	pub divergence: Vec<f64>,
+	/// The 9 period EMA of the divergence
+	pub signal: Vec<f64>,
+	/// The divergence less the signal
+	pub histogram: Vec<f64>,
+}
+
+/// Mean of the prices
+pub fn avg(prices: &[f64]) -> f64 {
+	let mut sum = 0.;
+
+	for price in prices {
+		sum += *price;
+	}
+
+	sum / prices.len() as f64
+}
+
+/// Sample standard deviation of the prices, 0 for fewer than 2 prices
+pub fn std_dev(prices: &[f64]) -> f64 {
+	let len = prices.len();
+	if len <= 1 {
+		return 0.;
+	}
+
+	let avg = avg(prices);
+	let mut squared_diff_sum = 0.;
+
+	for price in prices {
+		squared_diff_sum += (avg - price).powf(2.);
+	}
+
+	(squared_diff_sum / (len - 1) as f64).sqrt()
+}
+
+/// Pearson correlation of the paired prices, from -1 to 1, NaN when there are fewer than 2 pairs or either side is flat
+pub fn correlation(a: &[f64], b: &[f64]) -> f64 {
+	let len = a.len().min(b.len());
+	let (a, b) = (&a[..len], &b[..len]);
+
+	let (std_dev_a, std_dev_b) = (std_dev(a), std_dev(b));
+	if std_dev_a == 0. || std_dev_b == 0. {
+		return f64::NAN;
+	}
+
+	let (avg_a, avg_b) = (avg(a), avg(b));
+	let mut covariance = 0.;
+
+	for (x, y) in a.iter().zip(b) {
+		covariance += (x - avg_a) * (y - avg_b);
+	}
+
+	covariance / (len - 1) as f64 / (std_dev_a * std_dev_b)
+}
+
+/// Halfway between the high and the low of the candle
+pub fn median_of_ohlc<C: Candle>(ohlc: &C) -> f64 {
+	let low = ohlc.low();
+	((ohlc.high() - low) / 2.) + low
+}
+
+pub fn median_list<C: Candle>(list: &[C]) -> Vec<f64> {
+	let mut buf = vec![];
+
+	for ohlc in list {
+		buf.push(median_of_ohlc(ohlc));
+	}
+
+	buf
+}
+
+/// Simple moving average, the n-th value is the mean of `prices[n..n + periods]`
+pub fn sma(prices: &[f64], periods: usize) -> Vec<f64> {
+	if periods == 0 || prices.len() < periods {
+		return vec![];
+	}
+
+	prices.windows(periods).map(avg).collect()
+}
+
+/// Exponential moving average with one value for each price, the weights of the `periods` prices before each one fall off by the smoothing factor
+pub fn ema(prices: &[f64], periods: usize, smoothing_factor: f64) -> Vec<f64> {
+	let mut buf = vec![];
+
+	for point in 0..prices.len() {
+		let mut numerator = 0.;
+		let mut denominator = 0.;
+		for (i, price) in prices.iter().enumerate().take(point + 1).skip(point.saturating_sub(periods)) {
+			let exponent = (point + 1) - i;
+			let weight = (1. - smoothing_factor).powf(exponent as f64);
+
+			numerator += price * weight;
+			denominator += weight;
+		}
+
+		buf.push(numerator / denominator);
+	}
+
+	buf
+}
+
+/// Double exponential moving average, `2 * EMA - EMA(EMA)`, with one value for each price
+pub fn dema(prices: &[f64], periods: usize, smoothing_factor: f64) -> Vec<f64> {
+	let ema_buf = ema(prices, periods, smoothing_factor);
+	let ema_ema = ema(&ema_buf, periods, smoothing_factor);
+
+	ema_buf.iter().zip(ema_ema.iter()).map(|(ema, ema_ema)| 2. * ema - ema_ema).collect()
+}
+
+/// MACD(12, 26, 9) of the prices with the smoothing factor used by every EMA
+pub fn macd(prices: &[f64], smoothing_factor: f64) -> MACDSeries {
+	let short = ema(prices, 12, smoothing_factor);
+	let long = ema(prices, 26, smoothing_factor);
+
+	let divergence: Vec<f64> = short.iter().zip(long.iter()).map(|(short, long)| short - long).collect();
+	let signal = ema(&divergence, 9, smoothing_factor);
+	let histogram = divergence.iter().zip(signal.iter()).map(|(divergence, signal)| divergence - signal).collect();
+
+	MACDSeries { divergence, signal, histogram }
+}
+
+/// Relative strength index from 0 to 100 of the candles' bodies, one for each candle after the first `periods` and over the `periods` candles before it
+pub fn rsi<C: Candle>(data: &[C], periods: usize) -> Vec<f64> {
+	let mut rsi = vec![];
+
+	for i in periods..data.len() {
+		let rs = {
+			let mut gains = vec![];
+			let mut losses = vec![];
+
+			for candle in &data[i - periods..i] {
+				let delta = candle.close() - candle.open();
+				if delta >= 0. {
+					gains.push(delta);
+					losses.push(0.);
+				} else {
+					losses.push(delta.abs());
+					gains.push(0.);
+				}
+			}
+
+			avg(&gains[..]) / avg(&losses[..])
+		};
+
+		rsi.push(100. - 100. / (1. + rs));
+	}
+
+	rsi
+}
+
+/// Bollinger bands of the candles' medians, one for each candle after the first `periods` and over the `periods` candles before it
+pub fn bollinger_bands<C: Candle>(data: &[C], periods: usize, standard_deviations: f64) -> Vec<BandPoints> {
+	bollinger_bands_of_medians(&median_list(data), periods, standard_deviations)
+}
+
+/// Same as `bollinger_bands`, given the medians of the candles
+pub fn bollinger_bands_of_medians(medians: &[f64], periods: usize, standard_deviations: f64) -> Vec<BandPoints> {
+	// Each band is of the full periods of candles before it
+	medians.lookback_iter(periods).skip(periods).map(|(_, history)| {
+		let scaled_std_dev = std_dev(history) * standard_deviations;
+		let moving_avg = avg(history);
+
+		BandPoints {
+			higher: moving_avg + scaled_std_dev,
+			median: moving_avg,
+			lower: moving_avg - scaled_std_dev,
+		}
+	}).collect()
+}
+
+/// The largest of the candle's range and its gaps from the previous close
+pub fn true_range<C: Candle>(candle: &C, previous: Option<&C>) -> f64 {
+	let range = candle.high() - candle.low();
+
+	match previous {
+		Some(previous) => range
+			.max((candle.high() - previous.close()).abs())
+			.max((candle.low() - previous.close()).abs()),
+		None => range,
+	}
+}
+
+/// Average true range with Wilder's smoothing, the n-th value is at `data[n + periods - 1]`
+///
+/// The first value is the mean of the first `periods` true ranges, each one after is `(previous * (periods - 1) + true range) / periods`.
+pub fn atr<C: Candle>(data: &[C], periods: usize) -> Vec<f64> {
+	if periods == 0 || data.len() < periods {
+		return vec![];
+	}
+
+	let true_ranges: Vec<f64> = (0..data.len())
+		.map(|i| true_range(&data[i], if i > 0 { Some(&data[i - 1]) } else { None }))
+		.collect();
+
+	let mut atr = vec![avg(&true_ranges[..periods])];
+	for tr in &true_ranges[periods..] {
+		let previous = atr[atr.len() - 1];
+		atr.push((previous * (periods - 1) as f64 + tr) / periods as f64);
+	}
+
+	atr
+}
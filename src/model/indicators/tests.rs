@@ -0,0 +1,112 @@
+use model::data::OHLC;
+
+use super::*;
+
+fn assert_all_close(actual: &[f64], expected: &[f64]) {
+	assert_eq!(actual.len(), expected.len(), "{:?} != {:?}", actual, expected);
+	for (a, e) in actual.iter().zip(expected.iter()) {
+		assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+	}
+}
+
+fn candle(o: f64, h: f64, l: f64, c: f64) -> OHLC {
+	OHLC { o, h, l, c, t: None, v: None }
+}
+
+#[test]
+fn avg_and_std_dev() {
+	let prices = [2., 4., 4., 4., 5., 5., 7., 9.];
+
+	assert_eq!(avg(&prices), 5.);
+	// Squared differences from the mean sum to 32, over 7 degrees of freedom
+	assert_all_close(&[std_dev(&prices)], &[(32f64 / 7.).sqrt()]);
+	assert_eq!(std_dev(&[3.]), 0.);
+}
+
+#[test]
+fn correlation_test() {
+	let prices = [1., 2., 3., 5., 4.];
+
+	assert_all_close(&[correlation(&prices, &prices)], &[1.]);
+	assert_all_close(&[correlation(&prices, &[-1., -2., -3., -5., -4.])], &[-1.]);
+	// Deviations 2, -2, 0 and 1, 1, -2 cancel out
+	assert_all_close(&[correlation(&[1., 5., 3.], &[4., 4., 1.])], &[0.]);
+	assert!(correlation(&prices, &[2.; 5]).is_nan());
+	assert!(correlation(&[1.], &[1.]).is_nan());
+}
+
+#[test]
+fn medians() {
+	let data = [candle(1., 4., 0., 2.), candle(5., 6., 5., 5.)];
+
+	assert_eq!(median_of_ohlc(&data[0]), 2.);
+	assert_eq!(median_list(&data), vec![2., 5.5]);
+}
+
+#[test]
+fn sma_test() {
+	assert_all_close(&sma(&[1., 2., 3., 4.], 2), &[1.5, 2.5, 3.5]);
+	assert!(sma(&[1., 2.], 3).is_empty());
+	assert!(sma(&[1., 2.], 0).is_empty());
+}
+
+#[test]
+fn ema_test() {
+	// (1 * 0.25 + 2 * 0.5) / 0.75 and (2 * 0.25 + 3 * 0.5) / 0.75
+	assert_all_close(&ema(&[1., 2., 3.], 1, 0.5), &[1., 5. / 3., 8. / 3.]);
+}
+
+#[test]
+fn dema_test() {
+	// The EMA of [1, 5/3, 8/3] is [1, 13/9, 7/3]
+	assert_all_close(&dema(&[1., 2., 3.], 1, 0.5), &[1., 17. / 9., 3.]);
+}
+
+#[test]
+fn macd_test() {
+	let prices: Vec<f64> = (0..40).map(|p| (p as f64).sin() * 10. + 50.).collect();
+	let macd = macd(&prices, 0.1);
+
+	assert_eq!(macd.divergence.len(), prices.len());
+	for i in 0..prices.len() {
+		assert_eq!(macd.histogram[i], macd.divergence[i] - macd.signal[i]);
+	}
+
+	// Every average of a flat price is the price itself
+	let flat = super::macd(&[5.; 40], 0.1);
+	assert_all_close(&flat.divergence, &[0.; 40]);
+	assert_all_close(&flat.histogram, &[0.; 40]);
+}
+
+#[test]
+fn rsi_test() {
+	let data = [candle(1., 2., 1., 2.), candle(2., 2., 0., 0.), candle(0., 3., 0., 3.), candle(3., 3., 2., 2.)];
+
+	// Bodies of +1, -2: average gain 0.5 and loss 1. Bodies of -2, +3: average gain 1.5 and loss 1.
+	assert_all_close(&rsi(&data, 2), &[100. - 100. / 1.5, 100. - 100. / 2.5]);
+}
+
+#[test]
+fn bollinger_bands_test() {
+	let data: Vec<OHLC> = [1., 2., 3.].iter().map(|&p| candle(p, p, p, p)).collect();
+	let half_sqrt2 = 0.5f64.sqrt();
+
+	let bands = bollinger_bands(&data, 2, 2.);
+
+	assert_eq!(bands.len(), 1);
+	assert_all_close(&[bands[0].higher, bands[0].median, bands[0].lower], &[1.5 + 2. * half_sqrt2, 1.5, 1.5 - 2. * half_sqrt2]);
+}
+
+#[test]
+fn atr_test() {
+	let data = [candle(9., 10., 8., 9.), candle(9., 11., 9., 10.), candle(13., 14., 12., 13.), candle(12., 13., 10., 11.)];
+
+	// A gap up from 10 to a low of 12 makes the third true range 4 rather than 2
+	assert_eq!(true_range(&data[0], None), 2.);
+	assert_eq!(true_range(&data[2], Some(&data[1])), 4.);
+	assert_eq!(true_range(&data[3], Some(&data[2])), 3.);
+
+	// (2 + 2) / 2, then (2 + 4) / 2 and (3 + 3) / 2
+	assert_all_close(&atr(&data, 2), &[2., 3., 3.]);
+	assert!(atr(&data, 5).is_empty());
+}
@@ -2,16 +2,36 @@ pub use buffer::*;
 pub use data::Candle;
 pub use painting::*;
 
-pub use self::rex::RendererExtension;
+pub use self::rex::{DrawJob, RendererExtension};
 
 pub mod buffer;
+pub mod indicators;
 pub mod painting;
 pub mod rex;
 pub mod data;
+#[cfg(test)]
+mod tests;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Margin {
 	pub top: usize,
 	pub bottom: usize,
 	pub left: usize,
 	pub right: usize,
 }
+
+/// Which region of the chart a frame is drawn around
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameKind {
+	/// Around the plot area, inside the margins
+	Plot,
+	/// Around the outer edge of the whole canvas
+	Canvas,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Frame {
+	pub kind: FrameKind,
+	pub colour: u32,
+	pub thickness: usize,
+}
@@ -1,10 +1,138 @@
 use std::mem;
 
-use fonts::ASCII_TABLE;
+use fonts::*;
+use utils::blend;
 pub use OHLCRenderOptions;
 
 pub type Point = (usize, usize);
 
+/// An area of the image, inclusive of all its edges
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+	pub left: usize,
+	pub top: usize,
+	pub right: usize,
+	pub bottom: usize,
+}
+
+impl Rect {
+	pub fn contains(&self, x: usize, y: usize) -> bool {
+		x >= self.left && x <= self.right && y >= self.top && y <= self.bottom
+	}
+}
+
+/// Weight of drawn text
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextStyle {
+	Regular,
+	/// Faked by striking every glyph twice, one pixel apart, so the text is a pixel wider
+	Bold,
+}
+
+/// How lines are stroked
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum LineStyle {
+	Solid,
+	/// 6 pixels drawn, then 4 pixels skipped
+	Dashed,
+}
+
+/// Horizontal alignment of text relative to its anchor point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HAlign {
+	Left,
+	Centre,
+	Right,
+}
+
+/// Vertical alignment of text relative to its anchor point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VAlign {
+	Top,
+	Middle,
+	Bottom,
+}
+
+/// Quarter turn rotations for text, clockwise
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rotation90 {
+	/// Reads top to bottom
+	Deg90,
+	/// Reads bottom to top, as is usual for a y-axis caption
+	Deg270,
+}
+
+/// Something to draw, so the drawing can be worked out apart from the buffer and replayed into it later
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+	Line { from: Point, to: Point, rgba: u32 },
+	Polyline { points: Vec<Point>, rgba: u32 },
+	/// A cardinal spline through the points, see `Painter::spline`
+	Spline { points: Vec<Point>, tension: f64, rgba: u32 },
+	/// Filled, inclusive of both corners
+	Rect { from: Point, to: Point, rgba: u32 },
+	Text { topleft: Point, text: String, rgba: u32 },
+	/// A filled square of the size in pixels, centred on the point
+	Marker { centre: Point, size: usize, rgba: u32 },
+}
+
+/// Linearly interpolate every channel between the two colours, `prog` goes from 0 (`from`) to 1 (`to`)
+pub fn interpolate_rgba(from: u32, to: u32, prog: f64) -> u32 {
+	let mut rgba = 0;
+	for shift in [24, 16, 8, 0].iter() {
+		let from = (from >> shift) as u8 as f64;
+		let to = (to >> shift) as u8 as f64;
+
+		rgba |= ((from + (to - from) * prog).round() as u32) << shift;
+	}
+
+	rgba
+}
+
+/// Cuts the line down to the part from (0, 0) to (width, height), which is just past the last pixel so the end of a line that goes off the image isn't pulled in
+fn clip_line(p1: Point, p2: Point, width: usize, height: usize) -> Option<(Point, Point)> {
+	let (mut low, mut high) = (0f64, 1f64);
+	let delta = (p2.0 as f64 - p1.0 as f64, p2.1 as f64 - p1.1 as f64);
+
+	for &(from, delta, max) in [(p1.0 as f64, delta.0, width as f64), (p1.1 as f64, delta.1, height as f64)].iter() {
+		if delta == 0. {
+			if from > max {
+				return None;
+			}
+			continue;
+		}
+
+		let (a, b) = (-from / delta, (max - from) / delta);
+		low = low.max(a.min(b));
+		high = high.min(a.max(b));
+	}
+
+	if low > high {
+		return None;
+	}
+
+	let at = |prog: f64| ((p1.0 as f64 + delta.0 * prog).round() as usize, (p1.1 as f64 + delta.1 * prog).round() as usize);
+	Some((at(low), at(high)))
+}
+
+/// The y of the line through the points at x, the points must be in order of x
+fn y_at(points: &[Point], x: usize) -> Option<f64> {
+	for pair in points.windows(2) {
+		let (a, b) = (pair[0], pair[1]);
+
+		if x >= a.0 && x <= b.0 {
+			if a.0 == b.0 {
+				return Some(a.1.min(b.1) as f64);
+			}
+
+			let prog = (x - a.0) as f64 / (b.0 - a.0) as f64;
+			return Some(a.1 as f64 + (b.1 as f64 - a.1 as f64) * prog);
+		}
+	}
+
+	None
+}
+
 pub trait Painter {
 	fn buffer<'a>(&'a mut self) -> &'a mut Vec<u8>;
 
@@ -14,6 +142,29 @@ pub trait Painter {
 
 	fn background(&self) -> u32;
 
+	/// The font text is drawn and measured in
+	fn typeface(&self) -> &Typeface {
+		&BITMAP
+	}
+
+	/// Pixels outside of the clip area are left untouched by every primitive
+	fn clip(&self) -> Option<Rect> {
+		None
+	}
+
+	/// Whether the pixel is left untouched by every primitive, those outside of the clip area unless overridden
+	fn is_clipped(&self, x: usize, y: usize) -> bool {
+		match self.clip() {
+			Some(clip) => !clip.contains(x, y),
+			None => false,
+		}
+	}
+
+	/// Thickness of lines in pixels, more than 1 when supersampling so they're as thick once scaled down
+	fn line_width(&self) -> usize {
+		1
+	}
+
 	/// Render a rectangle by the diagonally opposite points and colour
 	fn rect_point(&mut self, p1: Point, p2: Point, rgba: u32) {
 		self.rect(p1.0, p1.1, p2.0, p2.1, rgba);
@@ -28,6 +179,9 @@ pub trait Painter {
 			mem::swap(&mut y1, &mut y2);
 		}
 
+		// Nothing past the edges of the image can be coloured, so don't go looking
+		let (x2, y2) = (x2.min(self.width().saturating_sub(1)), y2.min(self.height().saturating_sub(1)));
+
 		for x in x1..(x2 + 1) {
 			for y in y1..(y2 + 1) {
 				self.colour(x, y, rgba);
@@ -35,8 +189,89 @@ pub trait Painter {
 		}
 	}
 
+	/// Render a rectangle filled with a vertical gradient, from the colour at the top row to the colour at the bottom row
+	fn rect_gradient(&mut self, mut x1: usize, mut y1: usize, mut x2: usize, mut y2: usize, top_rgba: u32, bottom_rgba: u32) {
+		if x1 > x2 {
+			mem::swap(&mut x1, &mut x2);
+		}
+		if y1 > y2 {
+			mem::swap(&mut y1, &mut y2);
+		}
+
+		let (last_x, last_y) = (x2.min(self.width().saturating_sub(1)), y2.min(self.height().saturating_sub(1)));
+
+		for y in y1..(last_y + 1) {
+			let prog = if y2 > y1 { (y - y1) as f64 / (y2 - y1) as f64 } else { 0. };
+			let rgba = interpolate_rgba(top_rgba, bottom_rgba, prog);
+
+			for x in x1..(last_x + 1) {
+				self.colour(x, y, rgba);
+			}
+		}
+	}
+
+	/// Render a filled triangle by its corners
+	fn triangle(&mut self, p1: Point, p2: Point, p3: Point, rgba: u32) {
+		let corners = [p1, p2, p3];
+		let (x1, x2) = (corners.iter().map(|p| p.0).min().unwrap(), corners.iter().map(|p| p.0).max().unwrap());
+		let (y1, y2) = (corners.iter().map(|p| p.1).min().unwrap(), corners.iter().map(|p| p.1).max().unwrap());
+		let (x2, y2) = (x2.min(self.width().saturating_sub(1)), y2.min(self.height().saturating_sub(1)));
+
+		// Which side of the edge from a to b the point is on, 0 if it's on the edge
+		let side = |a: Point, b: Point, x: usize, y: usize| {
+			(b.0 as i64 - a.0 as i64) * (y as i64 - a.1 as i64) - (b.1 as i64 - a.1 as i64) * (x as i64 - a.0 as i64)
+		};
+
+		for y in y1..(y2 + 1) {
+			for x in x1..(x2 + 1) {
+				let sides = [side(p1, p2, x, y), side(p2, p3, x, y), side(p3, p1, x, y)];
+
+				// Inside when the point is on the same side of every edge, whichever way round the corners go
+				if sides.iter().all(|s| *s >= 0) || sides.iter().all(|s| *s <= 0) {
+					self.colour(x, y, rgba);
+				}
+			}
+		}
+	}
+
+	/// Render the outline of a rectangle by the min/max x and y points, the outline grows inwards by the thickness
+	fn rect_outline(&mut self, mut x1: usize, mut y1: usize, mut x2: usize, mut y2: usize, thickness: usize, rgba: u32) {
+		if x1 > x2 {
+			mem::swap(&mut x1, &mut x2);
+		}
+		if y1 > y2 {
+			mem::swap(&mut y1, &mut y2);
+		}
+
+		for t in 0..thickness {
+			if x1 + 2 * t > x2 || y1 + 2 * t > y2 {
+				break;
+			}
+
+			for x in (x1 + t)..(x2 - t).saturating_add(1).min(self.width()) {
+				self.colour(x, y1 + t, rgba);
+				self.colour(x, y2 - t, rgba);
+			}
+			for y in (y1 + t + 1)..(y2 - t).min(self.height()) {
+				self.colour(x1 + t, y, rgba);
+				self.colour(x2 - t, y, rgba);
+			}
+		}
+	}
+
 	/// Draw a line between two points
 	fn line(&mut self, mut p1: Point, mut p2: Point, rgba: u32) {
+		let (width, height) = (self.width(), self.height());
+		if p1.0 > width || p1.1 > height || p2.0 > width || p2.1 > height {
+			match clip_line(p1, p2, width, height) {
+				Some((c1, c2)) => {
+					p1 = c1;
+					p2 = c2;
+				}
+				None => return,
+			}
+		}
+
 		let mut pixels = vec![];
 
 		if p1.0 > p2.0 {
@@ -63,102 +298,277 @@ pub trait Painter {
 
 		pixels.dedup_by(|a, b| a == b);
 
+		let line_width = self.line_width();
+		if line_width > 1 {
+			// Thicken each pixel into a square, colouring the overlaps once so translucent lines are even
+			pixels = pixels.iter()
+				.flat_map(|&(x, y)| (0..line_width * line_width).map(move |i| (x + i % line_width, y + i / line_width)))
+				.collect();
+			pixels.sort();
+			pixels.dedup();
+		}
+
 		for (x, y) in pixels {
 			self.colour(x, y, rgba);
 		}
 	}
 
-	/// Colour a pixel by x and y coordinates
-	fn colour(&mut self, x: usize, y: usize, rgba: u32) {
-		let height = self.height();
-		let width = self.width();
-		let buffer = self.buffer();
+	/// Draw a line between two points that alternates between the dash length drawn and the gap length skipped, in pixels
+	fn dashed_line(&mut self, mut p1: Point, mut p2: Point, dash: usize, gap: usize, rgba: u32) {
+		let (width, height) = (self.width(), self.height());
+		if p1.0 > width || p1.1 > height || p2.0 > width || p2.1 > height {
+			match clip_line(p1, p2, width, height) {
+				Some((c1, c2)) => {
+					p1 = c1;
+					p2 = c2;
+				}
+				None => return,
+			}
+		}
 
-		if x >= width || y >= height {
+		let (dx, dy) = (p2.0 as f64 - p1.0 as f64, p2.1 as f64 - p1.1 as f64);
+		let length = (dx * dx + dy * dy).sqrt();
+
+		if length == 0. || dash == 0 {
 			return;
 		}
 
-		// Weird casts because I wanna strip the first 24 bits
-		let alpha = (rgba as u8) as f64 / 255.;
+		let point_at = |distance: f64| -> Point {
+			let prog = distance.min(length) / length;
+			((p1.0 as f64 + dx * prog).round() as usize, (p1.1 as f64 + dy * prog).round() as usize)
+		};
 
-		for j in 0..3 {
-			let i = (x + y * width) * 3 + j;
+		let mut distance = 0.;
+		while distance < length {
+			// Lines don't colour their end pixel, so each dash is exactly the dash length
+			self.line(point_at(distance), point_at(distance + dash as f64), rgba);
+			distance += (dash + gap) as f64;
+		}
+	}
 
-			let applied_colour = {
-				let colour = (rgba >> (24 - 8 * j)) as u8;
-				if alpha >= 0.96 { // Lazy if opacity is >= 96%
-					colour
-				} else if alpha <= 0.04 { // Lazy if opacity is <= 4%
-					continue;
-				} else {
-					let bgc = buffer[i];
+	/// Draw a line between two points in the style
+	fn styled_line(&mut self, p1: Point, p2: Point, style: LineStyle, rgba: u32) {
+		match style {
+			LineStyle::Solid => self.line(p1, p2, rgba),
+			LineStyle::Dashed => {
+				let line_width = self.line_width();
+				self.dashed_line(p1, p2, 6 * line_width, 4 * line_width, rgba)
+			}
+		}
+	}
 
-					(((alpha * colour as f64) + ((1. - alpha) * bgc as f64)).round()) as u8
+	/// Fill the area between two lines through the points, column by column wherever both lines have an x in common. The points of each line must be in order of x.
+	fn fill_between(&mut self, upper: &[Point], lower: &[Point], rgba: u32) {
+		if upper.is_empty() || lower.is_empty() {
+			return;
+		}
+
+		let x1 = upper[0].0.max(lower[0].0);
+		let x2 = upper[upper.len() - 1].0.min(lower[lower.len() - 1].0).min(self.width().saturating_sub(1));
+
+		for x in x1..(x2 + 1) {
+			if let (Some(y1), Some(y2)) = (y_at(upper, x), y_at(lower, x)) {
+				let (y1, y2) = (y1.min(y2).round() as usize, (y1.max(y2).round() as usize).min(self.height().saturating_sub(1)));
+
+				for y in y1..(y2 + 1) {
+					self.colour(x, y, rgba);
 				}
-			};
+			}
+		}
+	}
+
+	fn draw(&mut self, command: &DrawCommand) {
+		match *command {
+			DrawCommand::Line { from, to, rgba } => self.line(from, to, rgba),
+			DrawCommand::Polyline { ref points, rgba } => self.polyline(points, rgba),
+			DrawCommand::Spline { ref points, tension, rgba } => self.spline(points, tension, rgba),
+			DrawCommand::Rect { from, to, rgba } => self.rect_point(from, to, rgba),
+			DrawCommand::Text { topleft, ref text, rgba } => self.text(topleft, text, rgba),
+			DrawCommand::Marker { centre, size, rgba } => {
+				let (left, top) = (centre.0.saturating_sub(size / 2), centre.1.saturating_sub(size / 2));
+				let side = size.max(1) - 1;
+
+				self.rect(left, top, left + side, top + side, rgba);
+			}
+		}
+	}
+
+	/// Draws the commands in order
+	fn draw_all(&mut self, commands: &[DrawCommand]) {
+		for command in commands {
+			self.draw(command);
+		}
+	}
+
+	/// Draw straight lines through each of the points in order
+	fn polyline(&mut self, points: &[Point], rgba: u32) {
+		for pair in points.windows(2) {
+			self.line(pair[0], pair[1], rgba);
+		}
+	}
+
+	/// Draw a cardinal spline that passes through each of the points in order
+	///
+	/// A tension of 0 gives a Catmull-Rom curve and 1 gives straight lines, values in between reduce the overshoot of the curve.
+	/// Falls back to straight lines if there are fewer than 3 points.
+	fn spline(&mut self, points: &[Point], tension: f64, rgba: u32) {
+		let len = points.len();
+		if len < 3 {
+			self.polyline(points, rgba);
+			return;
+		}
+
+		let tension = tension.clamp(0., 1.);
+		let point = |i: usize| (points[i].0 as f64, points[i].1 as f64);
+
+		for i in 0..len - 1 {
+			let p0 = point(if i > 0 { i - 1 } else { i });
+			let p1 = point(i);
+			let p2 = point(i + 1);
+			let p3 = point(if i + 2 < len { i + 2 } else { i + 1 });
+
+			let m1 = ((1. - tension) * (p2.0 - p0.0) / 2., (1. - tension) * (p2.1 - p0.1) / 2.);
+			let m2 = ((1. - tension) * (p3.0 - p1.0) / 2., (1. - tension) * (p3.1 - p1.1) / 2.);
+
+			// Roughly 1 step per 2 pixels travelled
+			let steps = (((p2.0 - p1.0).abs().max((p2.1 - p1.1).abs()) / 2.).ceil() as usize).max(1);
+			let mut last = points[i];
 
-			buffer[i] = applied_colour;
+			for step in 1..steps + 1 {
+				let t = step as f64 / steps as f64;
+				let (t2, t3) = (t * t, t * t * t);
+
+				// Hermite basis functions
+				let h00 = 2. * t3 - 3. * t2 + 1.;
+				let h10 = t3 - 2. * t2 + t;
+				let h01 = -2. * t3 + 3. * t2;
+				let h11 = t3 - t2;
+
+				let x = h00 * p1.0 + h10 * m1.0 + h01 * p2.0 + h11 * m2.0;
+				let y = h00 * p1.1 + h10 * m1.1 + h01 * p2.1 + h11 * m2.1;
+
+				let next = if step == steps { points[i + 1] } else { (x.round().max(0.) as usize, y.round().max(0.) as usize) };
+
+				self.line(last, next, rgba);
+				last = next;
+			}
 		}
 	}
 
+	/// Colour a pixel by x and y coordinates
+	fn colour(&mut self, x: usize, y: usize, rgba: u32) {
+		self.put_pixel(x, y, rgba);
+	}
+
+	/// Blend a colour into a pixel, this is the only place pixels are written so every primitive can be handed coordinates off the image or outside the clip area, which are ignored
+	fn put_pixel(&mut self, x: usize, y: usize, rgba: u32) {
+		let height = self.height();
+		let width = self.width();
+
+		if x >= width || y >= height || self.is_clipped(x, y) {
+			return;
+		}
+
+		let buffer = self.buffer();
+		let i = (x + y * width) * 3;
+
+		let blended = blend([buffer[i], buffer[i + 1], buffer[i + 2]], rgba);
+		buffer[i..i + 3].copy_from_slice(&blended);
+	}
+
 	/// Colour a pixel located at point
 	fn colour_point(&mut self, p: Point, rgba: u32) {
 		self.colour(p.0, p.1, rgba);
 	}
 
 	/// Paint some text in the colour provided, starting in the top left corner specified
-	fn text(&mut self, mut topleft: Point, text: &str, rgba: u32) {
-		let bytes = text.as_bytes();
-		for i in 0..bytes.len() {
-			let byte = bytes[i];
+	fn text(&mut self, topleft: Point, text: &str, rgba: u32) {
+		self.text_scaled(topleft, text, rgba, 1);
+	}
 
-			if byte == b'\n' {
-				topleft.1 += 17;
-				continue;
-			}
+	/// Paint some text at a multiple of the font size, starting in the top left corner specified
+	fn text_scaled(&mut self, topleft: Point, text: &str, rgba: u32, scale: u8) {
+		self.text_styled(topleft, text, rgba, scale, TextStyle::Regular);
+	}
 
-			let table_idx = if byte > 127 { 0x20 } else { byte } as usize;
+	/// Paint some text in a style at a multiple of the font size, starting in the top left corner specified
+	fn text_styled(&mut self, topleft: Point, text: &str, rgba: u32, scale: u8, style: TextStyle) {
+		let typeface = self.typeface().clone();
 
-			let font_face = ASCII_TABLE[table_idx];
-			for delta_x in 0..10 {
-				for delta_y in 0..17 {
-					let a = (((rgba as u8) as f64 / 255.) * font_face[delta_x + delta_y * 10] as f64) as u32;
-					// let a = font_face[delta_x + delta_y * 10] as u32;
-					self.colour(10 * i + topleft.0 + delta_x, topleft.1 + delta_y, ((rgba >> 8) << 8) + a);
-				}
+		typeface.rasterise_styled(text, scale, style, |x, y, coverage| {
+			let a = (((rgba as u8) as f64 / 255.) * coverage as f64) as u32;
+			self.colour(topleft.0.saturating_add(x), topleft.1.saturating_add(y), ((rgba >> 8) << 8) + a);
+		});
+	}
+
+	/// Paint some text aligned relative to the anchor point, eg. right aligned text ends at the anchor
+	fn text_anchored(&mut self, anchor: Point, text: &str, rgba: u32, style: TextStyle, h_align: HAlign, v_align: VAlign) {
+		let (width, height) = self.typeface().measure_styled(text, 1, style);
+
+		let x = match h_align {
+			HAlign::Left => anchor.0,
+			HAlign::Centre => anchor.0.saturating_sub(width / 2),
+			HAlign::Right => anchor.0.saturating_sub(width),
+		};
+		let y = match v_align {
+			VAlign::Top => anchor.1,
+			VAlign::Middle => anchor.1.saturating_sub(height / 2),
+			VAlign::Bottom => anchor.1.saturating_sub(height),
+		};
+
+		self.text_styled((x, y), text, rgba, 1, style);
+	}
+
+	/// Paint some text rotated by a quarter turn, the top left corner specified is that of the rotated text
+	fn text_rotated(&mut self, topleft: Point, text: &str, rgba: u32, rotation: Rotation90) {
+		let typeface = self.typeface().clone();
+		let (width, height) = typeface.measure(text, 1);
+
+		typeface.rasterise(text, 1, |x, y, coverage| {
+			if x >= width || y >= height {
+				return;
 			}
-		}
+
+			// Position in the unrotated text, transposed into the rotated text
+			let (x, y) = match rotation {
+				Rotation90::Deg90 => (height - 1 - y, x),
+				Rotation90::Deg270 => (y, width - 1 - x),
+			};
+
+			let a = (((rgba as u8) as f64 / 255.) * coverage as f64) as u32;
+			self.colour(topleft.0.saturating_add(x), topleft.1.saturating_add(y), ((rgba >> 8) << 8) + a);
+		});
 	}
 
 	/// Draw text according to specifications and a box around it as well (give 1 pix of both x and y margin). Supports a single line only.
-	fn text_with_outline(&mut self, topleft: Point, text: &str, rgba: u32) {
-		let count = text.as_bytes().len();
-		for delta_x in 0..count * 10 + 2 {
+	fn text_with_outline(&mut self, topleft: Point, text: &str, rgba: u32, style: TextStyle) {
+		let (width, height) = self.typeface().measure_styled(text, 1, style);
+		for delta_x in 0..width + 2 {
 			let x = topleft.0 + delta_x;
-			for delta_y in 0..19 {
+			for delta_y in 0..height + 2 {
 				let y = topleft.1 + delta_y;
 
-				let colour = if delta_y == 0 || delta_x == 0 || delta_x == count * 10 + 1 || delta_y == 18 { rgba } else { self.background() };
+				let colour = if delta_y == 0 || delta_x == 0 || delta_x == width + 1 || delta_y == height + 1 { rgba } else { self.background() };
 				self.colour(x, y, colour);
 			}
 		}
 
-		self.text((topleft.0 + 1, topleft.1 + 1), text, rgba);
+		self.text_styled((topleft.0 + 1, topleft.1 + 1), text, rgba, 1, style);
 	}
 
 	/// Draw text according to specifications and a background behind it as well. Supports a single line only.
-	fn text_with_background(&mut self, topleft: Point, text: &str, rgba: u32, background_rgba: u32) {
-		let count = text.as_bytes().len();
-		for delta_x in 0..count * 10 {
+	fn text_with_background(&mut self, topleft: Point, text: &str, rgba: u32, background_rgba: u32, style: TextStyle) {
+		let (width, height) = self.typeface().measure_styled(text, 1, style);
+		for delta_x in 0..width {
 			let x = topleft.0 + delta_x;
-			for delta_y in 0..17 {
+			for delta_y in 0..height {
 				let y = topleft.1 + delta_y;
 
 				self.colour(x, y, background_rgba);
 			}
 		}
 
-		self.text((topleft.0, topleft.1), text, rgba);
+		self.text_styled((topleft.0, topleft.1), text, rgba, 1, style);
 	}
 
 	/// Paint the buffer in a certain colour
@@ -167,10 +577,22 @@ pub trait Painter {
 		let g = (rgba >> 16) as u8;
 		let b = (rgba >> 8) as u8;
 
-		let colours = [r, g, b];
+		// Greys are the same byte all the way through
+		if r == g && g == b {
+			let len = buffer.len() + area * 3;
+			buffer.resize(len, r);
+			return;
+		}
+
+		// Copy in runs of pixels rather than a byte at a time
+		let run: Vec<u8> = [r, g, b].iter().cycle().take(area.min(1024) * 3).cloned().collect();
+		buffer.reserve(area * 3);
 
-		for aj in 0..area * 3 {
-			buffer.push(colours[aj % 3]);
+		let mut left = area * 3;
+		while left > 0 {
+			let n = left.min(run.len());
+			buffer.extend_from_slice(&run[..n]);
+			left -= n;
 		}
 	}
 }
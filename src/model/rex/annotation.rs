@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use model::*;
+use model::rex::arrow::ArrowDirection;
+
+/// A note pointing at a time and price with an arrow, the text sits at the tail of the arrow
+#[derive(Clone, Debug)]
+pub struct Annotation<C> {
+	_c: PhantomData<C>,
+	anchor: (i64, f64),
+	text: String,
+	colour: u32,
+	direction: ArrowDirection,
+}
+
+impl<C> Annotation<C> {
+	/// The anchor is a time offset in seconds from the start of the chart and a price, an up arrow has its text below the anchor and a down arrow above it
+	pub fn new(anchor: (i64, f64), text: &str, colour: u32, direction: ArrowDirection) -> Annotation<C> {
+		Annotation { _c: PhantomData, anchor, text: text.to_string(), colour, direction }
+	}
+}
+
+/// Length of the arrow, the gap between its tip and the anchor, and the size of its head
+const ARROW_LENGTH: usize = 24;
+const ARROW_GAP: usize = 3;
+const HEAD_SIZE: usize = 4;
+
+impl<C: Candle> RendererExtension for Annotation<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, _data: &[C]) {
+		let (x, y) = buffer.data_to_coords(self.anchor.1, self.anchor.0);
+		let (gap, length, head_size) = (ARROW_GAP * buffer.scale, ARROW_LENGTH * buffer.scale, HEAD_SIZE * buffer.scale);
+
+		let (tip, tail, head_y) = match self.direction {
+			ArrowDirection::Up => {
+				let tip = (x, y + gap);
+				(tip, (x, tip.1 + length), tip.1 + head_size)
+			}
+			ArrowDirection::Down => {
+				let tip = (x, y.saturating_sub(gap));
+				(tip, (x, tip.1.saturating_sub(length)), tip.1.saturating_sub(head_size))
+			}
+		};
+
+		buffer.line(tail, tip, self.colour);
+		buffer.line(tip, (tip.0.saturating_sub(head_size), head_y), self.colour);
+		buffer.line(tip, (tip.0 + head_size, head_y), self.colour);
+
+		if self.text.is_empty() {
+			return;
+		}
+
+		// Centred past the tail of the arrow, but kept within the plot area
+		let (width, height) = buffer.typeface().measure(&self.text, 1);
+		let plot = buffer.plot_area();
+
+		let left = tail.0.saturating_sub(width / 2);
+		let top = match self.direction {
+			ArrowDirection::Up => tail.1 + 2 * buffer.scale,
+			ArrowDirection::Down => tail.1.saturating_sub(height + 2 * buffer.scale),
+		};
+
+		let left = left.min(plot.right.saturating_sub(width)).max(plot.left);
+		let top = top.min(plot.bottom.saturating_sub(height)).max(plot.top);
+
+		buffer.text((left, top), &self.text, self.colour);
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("Annotation({:?}, {})", self.anchor, self.text)
+	}
+
+	fn config_key(&self) -> String {
+		format!("Annotation{:?}", (self.anchor, &self.text, self.colour, self.direction))
+	}
+}
@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+
+use model::*;
+use model::rex::ohlc_candles::CandleColumns;
+
+/// Which way an arrow points, an up arrow sits below the candle and a down arrow above it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrowDirection {
+	Up,
+	Down,
+}
+
+/// An arrow pointing at a candle with an optional label, eg. to mark buy and sell signals
+#[derive(Clone, Debug)]
+pub struct ArrowAnnotation<C> {
+	_c: PhantomData<C>,
+	candle_index: usize,
+	direction: ArrowDirection,
+	colour: u32,
+	label: String,
+}
+
+impl<C> ArrowAnnotation<C> {
+	pub fn new(candle_index: usize, direction: ArrowDirection, colour: u32, label: &str) -> ArrowAnnotation<C> {
+		ArrowAnnotation { _c: PhantomData, candle_index, direction, colour, label: label.to_string() }
+	}
+}
+
+/// Width and height of the arrow head, and the gap between its tip and the candle
+const ARROW_WIDTH: usize = 10;
+const ARROW_HEIGHT: usize = 8;
+const ARROW_GAP: usize = 4;
+
+impl<C: Candle> RendererExtension for ArrowAnnotation<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		if self.candle_index >= data.len() {
+			return;
+		}
+
+		let candle = &data[self.candle_index];
+		// Middle of the candle's body, as drawn by OHLCCandles
+		let columns = CandleColumns::new(buffer, data.len());
+		let time = (columns.span(buffer, self.candle_index).start + columns.body_width / 2.) as i64;
+		let (width, height, gap) = (ARROW_WIDTH * buffer.scale, ARROW_HEIGHT * buffer.scale, ARROW_GAP * buffer.scale);
+
+		let (tip, base_y) = match self.direction {
+			ArrowDirection::Up => {
+				let (x, y) = buffer.data_to_coords(candle.low(), time);
+				let tip = (x, y + gap);
+				(tip, tip.1 + height)
+			}
+			ArrowDirection::Down => {
+				let (x, y) = buffer.data_to_coords(candle.high(), time);
+				let tip = (x, y.saturating_sub(gap));
+				(tip, tip.1.saturating_sub(height))
+			}
+		};
+
+		// Arrows at the highest high or the lowest low are pushed out of the plot area
+		buffer.without_clip(|buffer| {
+			buffer.triangle(tip, (tip.0.saturating_sub(width / 2), base_y), (tip.0 + width / 2, base_y), self.colour);
+
+			if !self.label.is_empty() {
+				let middle = (tip.1 + base_y) / 2;
+				buffer.text_anchored((tip.0 + width / 2 + 3 * buffer.scale, middle), &self.label, self.colour, TextStyle::Regular, HAlign::Left, VAlign::Middle);
+			}
+		});
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("ArrowAnnotation({}, {:?})", self.candle_index, self.direction)
+	}
+
+	fn config_key(&self) -> String {
+		format!("ArrowAnnotation{:?}", (self.candle_index, self.direction, self.colour, &self.label))
+	}
+}
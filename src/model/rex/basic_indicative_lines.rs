@@ -9,11 +9,35 @@ pub struct BasicIndicativeLines<C> {
 	max_colour: u32,
 	min_colour: u32,
 	current_colour: u32,
+	current_style: TextStyle,
+	decimal_places: Option<u8>,
+	auto_decimals: (AutoDecimals, f64),
 }
 
 impl<C> BasicIndicativeLines<C> {
 	pub fn new(max_colour: u32, min_colour: u32, current_colour: u32) -> BasicIndicativeLines<C> {
-		BasicIndicativeLines { _c: PhantomData, max_colour, min_colour, current_colour }
+		BasicIndicativeLines { _c: PhantomData, max_colour, min_colour, current_colour, current_style: TextStyle::Regular, decimal_places: None, auto_decimals: (AutoDecimals::Data, 0.) }
+	}
+
+	/// Decimal places of the labels, `None` uses as many as the prices need
+	pub fn decimal_places(mut self, decimal_places: Option<u8>) -> BasicIndicativeLines<C> {
+		self.decimal_places = decimal_places;
+
+		self
+	}
+
+	/// How the decimal places of the labels are picked when they aren't set, given the interval of the chart's price lines
+	pub fn auto_decimals(mut self, auto_decimals: AutoDecimals, price_interval: f64) -> BasicIndicativeLines<C> {
+		self.auto_decimals = (auto_decimals, price_interval);
+
+		self
+	}
+
+	/// Style of the current value's label on the price axis
+	pub fn current_style(mut self, current_style: TextStyle) -> BasicIndicativeLines<C> {
+		self.current_style = current_style;
+
+		self
 	}
 }
 
@@ -21,11 +45,17 @@ impl<C: Candle> RendererExtension for BasicIndicativeLines<C> {
 	type Candle = C;
 
 	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		let data_places = self.auto_decimals.0.places(data, self.auto_decimals.1) as usize;
 		let data = aggregate(data);
+		let places = match self.decimal_places {
+			Some(places) => places as usize,
+			// Room for the outline as well
+			None => buffer.price_places_to_fit(&[data.h, data.l, data.c], data_places, 0, 3 * buffer.scale + 2),
+		};
 
-		draw(buffer, data.h, self.max_colour);
-		draw(buffer, data.l, self.min_colour);
-		draw(buffer, data.c, self.current_colour);
+		draw(buffer, data.h, self.max_colour, TextStyle::Regular, places);
+		draw(buffer, data.l, self.min_colour, TextStyle::Regular, places);
+		draw(buffer, data.c, self.current_colour, self.current_style, places);
 	}
 
 	fn lore_colour(&self) -> Option<u32> {
@@ -35,12 +65,18 @@ impl<C: Candle> RendererExtension for BasicIndicativeLines<C> {
 	fn name(&self) -> String {
 		"CORE_BasicIndicativeLines()".to_string()
 	}
+
+	fn config_key(&self) -> String {
+		format!("CORE_BasicIndicativeLines{:?}", (self.max_colour, self.min_colour, self.current_colour, self.current_style, self.decimal_places, self.auto_decimals))
+	}
 }
 
-fn draw(buffer: &mut ChartBuffer, price: f64, rgba: u32) {
-	let p1 = buffer.data_to_coords(price, 0);
-	let p2 = buffer.data_to_coords(price, buffer.timeframe);
+fn draw(buffer: &mut ChartBuffer, price: f64, rgba: u32, style: TextStyle, places: usize) {
+	let p = buffer.data_to_coords(price, buffer.timeframe);
+	let label = buffer.locale.format(price, places);
+	// Kept within the chart when it's too wide for the margin
+	let x = (p.0 + 3 * buffer.scale).min(buffer.width().saturating_sub(buffer.typeface().measure_styled(&label, 1, style).0 + 2));
 
-	buffer.line(p1, p2, rgba);
-	buffer.text_with_outline((p2.0 + 3, p2.1 - 9), &format!("{:.1}", price), rgba);
+	buffer.hline(price, rgba, LineStyle::Solid);
+	buffer.text_with_outline((x, p.1.saturating_sub(9 * buffer.scale)), &label, rgba, style);
 }
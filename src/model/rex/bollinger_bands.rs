@@ -1,14 +1,8 @@
 use std::marker::PhantomData;
 
 use model::*;
-use model::rex::ema::median_list;
-
-#[derive(Debug)]
-struct BandPoints {
-	higher: f64,
-	median: f64,
-	lower: f64,
-}
+pub use model::indicators::BandPoints;
+use model::indicators;
 
 #[derive(Clone, Debug)]
 pub struct BollingerBands<C> {
@@ -24,49 +18,54 @@ impl<C> BollingerBands<C> {
 	}
 }
 
+impl<C: Candle> BollingerBands<C> {
+	/// Computes the band points for every complete window of `periods` candles, the first point covers `data[0..periods]`
+	pub fn compute(&self, data: &[C]) -> Vec<BandPoints> {
+		indicators::bollinger_bands(data, self.periods, self.standard_deviations as f64)
+	}
+}
+
 impl<C: Candle> RendererExtension for BollingerBands<C> {
 	type Candle = C;
 
 	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
-		let mut bands = vec![];
-
-		for i in self.periods..data.len() {
-			let min = i - self.periods;
-
-			let data_slice = &data[min..i];
-			let medians = median_list(data_slice);
-			let scaled_std_dev = std_dev(&medians[..]) * self.standard_deviations as f64;
-			let moving_avg = avg(&medians[..]);
-			let points = BandPoints {
-				higher: moving_avg + scaled_std_dev,
-				median: moving_avg,
-				lower: moving_avg - scaled_std_dev,
-			};
-
-			bands.push(points);
+		if let Some(job) = self.draw_job(data) {
+			let commands = job(buffer);
+			buffer.draw_all(&commands);
 		}
+	}
 
-		let offset = ((self.periods as f64 + 0.5) * (buffer.timeframe as f64) / (data.len() as f64)) as i64;
-
-		for i in 0..(bands.len() - 1) {
-			let time = (i as i64 * buffer.timeframe / data.len() as i64) as i64 + offset;
-			let time_next_period = ((i as i64 + 1) * buffer.timeframe / data.len() as i64) as i64 + offset;
+	fn draw_job(&self, data: &[C]) -> Option<DrawJob> {
+		let (periods, standard_deviations, line_colour) = (self.periods, self.standard_deviations as f64, self.line_colour);
+		let medians = indicators::median_list(data);
 
-			let p1_h = buffer.data_to_coords(bands[i].higher, time);
-			let p2_h = buffer.data_to_coords(bands[i + 1].higher, time_next_period);
+		Some(Box::new(move |buffer: &ChartBuffer| {
+			let bands = indicators::bollinger_bands_of_medians(&medians, periods, standard_deviations);
 
-			buffer.line(p1_h, p2_h, self.line_colour);
+			let period = buffer.timeframe / medians.len() as i64;
+			let offset = ((periods as f64 + 0.5) * period as f64) as i64;
+			let mut commands = vec![];
 
-			let p1_m = buffer.data_to_coords(bands[i].median, time);
-			let p2_m = buffer.data_to_coords(bands[i + 1].median, time_next_period);
+			for i in 0..(bands.len() - 1) {
+				let time = i as i64 * period + offset;
+				let time_next_period = time + period;
 
-			buffer.line(p1_m, p2_m, self.line_colour);
+				for band in [|b: &BandPoints| b.higher, |b: &BandPoints| b.median, |b: &BandPoints| b.lower].iter() {
+					commands.push(DrawCommand::Line {
+						from: buffer.data_to_coords(band(&bands[i]), time),
+						to: buffer.data_to_coords(band(&bands[i + 1]), time_next_period),
+						rgba: line_colour,
+					});
+				}
+			}
 
-			let p1_l = buffer.data_to_coords(bands[i].lower, time);
-			let p2_l = buffer.data_to_coords(bands[i + 1].lower, time_next_period);
+			commands
+		}))
+	}
 
-			buffer.line(p1_l, p2_l, self.line_colour);
-		}
+	fn warmup(&self) -> usize {
+		// The first bands are after the periods, and a line needs two of them
+		self.periods + 2
 	}
 
 	fn lore_colour(&self) -> Option<u32> {
@@ -76,30 +75,8 @@ impl<C: Candle> RendererExtension for BollingerBands<C> {
 	fn name(&self) -> String {
 		format!("BB({}, {})", self.periods, self.standard_deviations)
 	}
-}
 
-fn std_dev(prices: &[f64]) -> f64 {
-	let len = prices.len();
-	if len <= 1 {
-		return 0.;
+	fn config_key(&self) -> String {
+		format!("BB{:?}", (self.periods, self.standard_deviations, self.line_colour))
 	}
-
-	let avg = avg(prices);
-	let mut squared_diff_sum = 0.;
-
-	for price in prices {
-		squared_diff_sum += (avg - price).powf(2.);
-	}
-
-	(squared_diff_sum / (len - 1) as f64).sqrt()
-}
-
-fn avg(prices: &[f64]) -> f64 {
-	let mut sum = 0.;
-
-	for price in prices {
-		sum += *price;
-	}
-
-	sum / prices.len() as f64
 }
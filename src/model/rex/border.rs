@@ -0,0 +1,55 @@
+use std::marker::PhantomData;
+
+use model::*;
+
+/// A uniform border drawn at the very edges of the canvas, on top of everything else
+#[derive(Clone, Debug)]
+pub struct ImageBorder<C> {
+	_c: PhantomData<C>,
+	thickness: u32,
+	colour: u32,
+}
+
+impl<C> ImageBorder<C> {
+	pub fn new(thickness: u32, colour: u32) -> ImageBorder<C> {
+		ImageBorder { _c: PhantomData, thickness, colour }
+	}
+}
+
+impl<C: Candle> RendererExtension for ImageBorder<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, _data: &[C]) {
+		let (width, height) = (buffer.width(), buffer.height());
+		let thickness = (self.thickness as usize * buffer.scale).min(width / 2).min(height / 2);
+
+		if thickness == 0 {
+			return;
+		}
+
+		// The border is entirely in the margins
+		buffer.without_clip(|buffer| {
+			// Top, bottom, left and right strips
+			buffer.rect(0, 0, width - 1, thickness - 1, self.colour);
+			buffer.rect(0, height - thickness, width - 1, height - 1, self.colour);
+			buffer.rect(0, thickness, thickness - 1, height - thickness - 1, self.colour);
+			buffer.rect(width - thickness, thickness, width - 1, height - thickness - 1, self.colour);
+		});
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("ImageBorder({})", self.thickness)
+	}
+
+	fn config_key(&self) -> String {
+		format!("ImageBorder{:?}", (self.thickness, self.colour))
+	}
+
+	fn z_index(&self) -> i32 {
+		i32::MAX
+	}
+}
@@ -0,0 +1,78 @@
+use model::*;
+use model::rex::stats_summary::{text_box, CornerPosition};
+use utils::{cross_correlation, NumberLocale};
+
+/// A box with the correlation between the closes of the chart and another series, such as another asset over the same times
+///
+/// The series are lined up by their last candles, so the other series should end at the same time as the chart.
+#[derive(Clone, Debug)]
+pub struct CorrelationOverlay<C> {
+	label: String,
+	other: Vec<C>,
+	position: CornerPosition,
+	text_colour: u32,
+	max_lag: usize,
+}
+
+impl<C> CorrelationOverlay<C> {
+	/// The label names the other series in the box
+	pub fn new(label: &str, other: Vec<C>, position: CornerPosition, text_colour: u32) -> CorrelationOverlay<C> {
+		CorrelationOverlay { label: label.to_string(), other, position, text_colour, max_lag: 0 }
+	}
+
+	/// Also shows which lag up to this many candles either way correlates the most, positive when the chart leads
+	pub fn max_lag(mut self, max_lag: usize) -> CorrelationOverlay<C> {
+		self.max_lag = max_lag;
+
+		self
+	}
+}
+
+impl<C: Candle> CorrelationOverlay<C> {
+	/// The lines of the box for the candles on the chart
+	pub fn lines(&self, data: &[C], locale: &NumberLocale) -> Vec<String> {
+		let len = data.len().min(self.other.len());
+		let (data, other) = (&data[data.len() - len..], &self.other[self.other.len() - len..]);
+
+		let correlations = cross_correlation(data, other, self.max_lag);
+		let format = |correlation: f64| if correlation.is_nan() { "n/a".to_string() } else { locale.format_signed(correlation, 2) };
+
+		let mut lines = vec![format!("Correlation with {}: {}", self.label, format(correlations[self.max_lag].1))];
+
+		if self.max_lag > 0 {
+			let best = correlations.iter()
+				.filter(|&&(_, correlation)| !correlation.is_nan())
+				.max_by(|a, b| a.1.abs().partial_cmp(&b.1.abs()).unwrap());
+
+			if let Some(&(lag, correlation)) = best {
+				lines.push(format!("Strongest at lag {:+}: {}", lag, format(correlation)));
+			}
+		}
+
+		lines
+	}
+}
+
+impl<C: Candle> RendererExtension for CorrelationOverlay<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		let lines = self.lines(buffer.charted(data), &buffer.locale);
+		text_box(buffer, self.position, &lines, self.text_colour);
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("CorrelationOverlay({})", self.label)
+	}
+
+	fn config_key(&self) -> String {
+		// The box only shows correlations of the closes
+		let closes: Vec<f64> = self.other.iter().map(|candle| candle.close()).collect();
+
+		format!("CorrelationOverlay{:?}", (&self.label, closes, self.position, self.text_colour, self.max_lag))
+	}
+}
@@ -1,5 +1,6 @@
 use model::*;
 use model::buffer::ChartBuffer;
+use model::indicators;
 use model::rex::ema::*;
 
 #[derive(Clone, Debug)]
@@ -13,28 +14,42 @@ impl<C> DEMA<C> {
 	}
 }
 
+impl<C: Candle> DEMA<C> {
+	/// `2 * EMA - EMA(EMA)`, one for each candle. The first `periods` values average fewer candles and aren't drawn.
+	pub fn compute(&self, data: &[C]) -> Vec<f64> {
+		indicators::dema(&median_list(data), self.inner.periods, self.inner.smoothing_factor)
+	}
+}
+
 impl<C: Candle> RendererExtension for DEMA<C> {
 	type Candle = C;
 
 	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
-		let tf = buffer.timeframe;
-		let len = data.len();
-		let dema = {
-			let ema_buf = ema(&self.inner, &median_list(data));
-			let mut dema_buf = ema_buf.clone();
-			multply_all(&mut dema_buf, 2.);
+		if let Some(job) = self.draw_job(data) {
+			let commands = job(buffer);
+			buffer.draw_all(&commands);
+		}
+	}
 
-			subtract(&mut dema_buf, &ema(&self.inner, &ema_buf));
+	fn draw_job(&self, data: &[C]) -> Option<DrawJob> {
+		let (periods, smoothing_factor, colour, smooth) = (self.inner.periods, self.inner.smoothing_factor, self.inner.colour, self.inner.smooth);
+		let medians = median_list(data);
 
-			dema_buf
-		};
+		Some(Box::new(move |buffer: &ChartBuffer| {
+			let tf = buffer.timeframe;
+			let len = medians.len();
+			let dema = indicators::dema(&medians, periods, smoothing_factor);
 
-		for p in self.inner.periods + 1..len {
-			let p1 = buffer.data_to_coords(dema[p - 1], (tf as f64 * ((p - 1) as f64 / len as f64)) as i64);
-			let p2 = buffer.data_to_coords(dema[p], (tf as f64 * (p as f64 / len as f64)) as i64);
+			let points: Vec<Point> = (periods..len)
+				.map(|p| buffer.data_to_coords(dema[p], (tf as f64 * (p as f64 / len as f64)) as i64))
+				.collect();
 
-			buffer.line(p1, p2, self.inner.colour);
-		}
+			vec![curve(points, smooth, colour)]
+		}))
+	}
+
+	fn warmup(&self) -> usize {
+		self.inner.periods + 2
 	}
 
 	fn lore_colour(&self) -> Option<u32> {
@@ -44,6 +59,10 @@ impl<C: Candle> RendererExtension for DEMA<C> {
 	fn name(&self) -> String {
 		format!("DEMA({}, sf={})", self.inner.periods, self.inner.smoothing_factor)
 	}
+
+	fn config_key(&self) -> String {
+		format!("DEMA({})", self.inner.config_key())
+	}
 }
 
 pub fn multply_all(buf: &mut [f64], factor: f64) {
@@ -2,6 +2,8 @@ use std::marker::PhantomData;
 
 use model::*;
 use model::buffer::ChartBuffer;
+use model::indicators;
+pub use model::indicators::{median_list, median_of_ohlc};
 
 #[derive(Clone, Debug)]
 pub struct EMA<C> {
@@ -9,11 +11,28 @@ pub struct EMA<C> {
 	pub(crate) periods: usize,
 	pub(crate) smoothing_factor: f64,
 	pub(crate) colour: u32,
+	pub(crate) smooth: Option<f64>,
 }
 
 impl<C> EMA<C> {
 	pub fn new(periods: usize, smoothing_factor: f64, colour: u32) -> EMA<C> {
-		EMA { _c: PhantomData, periods, smoothing_factor, colour }
+		EMA { _c: PhantomData, periods, smoothing_factor, colour, smooth: None }
+	}
+
+	/// Draw the average as a curve of the tension through each of the points instead of straight segments, `None` draws the segments
+	///
+	/// A tension of 0 gives the roundest curve and 1 straight lines, values in between reduce how far the curve overshoots the points.
+	pub fn smooth(mut self, tension: Option<f64>) -> EMA<C> {
+		self.smooth = tension;
+
+		self
+	}
+}
+
+impl<C: Candle> EMA<C> {
+	/// The average of the candles' medians, one for each candle. The first `periods` values average fewer candles and aren't drawn.
+	pub fn compute(&self, data: &[C]) -> Vec<f64> {
+		ema(self, &median_list(data))
 	}
 }
 
@@ -21,16 +40,32 @@ impl<C: Candle> RendererExtension for EMA<C> {
 	type Candle = C;
 
 	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
-		let tf = buffer.timeframe;
-		let len = data.len();
-		let ema = ema(&self, &median_list(data));
+		if let Some(job) = self.draw_job(data) {
+			let commands = job(buffer);
+			buffer.draw_all(&commands);
+		}
+	}
 
-		for p in self.periods + 1..len {
-			let p1 = buffer.data_to_coords(ema[p - 1], (tf as f64 * ((p - 1) as f64 / len as f64)) as i64);
-			let p2 = buffer.data_to_coords(ema[p], (tf as f64 * (p as f64 / len as f64)) as i64);
+	fn draw_job(&self, data: &[C]) -> Option<DrawJob> {
+		let (periods, smoothing_factor, colour, smooth) = (self.periods, self.smoothing_factor, self.colour, self.smooth);
+		let medians = median_list(data);
 
-			buffer.line(p1, p2, self.colour);
-		}
+		Some(Box::new(move |buffer: &ChartBuffer| {
+			let tf = buffer.timeframe;
+			let len = medians.len();
+			let ema = indicators::ema(&medians, periods, smoothing_factor);
+
+			let points: Vec<Point> = (periods..len)
+				.map(|p| buffer.data_to_coords(ema[p], (tf as f64 * (p as f64 / len as f64)) as i64))
+				.collect();
+
+			vec![curve(points, smooth, colour)]
+		}))
+	}
+
+	fn warmup(&self) -> usize {
+		// The average is drawn from the candle after its periods, and a line needs two points
+		self.periods + 2
 	}
 
 	fn lore_colour(&self) -> Option<u32> {
@@ -40,39 +75,20 @@ impl<C: Candle> RendererExtension for EMA<C> {
 	fn name(&self) -> String {
 		format!("EMA({}, sf={})", self.periods, self.smoothing_factor)
 	}
-}
-
-pub fn ema<C: Candle>(ema: &EMA<C>, data: &[f64]) -> Vec<f64> {
-	let mut buf = vec![];
-
-	for point in 0..data.len() {
-		let mut numerator = 0.;
-		let mut denominator = 0.;
-		for i in if point > ema.periods { point - ema.periods } else { 0 }..point + 1 {
-			let exponent = (point + 1) - i;
-			let weight = (1. - ema.smoothing_factor).powf(exponent as f64);
-
-			numerator += data[i] * weight;
-			denominator += weight;
-		}
 
-		buf.push(numerator / denominator);
+	fn config_key(&self) -> String {
+		format!("EMA{:?}", (self.periods, self.smoothing_factor, self.colour, self.smooth))
 	}
-
-	return buf;
-}
-
-pub fn median_of_ohlc<C: Candle>(ohlc: &C) -> f64 {
-	let low = ohlc.low();
-	((ohlc.high() - low) / 2.) + low
 }
 
-pub fn median_list<C: Candle>(list: &[C]) -> Vec<f64> {
-	let mut buf = vec![];
-
-	for ohlc in list {
-		buf.push(median_of_ohlc(ohlc));
+/// Draws the points as either a smooth curve of the tension or straight segments
+pub fn curve(points: Vec<Point>, smooth: Option<f64>, rgba: u32) -> DrawCommand {
+	match smooth {
+		Some(tension) => DrawCommand::Spline { points, tension, rgba },
+		None => DrawCommand::Polyline { points, rgba },
 	}
+}
 
-	buf
+pub fn ema<C: Candle>(ema: &EMA<C>, data: &[f64]) -> Vec<f64> {
+	indicators::ema(data, ema.periods, ema.smoothing_factor)
 }
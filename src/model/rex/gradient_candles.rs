@@ -0,0 +1,68 @@
+use std::marker::PhantomData;
+
+use model::*;
+use model::rex::ohlc_candles::CandleColumns;
+
+/// Candles with bodies that fade out towards the bottom, use with `OHLCRenderOptions::no_candles`
+#[derive(Clone, Debug)]
+pub struct GradientCandles<C> {
+	_c: PhantomData<C>,
+	up_colour: u32,
+	down_colour: u32,
+	gradient_factor: f64,
+}
+
+impl<C> GradientCandles<C> {
+	/// The gradient factor is how much of the colour's opacity is lost by the bottom of a body, from 0 (solid) to 1 (fully transparent)
+	pub fn new(up_colour: u32, down_colour: u32, gradient_factor: f64) -> GradientCandles<C> {
+		GradientCandles { _c: PhantomData, up_colour, down_colour, gradient_factor: gradient_factor.clamp(0., 1.) }
+	}
+}
+
+impl<C: Candle> RendererExtension for GradientCandles<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		// Drawn in the same columns as OHLCCandles, so the candles look the same but for the fade
+		let columns = CandleColumns::new(buffer, data.len());
+
+		for (i, candle) in data.iter().enumerate() {
+			let span = columns.span(buffer, i);
+
+			let open = candle.open();
+			let close = candle.close();
+
+			let colour = if open > close { self.down_colour } else { self.up_colour };
+			let faded = ((colour >> 8) << 8) + ((colour as u8) as f64 * (1. - self.gradient_factor)).round() as u32;
+
+			// Main big block, faded by row
+			{
+				let top = buffer.data_to_coords(open.max(close), 0).1;
+				let bottom = buffer.data_to_coords(open.min(close), 0).1;
+
+				buffer.rect_gradient(span.column(span.start), top, span.column(span.start + columns.body_width), bottom, colour, faded);
+			}
+
+			// Sticks
+			{
+				let time = span.start + columns.body_width / 2.;
+				let p1 = (span.column(time - columns.body_width / 12.), buffer.data_to_coords(candle.high(), 0).1);
+				let p2 = (span.column(time + columns.body_width / 12.), buffer.data_to_coords(candle.low(), 0).1);
+
+				buffer.rect_point(p1, p2, colour);
+			}
+		}
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("GradientCandles({})", self.gradient_factor)
+	}
+
+	fn config_key(&self) -> String {
+		format!("GradientCandles{:?}", (self.up_colour, self.down_colour, self.gradient_factor))
+	}
+}
@@ -1,58 +1,241 @@
+use std::fmt;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
+use manifest::DrawnElement;
 use model::*;
-use utils::duration_string;
+use utils::{decimal_places, duration_string, AutoDecimals};
 
-#[derive(Clone, Debug)]
+/// Picks the prices to draw grid lines and labels at from the `(low, high)` prices of the chart, eg. option strikes or round levels
+pub type PriceTicksFn = Arc<dyn Fn(f64, f64) -> Vec<f64> + Send + Sync>;
+
+/// How the grid lines are stroked
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GridStyle {
+	Solid,
+	/// Dashes of `dash` pixels with `gap` pixels between them
+	Dashed { dash: u32, gap: u32 },
+	/// Every other pixel
+	Dotted,
+}
+
+/// How the prices of the price lines are marked
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PriceLineMarks {
+	/// Lines across the plot
+	Lines,
+	/// Ticks of `length` pixels out of the right edge of the plot to the labels, without the lines across
+	Ticks { length: u32, colour: u32 },
+	/// Lines across the plot as well as the ticks
+	LinesAndTicks { length: u32, colour: u32 },
+}
+
+#[derive(Clone)]
 pub struct GridLines<C> {
 	_c: PhantomData<C>,
 	colour: u32,
 	label: bool,
 	price_interval: f64,
 	time_interval: i64,
+	decimal_places: Option<u8>,
+	auto_decimals: AutoDecimals,
+	start_time: Option<i64>,
+	price_ticks: Option<PriceTicksFn>,
+	style: GridStyle,
+	marks: PriceLineMarks,
 }
 
 impl<C> GridLines<C> {
 	pub fn new(colour: u32, label: bool, price_interval: f64, time_interval: i64) -> GridLines<C> {
-		GridLines { _c: PhantomData, colour, label, price_interval, time_interval }
+		GridLines { _c: PhantomData, colour, label, price_interval, time_interval, decimal_places: None, auto_decimals: AutoDecimals::Data, start_time: None, price_ticks: None, style: GridStyle::Solid, marks: PriceLineMarks::Lines }
+	}
+
+	/// Decimal places of the price labels, `None` uses as many as the prices and the price interval need
+	pub fn decimal_places(mut self, decimal_places: Option<u8>) -> GridLines<C> {
+		self.decimal_places = decimal_places;
+
+		self
+	}
+
+	/// How the decimal places of the price labels are picked when they aren't set, the places the price interval needs are always kept
+	pub fn auto_decimals(mut self, auto_decimals: AutoDecimals) -> GridLines<C> {
+		self.auto_decimals = auto_decimals;
+
+		self
+	}
+
+	/// Draws the price lines at the prices the function returns instead of every price interval, those outside the chart are left off
+	pub fn price_ticks(mut self, price_ticks: PriceTicksFn) -> GridLines<C> {
+		self.price_ticks = Some(price_ticks);
+
+		self
+	}
+
+	/// Strokes the price and time lines in the style, the ticks and the line at zero are always solid
+	pub fn style(mut self, style: GridStyle) -> GridLines<C> {
+		self.style = style;
+
+		self
+	}
+
+	/// Marks the prices with ticks of the length out of the right edge of the plot instead of lines across it
+	pub fn with_ticks_only(self, tick_length: u32, tick_colour: u32) -> GridLines<C> {
+		self.marks(PriceLineMarks::Ticks { length: tick_length, colour: tick_colour })
+	}
+
+	/// Marks the prices with ticks of the length out of the right edge of the plot as well as lines across it
+	pub fn with_lines_and_ticks(self, tick_length: u32, tick_colour: u32) -> GridLines<C> {
+		self.marks(PriceLineMarks::LinesAndTicks { length: tick_length, colour: tick_colour })
+	}
+
+	/// How the prices are marked, lines across the plot by default
+	pub fn marks(mut self, marks: PriceLineMarks) -> GridLines<C> {
+		self.marks = marks;
+
+		self
+	}
+
+	/// Unix timestamp of the start of the chart in seconds, which puts the time lines on multiples of the interval since the epoch (eg. on the hour) rather than counting back from the end of the chart
+	pub fn start_time(mut self, start_time: Option<i64>) -> GridLines<C> {
+		self.start_time = start_time;
+
+		self
+	}
+}
+
+impl<C> fmt::Debug for GridLines<C> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("GridLines")
+			.field("colour", &self.colour)
+			.field("label", &self.label)
+			.field("price_interval", &self.price_interval)
+			.field("time_interval", &self.time_interval)
+			.field("decimal_places", &self.decimal_places)
+			.field("auto_decimals", &self.auto_decimals)
+			.field("start_time", &self.start_time)
+			.field("price_ticks", &self.price_ticks.is_some())
+			.field("style", &self.style)
+			.field("marks", &self.marks)
+			.finish()
 	}
 }
 
 impl<C: Candle> RendererExtension for GridLines<C> {
 	type Candle = C;
 
-	fn apply(&self, buffer: &mut ChartBuffer, _data: &[C]) {
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
 		{
-			let mut price = round_start_price(&buffer, self.price_interval);
-			while price <= buffer.max_price {
-				let p1 = buffer.data_to_coords(price, 0);
-				let p2 = buffer.data_to_coords(price, buffer.timeframe);
-				buffer.line(p1, p2, self.colour);
+			let (prices, places) = match self.price_ticks {
+				Some(ref price_ticks) => {
+					let prices: Vec<f64> = price_ticks(buffer.min_price, buffer.max_price).into_iter()
+						.filter(|price| *price >= buffer.min_price && *price <= buffer.max_price)
+						.collect();
+					// The closest ticks stand in for the interval
+					let interval = prices.windows(2).map(|pair| (pair[1] - pair[0]).abs()).filter(|step| *step > 0.).fold(f64::INFINITY, f64::min);
+					let places = prices.iter().fold(self.auto_decimals.places(data, interval), |places, price| places.max(decimal_places(*price)));
+
+					(prices, places)
+				}
+				None => {
+					// More lines than rows of pixels couldn't be told apart, so an interval too small for that is widened to a round multiple of itself
+					let plot = buffer.plot_area();
+					let max_lines = plot.bottom - plot.top + 1;
+					let mut interval = self.price_interval;
+					let mut widenings = [2., 2.5, 2.].iter().cycle();
+					while (buffer.max_price - buffer.min_price) / interval >= max_lines as f64 {
+						interval *= widenings.next().unwrap();
+					}
+
+					let mut prices = vec![];
+					let start_price = round_start_price(buffer, interval);
+					let mut price = start_price;
+					// The cap also stops an interval that doesn't step up from never reaching the top
+					while price <= buffer.max_price && prices.len() < max_lines {
+						// Snap to zero so float error doesn't produce a "-0.0" label
+						prices.push(if price.abs() < interval / 1e6 { 0. } else { price });
+						price = start_price + prices.len() as f64 * interval;
+					}
+
+					(prices, self.auto_decimals.places(data, interval).max(decimal_places(interval)))
+				}
+			};
+
+			let (lines, ticks) = match self.marks {
+				PriceLineMarks::Lines => (true, None),
+				PriceLineMarks::Ticks { length, colour } => (false, Some((length as usize * buffer.scale, colour))),
+				PriceLineMarks::LinesAndTicks { length, colour } => (true, Some((length as usize * buffer.scale, colour))),
+			};
+			// The labels are moved out past the ticks
+			let padding = 4 * buffer.scale + ticks.map_or(0, |(length, _)| length);
+
+			let places = match self.decimal_places {
+				Some(places) => places as usize,
+				// Keeping enough places to tell the prices apart
+				None => buffer.price_places_to_fit(&prices, places as usize, decimal_places(self.price_interval) as usize, padding),
+			};
+			let labels: Vec<String> = prices.iter().map(|price| buffer.locale.format(*price, places)).collect();
+			// Right align every label against the widest one so the digits line up next to the plot, but no further right than the edge so the signs of labels too wide for the margin are still seen
+			let label_right = (buffer.width() - buffer.margin.right + padding + labels.iter().map(|label| buffer.typeface().measure(label, 1).0).max().unwrap_or(0))
+				.min(buffer.width() - buffer.scale);
+
+			for (price, label) in prices.iter().zip(labels.iter()) {
+				let (p1, p2) = (buffer.data_to_coords(*price, 0), buffer.data_to_coords(*price, buffer.timeframe));
+				if lines {
+					self.grid_line(buffer, p1, p2);
+				}
+				if let Some((length, colour)) = ticks {
+					buffer.line(p2, (p2.0 + length, p2.1), colour);
+				}
 				if self.label {
-					buffer.text((p2.0 + 4, p2.1 - 8), &format!("{:.1}", price), self.colour);
+					let y = buffer.data_to_coords(*price, buffer.timeframe).1;
+					buffer.text_anchored((label_right, y), label, self.colour, TextStyle::Regular, HAlign::Right, VAlign::Middle);
+
+					if buffer.is_recording() {
+						let (width, height) = buffer.typeface().measure(label, 1);
+						let top = y.saturating_sub(height / 2);
+						let bounds = Rect { left: label_right.saturating_sub(width), top, right: label_right.saturating_sub(1), bottom: top + height.saturating_sub(1) };
+						buffer.record(DrawnElement::PriceLabel { price: *price, text: label.clone(), bounds });
+					}
 				}
+			}
+
+			// Emphasise zero when the prices cross it, such as for spreads or returns
+			if buffer.min_price < 0. && buffer.max_price > 0. {
+				let p1 = buffer.data_to_coords(0., 0);
+				let p2 = buffer.data_to_coords(0., buffer.timeframe);
+				let opaque = self.colour | 0xFF;
 
-				price += self.price_interval;
+				buffer.hline(0., opaque, LineStyle::Solid);
+				buffer.line((p1.0, p1.1 + 1), (p2.0, p2.1 + 1), opaque);
 			}
 		}
 
 		{
-			let mut time = buffer.timeframe;
-			for _ in 0..(time / self.time_interval) + 1 {
-				let p1 = {
-					let point = buffer.data_to_coords(buffer.min_price, time);
-					(point.0, point.1 + 15)
-				};
-				let p2 = buffer.data_to_coords(buffer.max_price, time);
+			let mut lines = vec![];
+			for time in time_line_times(buffer.timeframe, self.time_interval, self.start_time) {
+				// The bottom of the line, where it pokes out of the plot area as a tick above the label
+				let bottom = buffer.data_to_coords(buffer.min_price, time);
 
-				buffer.line(p1, p2, self.colour);
+				lines.push((time, bottom, duration_string((buffer.timeframe - time) as u64)));
+			}
 
-				if self.label {
-					let elapsed = format!("{}", duration_string((buffer.timeframe - time) as u64));
-					buffer.text((p1.0 - 10, p1.1 + 2), &elapsed, self.colour);
-				}
+			let label_step = time_label_step(buffer, &lines);
+
+			for (i, &(time, bottom, ref elapsed)) in lines.iter().enumerate() {
+				let top = buffer.data_to_coords(buffer.max_price, time);
+				self.grid_line(buffer, bottom, top);
+				buffer.line((bottom.0, bottom.1 + 15 * buffer.scale), bottom, self.colour);
 
-				time -= self.time_interval;
+				if self.label && i % label_step == 0 {
+					let topleft = (bottom.0.saturating_sub(10 * buffer.scale), bottom.1 + 17 * buffer.scale);
+					buffer.text(topleft, elapsed, self.colour);
+
+					if buffer.is_recording() {
+						let (width, height) = buffer.typeface().measure(elapsed, 1);
+						let bounds = Rect { left: topleft.0, top: topleft.1, right: topleft.0 + width.saturating_sub(1), bottom: topleft.1 + height.saturating_sub(1) };
+						buffer.record(DrawnElement::TimeLabel { time, text: elapsed.clone(), bounds });
+					}
+				}
 			}
 		}
 	}
@@ -64,8 +247,49 @@ impl<C: Candle> RendererExtension for GridLines<C> {
 	fn name(&self) -> String {
 		"CORE_GridLines()".to_string()
 	}
+
+	fn config_key(&self) -> String {
+		// The price ticks are told apart by their allocation, like on the options
+		format!("{:?} {:?}", self, self.price_ticks.as_ref().map(|price_ticks| Arc::as_ptr(price_ticks) as *const () as usize))
+	}
+}
+
+impl<C> GridLines<C> {
+	fn grid_line(&self, buffer: &mut ChartBuffer, p1: Point, p2: Point) {
+		let scale = buffer.scale;
+
+		match self.style {
+			GridStyle::Solid => buffer.line(p1, p2, self.colour),
+			GridStyle::Dashed { dash, gap } => buffer.dashed_line(p1, p2, dash as usize * scale, gap as usize * scale, self.colour),
+			GridStyle::Dotted => buffer.dashed_line(p1, p2, scale, scale, self.colour),
+		}
+	}
 }
 
+/// The first multiple of the interval above the minimum price, `%` would round towards zero for negative prices
 fn round_start_price(buffer: &ChartBuffer, interval: f64) -> f64 {
-	buffer.min_price + interval - (buffer.min_price % interval)
+	(buffer.min_price / interval).floor() * interval + interval
+}
+
+/// Times of the lines from the end of the chart back to its start, every interval back from the end or on the multiples of the interval since the epoch when the start time is known
+fn time_line_times(timeframe: i64, interval: i64, start_time: Option<i64>) -> Vec<i64> {
+	let last = match start_time {
+		Some(start_time) => timeframe - (start_time + timeframe).rem_euclid(interval),
+		None => timeframe,
+	};
+
+	(0..last / interval + 1).map(|i| last - i * interval).collect()
+}
+
+/// Labels are drawn on every n-th time line so that neighbouring labels don't overlap
+fn time_label_step(buffer: &ChartBuffer, lines: &[(i64, Point, String)]) -> usize {
+	if lines.len() < 2 {
+		return 1;
+	}
+
+	let spacing = (lines[0].1).0.saturating_sub((lines[1].1).0).max(1);
+	// Leave a character's worth of space between labels
+	let widest = lines.iter().map(|(_, _, label)| buffer.typeface().measure(label, 1).0).max().unwrap_or(0) + buffer.typeface().measure(" ", 1).0;
+
+	widest.div_ceil(spacing).max(1)
 }
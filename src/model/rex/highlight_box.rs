@@ -0,0 +1,58 @@
+use std::marker::PhantomData;
+
+use model::*;
+
+/// A filled box with a border over a region of interest, such as a consolidation zone
+#[derive(Clone, Debug)]
+pub struct HighlightBox<C> {
+	_c: PhantomData<C>,
+	start_time: i64,
+	end_time: i64,
+	low_price: f64,
+	high_price: f64,
+	border_colour: u32,
+	fill_colour: u32,
+}
+
+impl<C> HighlightBox<C> {
+	/// Times are in seconds from the start of the chart, use a semi-transparent fill colour to keep the candles under it visible
+	pub fn new(start_time: i64, end_time: i64, low_price: f64, high_price: f64, border_colour: u32, fill_colour: u32) -> HighlightBox<C> {
+		HighlightBox { _c: PhantomData, start_time, end_time, low_price, high_price, border_colour, fill_colour }
+	}
+}
+
+impl<C: Candle> RendererExtension for HighlightBox<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, _data: &[C]) {
+		let (start, end) = (self.start_time.min(self.end_time), self.start_time.max(self.end_time));
+		let (low, high) = (self.low_price.min(self.high_price), self.low_price.max(self.high_price));
+
+		// A box wholly off the chart would be pinned to its edges as a sliver
+		if end < 0 || start > buffer.timeframe || high < buffer.min_price || low > buffer.max_price {
+			return;
+		}
+
+		// data_to_coords clamps the corners to the plot area
+		let (left, top) = buffer.data_to_coords(high, start);
+		let (right, bottom) = buffer.data_to_coords(low, end);
+
+		// Inside the border so translucent colours aren't blended together
+		if right > left + 1 && bottom > top + 1 {
+			buffer.rect(left + 1, top + 1, right - 1, bottom - 1, self.fill_colour);
+		}
+		buffer.rect_outline(left, top, right, bottom, 1, self.border_colour);
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("HighlightBox({}..{}, {}..{})", self.start_time, self.end_time, self.low_price, self.high_price)
+	}
+
+	fn config_key(&self) -> String {
+		format!("HighlightBox{:?}", (self.start_time, self.end_time, self.low_price, self.high_price, self.border_colour, self.fill_colour))
+	}
+}
@@ -2,8 +2,27 @@ use std::marker::PhantomData;
 
 use model::*;
 use model::buffer::ChartBuffer;
-use model::rex::dema::*;
-use model::rex::ema::*;
+pub use model::indicators::MACDSeries;
+use model::indicators;
+use model::rex::ema::median_list;
+
+/// How the bars of the histogram are coloured
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MACDHistogramStyle {
+	/// Every bar in the histogram colour
+	Monotone,
+	/// By which side of zero the bar is on and whether it's further from zero than the bar before, as on TradingView
+	Gradient {
+		/// Above zero and growing
+		positive_growing: u32,
+		/// Above zero and shrinking towards it
+		positive_shrinking: u32,
+		/// Below zero and growing more negative
+		negative_growing: u32,
+		/// Below zero and shrinking towards it
+		negative_shrinking: u32,
+	},
+}
 
 #[derive(Clone, Debug)]
 pub struct MACD<C> {
@@ -13,6 +32,7 @@ pub struct MACD<C> {
 	histogram_colour: u32,
 	label_colour: u32,
 	smoothing_factor: f64,
+	histogram_style: MACDHistogramStyle,
 }
 
 impl<C> MACD<C> {
@@ -24,30 +44,49 @@ impl<C> MACD<C> {
 			histogram_colour,
 			label_colour,
 			smoothing_factor,
+			histogram_style: MACDHistogramStyle::Monotone,
 		}
 	}
-}
-
-impl<C: Candle> RendererExtension for MACD<C> {
-	type Candle = C;
 
-	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
-		let (divergence, signal, histogram) = {
-			let median_list = median_list(data);
+	/// Colours the histogram's bars by the style instead of all in the histogram colour
+	pub fn histogram_style(mut self, style: MACDHistogramStyle) -> MACD<C> {
+		self.histogram_style = style;
 
-			let short = ema::<C>(&EMA::new(12, self.smoothing_factor, 0), &median_list);
-			let long = ema::<C>(&EMA::new(26, self.smoothing_factor, 0), &median_list);
+		self
+	}
 
-			let mut divergence = short.clone();
-			subtract(&mut divergence, &long);
+	/// Colour of each bar of the histogram, the first bar and the first on each side of zero count as growing
+	pub fn histogram_colours(&self, histogram: &[f64]) -> Vec<u32> {
+		match self.histogram_style {
+			MACDHistogramStyle::Monotone => vec![self.histogram_colour; histogram.len()],
+			MACDHistogramStyle::Gradient { positive_growing, positive_shrinking, negative_growing, negative_shrinking } => {
+				histogram.iter().enumerate().map(|(i, &bar)| {
+					let previous = if i > 0 { histogram[i - 1] } else { bar };
+
+					match (bar >= 0., bar.abs() >= previous.abs() || bar * previous < 0.) {
+						(true, true) => positive_growing,
+						(true, false) => positive_shrinking,
+						(false, true) => negative_growing,
+						(false, false) => negative_shrinking,
+					}
+				}).collect()
+			}
+		}
+	}
+}
 
-			let signal = ema::<C>(&EMA::new(9, self.smoothing_factor, 0), &divergence);
+impl<C: Candle> MACD<C> {
+	/// Computes the lines, only the divergence after the first 26 values and the rest after the first 35 are drawn
+	pub fn compute(&self, data: &[C]) -> MACDSeries {
+		indicators::macd(&median_list(data), self.smoothing_factor)
+	}
+}
 
-			let mut histogram = divergence.clone();
-			subtract(&mut histogram, &signal);
+impl<C: Candle> RendererExtension for MACD<C> {
+	type Candle = C;
 
-			(divergence, signal, histogram)
-		};
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		let MACDSeries { divergence, signal, histogram } = self.compute(data);
 
 		let (mut lowest, mut highest) = (divergence[0], divergence[0]);
 
@@ -67,11 +106,12 @@ impl<C: Candle> RendererExtension for MACD<C> {
 		}
 
 		let range = highest - lowest;
+		let colours = self.histogram_colours(&histogram);
 
 		buffer.create_extension_strip(135, move |buffer| {
-			buffer.text((8, 8), &self.name(), self.label_colour);
-			buffer.text_with_background((8, 8 + 17), "MACD Divergence", self.divergence_colour, 0x7F7F7F7F);
-			buffer.text_with_background((8, 8 + 17 * 2), "MACD Signal", self.signal_colour, 0x7F7F7F7F);
+			buffer.text((8 * buffer.scale, 8 * buffer.scale), &self.name(), self.label_colour);
+			buffer.text_with_background((8 * buffer.scale, (8 + 17) * buffer.scale), "MACD Divergence", self.divergence_colour, 0x7F7F7F7F, TextStyle::Regular);
+			buffer.text_with_background((8 * buffer.scale, (8 + 17 * 2) * buffer.scale), "MACD Signal", self.signal_colour, 0x7F7F7F7F, TextStyle::Regular);
 
 			let period = buffer.timeframe / data.len() as i64;
 			let period_addition = 4. * period as f64 / 5.;
@@ -83,7 +123,7 @@ impl<C: Candle> RendererExtension for MACD<C> {
 					let p1 = buffer.data_to_coords((histogram[i] - lowest) / range, time - (period_addition / 12.).ceil() as i64);
 					let p2 = buffer.data_to_coords(-lowest / range, time + (period_addition / 12.).floor() as i64);
 
-					buffer.rect_point(p1, p2, self.histogram_colour);
+					buffer.rect_point(p1, p2, colours[i]);
 				}
 			}
 
@@ -94,7 +134,7 @@ impl<C: Candle> RendererExtension for MACD<C> {
 				let p2 = buffer.data_to_coords(prog, buffer.timeframe);
 
 				buffer.line(p1, p2, self.label_colour);
-				buffer.text((p2.0 + 4, p2.1 - 8), "Zero", self.label_colour);
+				buffer.text((p2.0 + 4 * buffer.scale, p2.1 - 8 * buffer.scale), "Zero", self.label_colour);
 			}
 
 			// Signal & divergence
@@ -115,6 +155,11 @@ impl<C: Candle> RendererExtension for MACD<C> {
 		});
 	}
 
+	fn warmup(&self) -> usize {
+		// The histogram starts once the signal has its 9 periods on top of the 26 of the long average
+		36
+	}
+
 	fn lore_colour(&self) -> Option<u32> {
 		None
 	}
@@ -122,4 +167,8 @@ impl<C: Candle> RendererExtension for MACD<C> {
 	fn name(&self) -> String {
 		format!("MACD(12, 26, 9, sf={})", self.smoothing_factor)
 	}
+
+	fn config_key(&self) -> String {
+		format!("MACD{:?}", (self.divergence_colour, self.signal_colour, self.histogram_colour, self.label_colour, self.smoothing_factor, self.histogram_style))
+	}
 }
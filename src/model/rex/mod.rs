@@ -1,37 +1,92 @@
 pub use buffer::*;
+use model::painting::DrawCommand;
 pub use data::Candle;
-pub use model::rex::volume::Volume;
+pub use model::rex::volume::{ColourMode, Volume, VolumeScale};
 pub use OHLCRenderOptions;
 
+pub use self::annotation::Annotation;
+pub use self::arrow::{ArrowAnnotation, ArrowDirection};
 pub use self::basic_indicative_lines::BasicIndicativeLines;
 pub use self::bollinger_bands::BollingerBands;
+pub use self::border::ImageBorder;
+pub use self::correlation_overlay::CorrelationOverlay;
 pub use self::dema::DEMA;
 pub use self::ema::EMA;
-pub use self::grid_lines::GridLines;
-pub use self::macd::MACD;
+pub use self::gradient_candles::GradientCandles;
+pub use self::grid_lines::{GridLines, GridStyle, PriceLineMarks, PriceTicksFn};
+pub use self::highlight_box::HighlightBox;
+pub use self::macd::{MACD, MACDHistogramStyle};
+pub use self::momentum::Momentum;
 pub use self::no_extension::NoExtension;
-pub use self::ohlc_candles::OHLCCandles;
+pub use self::ohlc_candles::{CandleColourFn, CandleStyle, HollowMode, OHLCCandles, WickCap, WickStyle};
+pub use self::price_alert::PriceAlert;
 pub use self::rsi::RSI;
+pub use self::rsi_divergence::RSIDivergenceAnnotations;
+pub use self::sd_bands::StandardDeviationBands;
+pub use self::stats_summary::{CornerPosition, StatsSummary};
+pub use self::trend_line::TrendLine;
+
+/// Works out everything an extension draws from where things are on the chart, taking what it needs from the extension and the candles beforehand so it can be run on another thread
+pub type DrawJob = Box<dyn FnOnce(&ChartBuffer) -> Vec<DrawCommand> + Send>;
 
 pub trait RendererExtension {
 	type Candle: Candle;
 
+	/// Draws the extension over the candles, which start with any history it's warmed up on. `ChartBuffer::charted` leaves that off for extensions describing only what's on the chart.
 	fn apply(&self, _buffer: &mut ChartBuffer, _data: &[Self::Candle]);
 
+	/// What the extension draws as a job, if it can be worked out without touching the buffer. Extensions that can are drawn from the job's commands instead of `apply`, and with the `rayon` feature the jobs of all of them are run in parallel.
+	///
+	/// Nothing that extensions applied before this one do to the buffer is seen, only where things are on the chart.
+	fn draw_job(&self, _data: &[Self::Candle]) -> Option<DrawJob> {
+		None
+	}
+
+	/// How many candles the extension needs before it has anything to draw, eg. the periods of a moving average and then two more for a line
+	///
+	/// Extensions are skipped with a warning when rendering fewer candles than this, so `apply` and `draw_job` can rely on having at least this many.
+	fn warmup(&self) -> usize {
+		0
+	}
+
 	fn lore_colour(&self) -> Option<u32>;
 
 	fn name(&self) -> String;
+
+	/// Everything about the extension that changes what it draws, which renders are told apart by in the `RenderCache`
+	///
+	/// Only the name by default, so extensions that can be set up differently under the same name should override this with all of their settings, or charts with either will be served the same cached PNG.
+	fn config_key(&self) -> String {
+		self.name()
+	}
+
+	/// Extensions are applied in ascending order of z-index, extensions sharing a z-index are applied in the order they were added
+	fn z_index(&self) -> i32 {
+		0
+	}
 }
 
+pub mod annotation;
+pub mod arrow;
 pub mod basic_indicative_lines;
 pub mod bollinger_bands;
+pub mod border;
+pub mod correlation_overlay;
 pub mod dema;
 pub mod ema;
+pub mod gradient_candles;
 pub mod grid_lines;
+pub mod highlight_box;
 pub mod macd;
+pub mod momentum;
 pub mod no_extension;
 pub mod ohlc_candles;
+pub mod price_alert;
 pub mod rsi;
+pub mod rsi_divergence;
+pub mod sd_bands;
+pub mod stats_summary;
+pub mod trend_line;
 pub mod volume;
 #[cfg(test)]
 pub mod test_fill;
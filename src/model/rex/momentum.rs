@@ -0,0 +1,80 @@
+use std::marker::PhantomData;
+
+use model::*;
+use utils::calculate_momentum;
+
+/// Colour of the zero line and the labels
+const LABEL_COLOUR: u32 = 0xCCCCCCFF;
+
+#[derive(Clone, Debug)]
+pub struct Momentum<C> {
+	_c: PhantomData<C>,
+	periods: usize,
+	positive_colour: u32,
+	negative_colour: u32,
+}
+
+impl<C> Momentum<C> {
+	pub fn new(periods: usize, positive_colour: u32, negative_colour: u32) -> Momentum<C> {
+		Momentum { _c: PhantomData, periods, positive_colour, negative_colour }
+	}
+}
+
+impl<C: Candle> Momentum<C> {
+	/// The momentum of each candle, `None` for the first periods as there's nothing to compare them to
+	pub fn compute(&self, data: &[C]) -> Vec<Option<f64>> {
+		calculate_momentum(data, self.periods)
+	}
+}
+
+impl<C: Candle> RendererExtension for Momentum<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		let momentum = self.compute(data);
+		// Zero sits in the middle of the strip, with the biggest swing either way reaching the edge
+		let max_swing = momentum.iter().filter_map(|m| *m).fold(0., |max: f64, m| max.max(m.abs()));
+		let up_progress = |m: f64| if max_swing > 0. { 0.5 + m / max_swing / 2. } else { 0.5 };
+
+		let period = buffer.timeframe / data.len().max(1) as i64;
+		let period_addition = 4. * period as f64 / 5.;
+
+		buffer.create_extension_strip(175, move |buffer| {
+			buffer.text((8 * buffer.scale, 8 * buffer.scale), &self.name(), LABEL_COLOUR);
+
+			{
+				let p1 = buffer.data_to_coords(0.5, 0);
+				let p2 = buffer.data_to_coords(0.5, buffer.timeframe);
+
+				buffer.line(p1, p2, LABEL_COLOUR);
+				buffer.text((p2.0 + 4 * buffer.scale, p2.1 - 8 * buffer.scale), "0", LABEL_COLOUR);
+			}
+
+			for (i, m) in momentum.iter().enumerate() {
+				if let Some(m) = *m {
+					let colour = if m >= 0. { self.positive_colour } else { self.negative_colour };
+					let p1 = buffer.data_to_coords(0.5, period * i as i64);
+					let p2 = buffer.data_to_coords(up_progress(m), ((period * i as i64) as f64 + period_addition) as i64);
+
+					buffer.rect_point(p1, p2, colour);
+				}
+			}
+		});
+	}
+
+	fn warmup(&self) -> usize {
+		self.periods + 1
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("Momentum({})", self.periods)
+	}
+
+	fn config_key(&self) -> String {
+		format!("Momentum{:?}", (self.periods, self.positive_colour, self.negative_colour))
+	}
+}
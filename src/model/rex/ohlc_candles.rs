@@ -1,17 +1,180 @@
+use std::fmt;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
+use manifest::DrawnElement;
 use model::*;
 
-#[derive(Clone, Debug)]
+/// Widest a lone candle's body is drawn in pixels, which would otherwise be most of the chart wide
+const MAX_BODY_WIDTH: usize = 40;
+
+/// Picks the `(body_colour, wick_colour)` of a candle from the candle and its index
+pub type CandleColourFn<C> = Arc<dyn Fn(&C, usize) -> (u32, u32) + Send + Sync>;
+
+/// What's drawn at the ends of the wicks
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WickCap {
+	None,
+	/// A short horizontal mark at the high and the low, like on an OHLC bar chart
+	Tick,
+}
+
+/// Which parts of the wicks of standard candles are drawn
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WickStyle {
+	/// Wicks above and below the body
+	Full,
+	/// No wicks, any wick caps still mark the high and the low
+	None,
+	/// Only the wick above the body, up to the high
+	UpperOnly,
+	/// Only the wick below the body, down to the low
+	LowerOnly,
+	/// Nothing but the body, without wicks or wick caps
+	BodyOnly,
+}
+
+impl WickStyle {
+	fn upper(&self) -> bool {
+		*self == WickStyle::Full || *self == WickStyle::UpperOnly
+	}
+
+	fn lower(&self) -> bool {
+		*self == WickStyle::Full || *self == WickStyle::LowerOnly
+	}
+}
+
+/// How each candle is drawn
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CandleStyle {
+	/// A body from the open to the close, with a wick from the low to the high
+	Candlestick,
+	/// A line from the low to the high, with a tick to the left at the open and a tick to the right at the close
+	OhlcBar,
+	/// A line through the close prices
+	Line,
+	/// A line through the close prices, filled down to the bottom of the chart
+	Area,
+	/// A line through the close prices, filled to the baseline in one colour above it and another below it
+	Baseline,
+}
+
+/// Which candlestick bodies are drawn as outlines instead of being filled
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HollowMode {
+	/// Every body is filled
+	Filled,
+	/// Candles that close above their open are hollow
+	OpenClose,
+	/// Candles that close at or above the previous candle's close are hollow, whatever their own open, and the first candle is filled
+	PrevClose,
+}
+
+impl HollowMode {
+	fn is_hollow<C: Candle>(&self, data: &[C], i: usize) -> bool {
+		match *self {
+			HollowMode::Filled => false,
+			HollowMode::OpenClose => data[i].close() > data[i].open(),
+			HollowMode::PrevClose => i > 0 && data[i].close() >= data[i - 1].close(),
+		}
+	}
+}
+
+#[derive(Clone)]
 pub struct OHLCCandles<C> {
 	_c: PhantomData<C>,
 	up_colour: u32,
 	down_colour: u32,
+	up_wick_colour: u32,
+	down_wick_colour: u32,
+	colour_fn: Option<CandleColourFn<C>>,
+	wick_style: WickStyle,
+	wick_cap: WickCap,
+	cap_width: usize,
+	style: CandleStyle,
+	tick_length: usize,
+	fill_colour: u32,
+	baseline: (f64, u32, u32),
+	antialias: bool,
+	hollow: HollowMode,
 }
 
 impl<C> OHLCCandles<C> {
-	pub fn new(up_colour: u32, down_colour: u32) -> OHLCCandles<C> {
-		OHLCCandles { _c: PhantomData, up_colour, down_colour }
+	/// Wicks are the colour of their bodies unless given their own colours. The wick style only applies to `CandleStyle::Candlestick`, OHLC bars always have their full bar.
+	pub fn new(up_colour: u32, down_colour: u32, up_wick_colour: Option<u32>, down_wick_colour: Option<u32>, wick_style: WickStyle) -> OHLCCandles<C> {
+		OHLCCandles { _c: PhantomData, up_colour, down_colour, up_wick_colour: up_wick_colour.unwrap_or(up_colour), down_wick_colour: down_wick_colour.unwrap_or(down_colour), colour_fn: None, wick_style, wick_cap: WickCap::None, cap_width: 0, style: CandleStyle::Candlestick, tick_length: 0, fill_colour: (up_colour & 0xFFFFFF00) | 0x40, baseline: (0., up_colour, down_colour), antialias: false, hollow: HollowMode::Filled }
+	}
+
+	/// Colour candles with the function instead of by whether they rose or fell
+	pub fn colour_fn(mut self, colour_fn: CandleColourFn<C>) -> OHLCCandles<C> {
+		self.colour_fn = Some(colour_fn);
+
+		self
+	}
+
+	/// Draws candles in the style, the tick length in pixels is used by `CandleStyle::OhlcBar`
+	pub fn style(mut self, style: CandleStyle, tick_length: usize) -> OHLCCandles<C> {
+		self.style = style;
+		self.tick_length = tick_length;
+
+		self
+	}
+
+	/// Colour of the area under the line for `CandleStyle::Area`, semi-transparent colours let the grid show through
+	pub fn fill_colour(mut self, fill_colour: u32) -> OHLCCandles<C> {
+		self.fill_colour = fill_colour;
+
+		self
+	}
+
+	/// Price and the colours above and below it for `CandleStyle::Baseline`, the fills are translucent versions of the colours
+	pub fn baseline(mut self, baseline: f64, above_colour: u32, below_colour: u32) -> OHLCCandles<C> {
+		self.baseline = (baseline, above_colour, below_colour);
+
+		self
+	}
+
+	/// Caps the ends of the wicks, the width of the cap is in pixels
+	pub fn wick_cap(mut self, wick_cap: WickCap, cap_width: usize) -> OHLCCandles<C> {
+		self.wick_cap = wick_cap;
+		self.cap_width = cap_width;
+
+		self
+	}
+
+	/// Blends the columns the left and right edges of bodies fall partway into by how much of them is covered, so every body looks as wide as the others wherever it lands between pixels
+	pub fn antialias(mut self, antialias: bool) -> OHLCCandles<C> {
+		self.antialias = antialias;
+
+		self
+	}
+
+	/// Outlines the bodies of some candlesticks instead of filling them, hollow bodies are outlined at whole pixels even when antialiasing
+	pub fn hollow(mut self, hollow: HollowMode) -> OHLCCandles<C> {
+		self.hollow = hollow;
+
+		self
+	}
+}
+
+impl<C> fmt::Debug for OHLCCandles<C> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("OHLCCandles")
+			.field("up_colour", &self.up_colour)
+			.field("down_colour", &self.down_colour)
+			.field("up_wick_colour", &self.up_wick_colour)
+			.field("down_wick_colour", &self.down_wick_colour)
+			.field("colour_fn", &self.colour_fn.is_some())
+			.field("wick_style", &self.wick_style)
+			.field("wick_cap", &self.wick_cap)
+			.field("cap_width", &self.cap_width)
+			.field("style", &self.style)
+			.field("tick_length", &self.tick_length)
+			.field("fill_colour", &self.fill_colour)
+			.field("baseline", &self.baseline)
+			.field("antialias", &self.antialias)
+			.field("hollow", &self.hollow)
+			.finish()
 	}
 }
 
@@ -22,29 +185,147 @@ impl<C: Candle> RendererExtension for OHLCCandles<C> {
 		let period = buffer.timeframe / data.len() as i64;
 		let period_addition = 4. * period as f64 / 5.;
 
+		if self.style == CandleStyle::Line || self.style == CandleStyle::Area || self.style == CandleStyle::Baseline {
+			// Closes sit in the middle of where the candles would be
+			let closes: Vec<Point> = data.iter().enumerate()
+				.map(|(i, candle)| buffer.data_to_coords(candle.close(), period * i as i64 + (period_addition / 2.) as i64))
+				.collect();
+
+			if buffer.is_recording() {
+				for (i, (candle, &(x, y))) in data.iter().zip(closes.iter()).enumerate() {
+					let bounds = Rect { left: x, top: y, right: x, bottom: y };
+					buffer.record(DrawnElement::Candle { index: i, open: candle.open(), high: candle.high(), low: candle.low(), close: candle.close(), bounds });
+				}
+			}
+
+			if self.style == CandleStyle::Area && !closes.is_empty() {
+				let bottom = buffer.height() - buffer.margin.bottom;
+				let floor = [(closes[0].0, bottom), (closes[closes.len() - 1].0, bottom)];
+
+				buffer.fill_between(&closes, &floor, self.fill_colour);
+			}
+
+			if self.style == CandleStyle::Baseline {
+				draw_baseline(buffer, &closes, self.baseline);
+			} else {
+				buffer.polyline(&closes, self.up_colour);
+			}
+			return;
+		}
+
+		let columns = CandleColumns::new(buffer, data.len());
+		let period_addition = columns.body_width;
+		let (tick_length, cap_width) = (self.tick_length * buffer.scale, self.cap_width * buffer.scale);
+
 		for i in 0..data.len() {
 			let candle = &data[i];
+			let span = columns.span(buffer, i);
+			let start = span.start;
+			let column = |time: f64| span.column(time);
 
 			let open = candle.open();
 			let close = candle.close();
 
-			let colour = if open > close { self.down_colour } else { self.up_colour };
+			let (body_colour, wick_colour) = match self.colour_fn {
+				Some(ref colour_fn) => colour_fn(candle, i),
+				None => {
+					if open > close { (self.down_colour, self.down_wick_colour) } else { (self.up_colour, self.up_wick_colour) }
+				}
+			};
 
-			// Main big block
-			{
-				let p1 = buffer.data_to_coords(open, period * i as i64);
-				let p2 = buffer.data_to_coords(close, ((period * (i as i64)) as f64 + period_addition) as i64);
+			// Main big block, what it covers is left out of the wick and caps so translucent colours aren't blended twice
+			let body = if self.style == CandleStyle::Candlestick {
+				let p1 = (column(start), buffer.data_to_coords(open, 0).1);
+				let p2 = (column(start + period_addition), buffer.data_to_coords(close, 0).1);
 
-				buffer.rect_point(p1, p2, colour);
-			}
+				let (top, bottom) = (p1.1.min(p2.1), p1.1.max(p2.1));
+
+				if self.hollow.is_hollow(data, i) {
+					let (left, right) = (p1.0.min(p2.0), p1.0.max(p2.0));
+					outline(buffer, left, top, right, bottom, body_colour);
+
+					Some((left, top, right, bottom))
+				} else if self.antialias {
+					// As wide as the fast body on average, one past the column its right edge rounds down to
+					let left = buffer.time_to_x(start);
+					let right = buffer.time_to_x(start + period_addition) + 1.;
+					let (left, right) = rect_fractional(buffer, left, right, top, bottom, body_colour);
+
+					Some((left, top, right, bottom))
+				} else {
+					buffer.rect_point(p1, p2, body_colour);
+
+					Some((p1.0.min(p2.0), top, p1.0.max(p2.0), bottom))
+				}
+			} else {
+				None
+			};
 
 			// Sticks
 			{
-				let time = period * i as i64 + (period_addition / 2.) as i64;
-				let p1 = buffer.data_to_coords(candle.high(), time - (period_addition / 12.).ceil() as i64);
-				let p2 = buffer.data_to_coords(candle.low(), time + (period_addition / 12.).floor() as i64);
+				let time = start + period_addition / 2.;
+				let p1 = (column(time - period_addition / 12.), buffer.data_to_coords(candle.high(), 0).1);
+				let p2 = (column(time + period_addition / 12.), buffer.data_to_coords(candle.low(), 0).1);
+
+				if self.style == CandleStyle::OhlcBar {
+					// The bar and its ticks share a thickness, in the body's colour
+					let thickness = p2.0 - p1.0;
+					let open_y = buffer.data_to_coords(open, 0).1;
+					let close_y = buffer.data_to_coords(close, 0).1;
 
-				buffer.rect_point(p1, p2, colour);
+					buffer.rect_point(p1, p2, body_colour);
+					if tick_length > 0 {
+						buffer.rect(p1.0.saturating_sub(tick_length), open_y, p1.0.saturating_sub(1), open_y + thickness, body_colour);
+						buffer.rect(p2.0 + 1, close_y, p2.0 + tick_length, close_y + thickness, body_colour);
+					}
+				} else if let Some((_, body_top, _, body_bottom)) = body {
+					if self.wick_style.upper() && p1.1 < body_top {
+						buffer.rect(p1.0, p1.1, p2.0, body_top - 1, wick_colour);
+					}
+					if self.wick_style.lower() && p2.1 > body_bottom {
+						buffer.rect(p1.0, body_bottom + 1, p2.0, p2.1, wick_colour);
+					}
+				} else {
+					buffer.rect_point(p1, p2, wick_colour);
+				}
+
+				if self.wick_cap == WickCap::Tick && self.wick_style != WickStyle::BodyOnly {
+					let centre = column(time);
+					let left = centre.saturating_sub(cap_width / 2);
+					let right = left + cap_width.max(1) - 1;
+					let bar = body.is_none();
+
+					for &(y, wick) in [(p1.1, bar || self.wick_style.upper()), (p2.1, bar || self.wick_style.lower())].iter() {
+						// Leave out whatever is already drawn on the row, the body or the wick
+						let skip = match body {
+							Some((body_left, body_top, body_right, body_bottom)) if y >= body_top && y <= body_bottom => Some((body_left, body_right)),
+							_ if wick => Some((p1.0, p2.0)),
+							_ => None,
+						};
+
+						match skip {
+							Some((skip_left, skip_right)) => {
+								if left < skip_left {
+									buffer.rect(left, y, (skip_left - 1).min(right), y, wick_colour);
+								}
+								if right > skip_right {
+									buffer.rect((skip_right + 1).max(left), y, right, y, wick_colour);
+								}
+							}
+							None => buffer.rect(left, y, right, y, wick_colour),
+						}
+					}
+				}
+
+				if buffer.is_recording() {
+					let (left, right) = if self.style == CandleStyle::OhlcBar { (p1.0.saturating_sub(tick_length), p2.0 + tick_length) } else { (p1.0, p2.0) };
+					let bounds = match body {
+						Some((body_left, body_top, body_right, body_bottom)) => Rect { left: left.min(body_left), top: p1.1.min(body_top), right: right.max(body_right), bottom: p2.1.max(body_bottom) },
+						None => Rect { left, top: p1.1, right, bottom: p2.1 },
+					};
+
+					buffer.record(DrawnElement::Candle { index: i, open, high: candle.high(), low: candle.low(), close, bounds });
+				}
 			}
 		}
 	}
@@ -56,4 +337,124 @@ impl<C: Candle> RendererExtension for OHLCCandles<C> {
 	fn name(&self) -> String {
 		"OHLC_Candles()".to_string()
 	}
+
+	fn config_key(&self) -> String {
+		// The colour function is told apart by its allocation, like on the options
+		format!("{:?} {:?}", self, self.colour_fn.as_ref().map(|colour_fn| Arc::as_ptr(colour_fn) as *const () as usize))
+	}
+}
+
+/// Where the candles' bodies are drawn, shared by the extensions drawing candles so they all line up
+pub(crate) struct CandleColumns {
+	period: i64,
+	inset: f64,
+	/// Width of a body in seconds
+	pub(crate) body_width: f64,
+	pixels_per_second: f64,
+}
+
+impl CandleColumns {
+	pub(crate) fn new(buffer: &ChartBuffer, candles: usize) -> CandleColumns {
+		let period = buffer.timeframe / candles as i64;
+		let pixels_per_second = buffer.chart_width() as f64 / buffer.timeframe as f64;
+
+		if candles == 1 {
+			// Drawn no wider than a few candles would be, in the middle of the chart
+			let body_width = (4. * period as f64 / 5.).min((MAX_BODY_WIDTH * buffer.scale) as f64 / pixels_per_second);
+
+			CandleColumns { period, inset: (period as f64 - body_width) / 2., body_width, pixels_per_second }
+		} else {
+			CandleColumns { period, inset: 0., body_width: 4. * period as f64 / 5., pixels_per_second }
+		}
+	}
+
+	/// Where the body of the candle at the index starts
+	pub(crate) fn span(&self, buffer: &ChartBuffer, index: usize) -> CandleSpan {
+		let start = (self.period * index as i64) as f64 + self.inset;
+
+		CandleSpan { start, start_x: buffer.time_to_x(start).round(), pixels_per_second: self.pixels_per_second }
+	}
+}
+
+/// The columns of a single candle
+pub(crate) struct CandleSpan {
+	/// Time the body starts at
+	pub(crate) start: f64,
+	start_x: f64,
+	pixels_per_second: f64,
+}
+
+impl CandleSpan {
+	/// Columns are counted from the candle's start, rounded, so that every candle is the same width and the gaps between them differ by a pixel at most
+	pub(crate) fn column(&self, time: f64) -> usize {
+		(self.start_x + ((time - self.start) * self.pixels_per_second).round()) as usize
+	}
+}
+
+/// Draws the edges of the rectangle, each pixel once, filling it if it's too small to have an inside
+fn outline(buffer: &mut ChartBuffer, left: usize, top: usize, right: usize, bottom: usize, rgba: u32) {
+	if right - left < 2 || bottom - top < 2 {
+		buffer.rect(left, top, right, bottom, rgba);
+		return;
+	}
+
+	buffer.rect(left, top, right, top, rgba);
+	buffer.rect(left, bottom, right, bottom, rgba);
+	buffer.rect(left, top + 1, left, bottom - 1, rgba);
+	buffer.rect(right, top + 1, right, bottom - 1, rgba);
+}
+
+/// Fills the rows between the left and right edges, the columns the edges fall partway into are blended by how much of them is covered
+///
+/// Returns: the first and last columns drawn in
+fn rect_fractional(buffer: &mut ChartBuffer, left: f64, right: f64, top: usize, bottom: usize, rgba: u32) -> (usize, usize) {
+	let with_coverage = |coverage: f64| (rgba & 0xFFFFFF00) | ((rgba & 0xFF) as f64 * coverage).round() as u32;
+
+	let (first, last) = (left.floor(), (right.ceil() - 1.).max(left.floor()));
+
+	if first == last {
+		buffer.rect(first as usize, top, first as usize, bottom, with_coverage(right - left));
+	} else {
+		buffer.rect(first as usize, top, first as usize, bottom, with_coverage(first + 1. - left));
+		if last > first + 1. {
+			buffer.rect(first as usize + 1, top, last as usize - 1, bottom, rgba);
+		}
+		buffer.rect(last as usize, top, last as usize, bottom, with_coverage(right - last));
+	}
+
+	(first as usize, last as usize)
+}
+
+/// Splits the line where it crosses the baseline, so each piece is entirely above or below it and can be coloured by side
+fn draw_baseline(buffer: &mut ChartBuffer, closes: &[Point], (baseline, above_colour, below_colour): (f64, u32, u32)) {
+	let base_y = buffer.data_to_coords(baseline, 0).1;
+	let translucent = |rgba: u32| (rgba & 0xFFFFFF00) | 0x40;
+
+	let mut pieces = vec![];
+	for pair in closes.windows(2) {
+		let (a, b) = (pair[0], pair[1]);
+
+		// Smaller y is higher up, so crossing means the ends are on opposite sides of the baseline
+		if (a.1 < base_y && b.1 > base_y) || (a.1 > base_y && b.1 < base_y) {
+			let prog = (base_y as f64 - a.1 as f64) / (b.1 as f64 - a.1 as f64);
+			let crossing = ((a.0 as f64 + (b.0 as f64 - a.0 as f64) * prog).round() as usize, base_y);
+
+			pieces.push((a, crossing));
+			pieces.push((crossing, b));
+		} else {
+			pieces.push((a, b));
+		}
+	}
+
+	for (i, &(from, to)) in pieces.iter().enumerate() {
+		let colour = if from.1.min(to.1) < base_y { above_colour } else { below_colour };
+
+		// Pieces share their end column with the start of the next, which would be filled twice
+		let end = if i + 1 < pieces.len() { to.0.saturating_sub(1).max(from.0) } else { to.0 };
+		let edge = [from, to];
+		let base = [(from.0, base_y), (end, base_y)];
+
+		buffer.fill_between(&edge, &base, translucent(colour));
+		buffer.line(from, to, colour);
+	}
 }
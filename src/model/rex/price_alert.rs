@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+
+use model::*;
+
+/// A labelled horizontal line at a price, eg. for a stop-loss or take-profit level
+#[derive(Clone, Debug)]
+pub struct PriceAlert<C> {
+	_c: PhantomData<C>,
+	price: f64,
+	label: String,
+	colour: u32,
+	style: LineStyle,
+}
+
+impl<C> PriceAlert<C> {
+	pub fn new(price: f64, label: &str, colour: u32, style: LineStyle) -> PriceAlert<C> {
+		PriceAlert { _c: PhantomData, price, label: label.to_string(), colour, style }
+	}
+}
+
+impl<C: Candle> RendererExtension for PriceAlert<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, _data: &[C]) {
+		// The label would be pinned to the edge of the chart along with the line, where it'd be misleading
+		if self.price < buffer.min_price || self.price > buffer.max_price {
+			return;
+		}
+
+		let p = buffer.data_to_coords(self.price, buffer.timeframe);
+
+		buffer.hline(self.price, self.colour, self.style);
+
+		if !self.label.is_empty() {
+			buffer.text_anchored((p.0 - 2 * buffer.scale, p.1 - buffer.scale), &self.label, self.colour, TextStyle::Regular, HAlign::Right, VAlign::Bottom);
+		}
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("PriceAlert({}, {})", self.price, self.label)
+	}
+
+	fn config_key(&self) -> String {
+		format!("PriceAlert{:?}", (self.price, &self.label, self.colour, self.style))
+	}
+}
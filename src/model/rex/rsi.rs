@@ -1,6 +1,10 @@
 use std::marker::PhantomData;
 
 use model::*;
+use model::indicators;
+
+/// Number of candles each value is over
+pub(crate) const PERIODS: usize = 10;
 
 #[derive(Clone, Debug)]
 pub struct RSI<C> {
@@ -17,38 +21,21 @@ impl<C> RSI<C> {
 	}
 }
 
+impl<C: Candle> RSI<C> {
+	/// The RSI from 0 to 100, one for each candle after the first 10 and over the 10 candles before it
+	pub fn compute(&self, data: &[C]) -> Vec<f64> {
+		indicators::rsi(data, PERIODS)
+	}
+}
+
 impl<C: Candle> RendererExtension for RSI<C> {
 	type Candle = C;
 
 	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
-		let periods = 10;
-
-		let mut rsi = vec![];
-
-		for i in periods..data.len() {
-			let rs = {
-				let mut gains = vec![];
-				let mut losses = vec![];
-
-				for j in i - periods..i {
-					let delta = data[j].close() - data[j].open();
-					if delta >= 0. {
-						gains.push(delta);
-						losses.push(0.);
-					} else {
-						losses.push(delta.abs());
-						gains.push(0.);
-					}
-				}
-
-				avg(&gains[..]) / avg(&losses[..])
-			};
-
-			rsi.push(100. - 100. / (1. + rs));
-		}
+		let rsi = self.compute(data);
 
 		buffer.create_extension_strip(175, move |buffer| {
-			buffer.text((8, 8), "RSI(10)", self.label_colour);
+			buffer.text((8 * buffer.scale, 8 * buffer.scale), "RSI(10)", self.label_colour);
 
 			// Lines and labels
 			{
@@ -57,14 +44,14 @@ impl<C: Candle> RendererExtension for RSI<C> {
 					let p2 = buffer.data_to_coords(0.7, buffer.timeframe);
 
 					buffer.line(p1, p2, self.overbought_colour);
-					buffer.text((p2.0 + 4, p2.1 - 8), "Overbought", self.overbought_colour);
+					buffer.text((p2.0 + 4 * buffer.scale, p2.1 - 8 * buffer.scale), "Overbought", self.overbought_colour);
 				}
 				{
 					let p1 = buffer.data_to_coords(0.3, 0);
 					let p2 = buffer.data_to_coords(0.3, buffer.timeframe);
 
 					buffer.line(p1, p2, self.oversold_colour);
-					buffer.text((p2.0 + 4, p2.1 - 8), "Oversold", self.oversold_colour);
+					buffer.text((p2.0 + 4 * buffer.scale, p2.1 - 8 * buffer.scale), "Oversold", self.oversold_colour);
 				}
 
 				for prog in [0, 50, 100].iter() {
@@ -72,17 +59,18 @@ impl<C: Candle> RendererExtension for RSI<C> {
 					let p2 = buffer.data_to_coords(*prog as f64 / 100., buffer.timeframe);
 
 					buffer.line(p1, p2, self.label_colour);
-					buffer.text((p2.0 + 4, p2.1 - 8), &format!("{}", prog), self.label_colour);
+					buffer.text((p2.0 + 4 * buffer.scale, p2.1 - 8 * buffer.scale), &format!("{}", prog), self.label_colour);
 				}
 			}
 
 			// Actual RSI Curve
 			{
-				let offset = ((periods as f64 + 0.5) * (buffer.timeframe as f64) / (data.len() as f64)) as i64;
+				let period = buffer.timeframe / data.len() as i64;
+				let offset = ((PERIODS as f64 + 0.5) * period as f64) as i64;
 
 				for i in 0..rsi.len() - 1 {
-					let p1 = buffer.data_to_coords(rsi[i] / 100., buffer.timeframe * i as i64 / data.len() as i64 + offset);
-					let p2 = buffer.data_to_coords(rsi[i + 1] / 100., buffer.timeframe * (i + 1) as i64 / data.len() as i64 + offset);
+					let p1 = buffer.data_to_coords(rsi[i] / 100., i as i64 * period + offset);
+					let p2 = buffer.data_to_coords(rsi[i + 1] / 100., (i + 1) as i64 * period + offset);
 
 					buffer.line(p1, p2, self.colour);
 				}
@@ -90,6 +78,10 @@ impl<C: Candle> RendererExtension for RSI<C> {
 		});
 	}
 
+	fn warmup(&self) -> usize {
+		PERIODS + 2
+	}
+
 	fn lore_colour(&self) -> Option<u32> {
 		None
 	}
@@ -97,14 +89,8 @@ impl<C: Candle> RendererExtension for RSI<C> {
 	fn name(&self) -> String {
 		"RSI(10)".to_string()
 	}
-}
-
-fn avg(prices: &[f64]) -> f64 {
-	let mut sum = 0.;
 
-	for price in prices {
-		sum += *price;
+	fn config_key(&self) -> String {
+		format!("RSI(10){:?}", (self.label_colour, self.colour, self.overbought_colour, self.oversold_colour))
 	}
-
-	sum / prices.len() as f64
 }
@@ -0,0 +1,56 @@
+use std::marker::PhantomData;
+
+use model::*;
+use model::rex::{ArrowAnnotation, ArrowDirection};
+use model::rex::rsi::PERIODS;
+use utils::{calculate_rsi, detect_rsi_divergence, DivergenceType};
+
+/// Arrows at the candles where the price and the RSI(10) diverge, pointing up below bullish divergences and down above bearish ones
+#[derive(Clone, Debug)]
+pub struct RSIDivergenceAnnotations<C> {
+	_c: PhantomData<C>,
+	lookback: usize,
+	bullish_colour: u32,
+	bearish_colour: u32,
+}
+
+impl<C> RSIDivergenceAnnotations<C> {
+	/// Peaks and troughs are the highest highs and lowest lows of the `lookback` candles either side, see `detect_rsi_divergence`
+	pub fn new(lookback: usize, bullish_colour: u32, bearish_colour: u32) -> RSIDivergenceAnnotations<C> {
+		RSIDivergenceAnnotations { _c: PhantomData, lookback, bullish_colour, bearish_colour }
+	}
+}
+
+impl<C: Candle> RSIDivergenceAnnotations<C> {
+	/// Index and type of each divergence on the chart, in order of index
+	pub fn compute(&self, data: &[C]) -> Vec<(usize, DivergenceType)> {
+		detect_rsi_divergence(data, &calculate_rsi(data, PERIODS), self.lookback)
+	}
+}
+
+impl<C: Candle> RendererExtension for RSIDivergenceAnnotations<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		for (index, divergence) in self.compute(data) {
+			let arrow = match divergence {
+				DivergenceType::Bullish => ArrowAnnotation::<C>::new(index, ArrowDirection::Up, self.bullish_colour, ""),
+				DivergenceType::Bearish => ArrowAnnotation::<C>::new(index, ArrowDirection::Down, self.bearish_colour, ""),
+			};
+
+			arrow.apply(buffer, data);
+		}
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("RSIDivergence({})", self.lookback)
+	}
+
+	fn config_key(&self) -> String {
+		format!("RSIDivergence{:?}", (self.lookback, self.bullish_colour, self.bearish_colour))
+	}
+}
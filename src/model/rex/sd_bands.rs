@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+
+use model::*;
+use model::indicators::{avg, std_dev};
+
+/// Horizontal lines at some number of standard deviations from the mean close price of the whole chart
+#[derive(Clone, Debug)]
+pub struct StandardDeviationBands<C> {
+	_c: PhantomData<C>,
+	std_devs: Vec<f64>,
+	line_colour: u32,
+}
+
+impl<C> StandardDeviationBands<C> {
+	/// Each number of standard deviations gets a line above and below the mean, 0 draws the mean itself
+	pub fn new(std_devs: &[f64], line_colour: u32) -> StandardDeviationBands<C> {
+		StandardDeviationBands { _c: PhantomData, std_devs: std_devs.to_vec(), line_colour }
+	}
+}
+
+impl<C: Candle> StandardDeviationBands<C> {
+	/// Prices of the lines, in the order of the standard deviations with the line above the mean before the one below it
+	pub fn compute(&self, data: &[C]) -> Vec<f64> {
+		let closes: Vec<f64> = data.iter().map(|candle| candle.close()).collect();
+		let (mean, std_dev) = (avg(&closes), std_dev(&closes));
+
+		self.std_devs.iter()
+			.flat_map(|n| vec![mean + n * std_dev, mean - n * std_dev])
+			.collect()
+	}
+}
+
+impl<C: Candle> RendererExtension for StandardDeviationBands<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		if data.is_empty() {
+			return;
+		}
+
+		for price in self.compute(data) {
+			buffer.hline(price, self.line_colour, LineStyle::Solid);
+		}
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		Some(self.line_colour)
+	}
+
+	fn name(&self) -> String {
+		let std_devs: Vec<String> = self.std_devs.iter().map(|n| n.to_string()).collect();
+
+		format!("SD({})", std_devs.join(", "))
+	}
+
+	fn config_key(&self) -> String {
+		format!("SD{:?}", (&self.std_devs, self.line_colour))
+	}
+}
@@ -0,0 +1,108 @@
+use std::marker::PhantomData;
+
+use model::*;
+use model::indicators::std_dev;
+use utils::{aggregate, detect_price_precision, NumberLocale};
+
+/// Corner of the plot area
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum CornerPosition {
+	TopLeft,
+	TopRight,
+	BottomLeft,
+	BottomRight,
+}
+
+/// A box of the open, high, low and close of the whole chart, its change and the volatility of its closes
+#[derive(Clone, Debug)]
+pub struct StatsSummary<C> {
+	_c: PhantomData<C>,
+	position: CornerPosition,
+	text_colour: u32,
+}
+
+impl<C> StatsSummary<C> {
+	pub fn new(position: CornerPosition, text_colour: u32) -> StatsSummary<C> {
+		StatsSummary { _c: PhantomData, position, text_colour }
+	}
+}
+
+/// Colour behind the text, the same as behind extension lores
+const BOX_COLOUR: u32 = 0x7F7F7F7F;
+/// Gap between the box and the edges of the plot area, and between the box and the text
+const PADDING: usize = 4;
+
+impl<C: Candle> StatsSummary<C> {
+	/// The lines of the summary, prices to the precision of the data
+	pub fn lines(&self, data: &[C], locale: &NumberLocale) -> Vec<String> {
+		let set = aggregate(data);
+		let precision = detect_price_precision(data) as usize;
+
+		let change = if set.o != 0. { (set.c - set.o) / set.o * 100. } else { 0. };
+
+		// Standard deviation of the percentage changes from one close to the next
+		let returns: Vec<f64> = data.windows(2)
+			.filter(|pair| pair[0].close() != 0.)
+			.map(|pair| (pair[1].close() - pair[0].close()) / pair[0].close() * 100.)
+			.collect();
+
+		vec![
+			format!("Open: {}", locale.format(set.o, precision)),
+			format!("High: {}", locale.format(set.h, precision)),
+			format!("Low: {}", locale.format(set.l, precision)),
+			format!("Close: {}", locale.format(set.c, precision)),
+			format!("Change: {}%", locale.format_signed(change, 2)),
+			format!("Volatility: {}%", locale.format(std_dev(&returns), 2)),
+		]
+	}
+}
+
+/// Draws the lines in a box in the corner of the plot area
+pub(crate) fn text_box(buffer: &mut ChartBuffer, position: CornerPosition, lines: &[String], text_colour: u32) {
+	let padding = PADDING * buffer.scale;
+	let line_height = buffer.typeface().line_height(1);
+	let text_width = lines.iter().map(|line| buffer.typeface().measure(line, 1).0).max().unwrap_or(0);
+
+	let (width, height) = (text_width + padding * 2, line_height * lines.len() + padding * 2);
+	let plot = buffer.plot_area();
+
+	let left = match position {
+		CornerPosition::TopLeft | CornerPosition::BottomLeft => plot.left + padding,
+		CornerPosition::TopRight | CornerPosition::BottomRight => plot.right.saturating_sub(padding + width),
+	};
+	let top = match position {
+		CornerPosition::TopLeft | CornerPosition::TopRight => plot.top + padding,
+		CornerPosition::BottomLeft | CornerPosition::BottomRight => plot.bottom.saturating_sub(padding + height),
+	};
+
+	buffer.rect(left, top, left + width - 1, top + height - 1, BOX_COLOUR);
+
+	for (i, line) in lines.iter().enumerate() {
+		buffer.text((left + padding, top + padding + i * line_height), line, text_colour);
+	}
+}
+
+impl<C: Candle> RendererExtension for StatsSummary<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
+		if data.is_empty() {
+			return;
+		}
+
+		let lines = self.lines(buffer.charted(data), &buffer.locale);
+		text_box(buffer, self.position, &lines, self.text_colour);
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("StatsSummary({:?})", self.position)
+	}
+
+	fn config_key(&self) -> String {
+		format!("StatsSummary{:?}", (self.position, self.text_colour))
+	}
+}
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+use model::*;
+
+/// A line between two points on the chart, each being a time offset in seconds from the start of the chart and a price
+#[derive(Clone, Debug)]
+pub struct TrendLine<C> {
+	_c: PhantomData<C>,
+	start: (i64, f64),
+	end: (i64, f64),
+	colour: u32,
+	extend: bool,
+	channel: Option<f64>,
+}
+
+impl<C> TrendLine<C> {
+	/// Extending the line carries it on past both points to the edges of the chart
+	pub fn new(start: (i64, f64), end: (i64, f64), colour: u32, extend: bool) -> TrendLine<C> {
+		TrendLine { _c: PhantomData, start, end, colour, extend, channel: None }
+	}
+
+	/// Adds a parallel line offset by the price difference, making a trend channel
+	pub fn channel(mut self, channel: Option<f64>) -> TrendLine<C> {
+		self.channel = channel;
+
+		self
+	}
+}
+
+impl<C: Candle> RendererExtension for TrendLine<C> {
+	type Candle = C;
+
+	fn apply(&self, buffer: &mut ChartBuffer, _data: &[C]) {
+		draw(buffer, self.start, self.end, self.extend, self.colour);
+
+		if let Some(offset) = self.channel {
+			draw(buffer, (self.start.0, self.start.1 + offset), (self.end.0, self.end.1 + offset), self.extend, self.colour);
+		}
+	}
+
+	fn lore_colour(&self) -> Option<u32> {
+		None
+	}
+
+	fn name(&self) -> String {
+		format!("TrendLine({:?}, {:?})", self.start, self.end)
+	}
+
+	fn config_key(&self) -> String {
+		format!("TrendLine{:?}", (self.start, self.end, self.colour, self.extend, self.channel))
+	}
+}
+
+fn draw(buffer: &mut ChartBuffer, start: (i64, f64), end: (i64, f64), extend: bool, rgba: u32) {
+	let (mut start, mut end) = ((start.0 as f64, start.1), (end.0 as f64, end.1));
+
+	if extend && start.0 != end.0 {
+		let slope = (end.1 - start.1) / (end.0 - start.0);
+		let (from, to) = if start.0 < end.0 { (0., buffer.timeframe as f64) } else { (buffer.timeframe as f64, 0.) };
+
+		end = (to, start.1 + slope * (to - start.0));
+		start = (from, start.1 + slope * (from - start.0));
+	}
+
+	// data_to_coords pins anything off the chart to its edges, which would bend the line, so cut it to the chart first
+	if let Some((start, end)) = clip(buffer, start, end) {
+		let p1 = buffer.data_to_coords(start.1, start.0.round() as i64);
+		let p2 = buffer.data_to_coords(end.1, end.0.round() as i64);
+
+		buffer.line(p1, p2, rgba);
+	}
+}
+
+/// Cuts the segment down to the part within the time and price range of the chart
+fn clip(buffer: &ChartBuffer, start: (f64, f64), end: (f64, f64)) -> Option<((f64, f64), (f64, f64))> {
+	let (mut low, mut high) = (0f64, 1f64);
+	let delta = (end.0 - start.0, end.1 - start.1);
+
+	let bounds = [
+		(start.0, delta.0, 0., buffer.timeframe as f64),
+		(start.1, delta.1, buffer.min_price, buffer.max_price),
+	];
+
+	for &(from, delta, min, max) in bounds.iter() {
+		if delta == 0. {
+			if from < min || from > max {
+				return None;
+			}
+			continue;
+		}
+
+		let (a, b) = ((min - from) / delta, (max - from) / delta);
+		low = low.max(a.min(b));
+		high = high.min(a.max(b));
+	}
+
+	if low > high {
+		return None;
+	}
+
+	Some((
+		(start.0 + delta.0 * low, start.1 + delta.1 * low),
+		(start.0 + delta.0 * high, start.1 + delta.1 * high),
+	))
+}
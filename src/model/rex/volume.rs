@@ -2,6 +2,79 @@ use std::marker::PhantomData;
 
 use model::*;
 
+/// How volumes map to the heights of the bars
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum VolumeScale {
+	/// Heights in proportion to the volumes
+	Linear,
+	/// Heights in proportion to `log10(volume + 1)`, so that spikes don't flatten the other bars
+	Log,
+	/// Heights in proportion to the volumes with the average volume at half the height of the strip, so that a spike doesn't flatten the other bars but is cut off at the top
+	Average,
+}
+
+impl VolumeScale {
+	/// Volume at the top of the strip, the max volume or twice the average
+	fn top(&self, max_volume: f64, mean_volume: f64) -> f64 {
+		match *self {
+			VolumeScale::Average => 2. * mean_volume,
+			VolumeScale::Linear | VolumeScale::Log => max_volume,
+		}
+	}
+
+	/// Height of the volume as a fraction of the strip, where the top volume reaches the top
+	fn height(&self, volume: f64, top_volume: f64) -> f64 {
+		// Negative volumes are nonsense, leave them at the bottom rather than taking their log
+		let (volume, top_volume) = (volume.max(0.), top_volume.max(0.));
+		if top_volume == 0. {
+			return 0.;
+		}
+
+		match *self {
+			VolumeScale::Linear | VolumeScale::Average => (volume / top_volume).min(1.),
+			VolumeScale::Log => ((volume + 1.).log10() / (top_volume + 1.).log10()).min(1.),
+		}
+	}
+
+	/// The inverse of `height`, the volume at the fraction of the strip
+	fn volume_at(&self, height: f64, top_volume: f64) -> f64 {
+		let top_volume = top_volume.max(0.);
+
+		match *self {
+			VolumeScale::Linear | VolumeScale::Average => height * top_volume,
+			VolumeScale::Log => 10f64.powf(height * (top_volume + 1.).log10()) - 1.,
+		}
+	}
+}
+
+/// What a candle's direction is measured from when colouring by it
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ColourMode {
+	/// Up when it closes above its open
+	OpenClose,
+	/// Up when it closes above the previous candle's close, the first candle goes by its open
+	PreviousClose,
+}
+
+impl ColourMode {
+	/// Returns: the up, down or neutral colour by the direction of the candle at the index
+	pub fn pick<C: Candle>(&self, data: &[C], i: usize, up_colour: u32, down_colour: u32, neutral_colour: u32) -> u32 {
+		let from = match *self {
+			ColourMode::PreviousClose if i > 0 => data[i - 1].close(),
+			_ => data[i].open(),
+		};
+		let close = data[i].close();
+
+		if close > from {
+			up_colour
+		} else if close < from {
+			down_colour
+		} else {
+			neutral_colour
+		}
+	}
+}
+
 #[derive(Clone, Debug)]
 pub struct Volume<C> {
 	_c: PhantomData<C>,
@@ -9,11 +82,27 @@ pub struct Volume<C> {
 	buy_colour: u32,
 	sell_colour: u32,
 	generic_colour: u32,
+	scale: VolumeScale,
+	direction_colours: Option<(u32, u32, u32, ColourMode)>,
 }
 
 impl<C> Volume<C> {
 	pub fn new(label_colour: u32, buy_colour: u32, sell_colour: u32, generic_colour: u32) -> Volume<C> {
-		Volume { _c: PhantomData, label_colour, buy_colour, sell_colour, generic_colour }
+		Volume { _c: PhantomData, label_colour, buy_colour, sell_colour, generic_colour, scale: VolumeScale::Linear, direction_colours: None }
+	}
+
+	/// Colours whole bars by the direction of their candles, neutral for candles that don't move, instead of splitting them by buy volume
+	pub fn direction_colours(mut self, up_colour: u32, down_colour: u32, neutral_colour: u32, mode: ColourMode) -> Volume<C> {
+		self.direction_colours = Some((up_colour, down_colour, neutral_colour, mode));
+
+		self
+	}
+
+	/// How volumes map to the heights of the bars, linear up to the max volume by default
+	pub fn scale(mut self, scale: VolumeScale) -> Volume<C> {
+		self.scale = scale;
+
+		self
 	}
 }
 
@@ -21,22 +110,16 @@ impl<C: Candle> RendererExtension for Volume<C> {
 	type Candle = C;
 
 	fn apply(&self, buffer: &mut ChartBuffer, data: &[C]) {
-		let mut vols = vec![];
-		let mut max_vol = 0.;
+		let vols: Vec<(Option<f64>, f64)> = data.iter().map(|candle| (candle.buy_volume(), candle.total_volume())).collect();
 
-		for i in 0..data.len() {
-			let candle = &data[i];
-			let b_vol = candle.buy_volume();
-			let total_vol = candle.total_volume();
-
-			if total_vol > max_vol {
-				max_vol = total_vol;
-			}
-			vols.push((b_vol, total_vol));
-		}
+		// Scaled to the candles on the chart, the bars of those in the lead aren't drawn
+		let charted = buffer.charted(&vols);
+		let max_vol = charted.iter().fold(0., |max_vol: f64, &(_, total_vol)| max_vol.max(total_vol));
+		let mean_vol = charted.iter().map(|&(_, total_vol)| total_vol.max(0.)).sum::<f64>() / charted.len().max(1) as f64;
+		let top_vol = self.scale.top(max_vol, mean_vol);
 
 		buffer.create_extension_strip(175, move |buffer| {
-			buffer.text((8, 8), "Volume", self.label_colour);
+			buffer.text((8 * buffer.scale, 8 * buffer.scale), "Volume", self.label_colour);
 
 			// Lines and labels
 			{
@@ -46,9 +129,9 @@ impl<C: Candle> RendererExtension for Volume<C> {
 
 					buffer.line(p1, p2, self.label_colour);
 
-					let price = prog * max_vol;
+					let price = self.scale.volume_at(*prog, top_vol);
 
-					buffer.text_with_outline((p2.0 + 5, p2.1 - 9), &format!("{}", keep_msf(price, 3)), self.label_colour);
+					buffer.text_with_outline((p2.0 + 5 * buffer.scale, p2.1 - 9 * buffer.scale), &format!("{}", keep_msf(price, 3)), self.label_colour, TextStyle::Regular);
 				}
 			}
 
@@ -64,14 +147,20 @@ impl<C: Candle> RendererExtension for Volume<C> {
 					let right_most = ((period * (i as i64)) as f64 + period_addition) as i64;
 
 					let bottom_left = buffer.data_to_coords(0., left_most);
-					if let Some(b) = b {
-						let mid_right = buffer.data_to_coords(b / max_vol, right_most);
+					let height = self.scale.height(t, top_vol);
+					if let Some((up_colour, down_colour, neutral_colour, mode)) = self.direction_colours {
+						let top_right = buffer.data_to_coords(height, right_most);
+						buffer.rect_point(bottom_left, top_right, mode.pick(data, i, up_colour, down_colour, neutral_colour));
+					} else if let Some(b) = b {
+						// The buy share of the bar is kept in proportion on either scale
+						let buy_height = if t > 0. { height * b / t } else { 0. };
+						let mid_right = buffer.data_to_coords(buy_height, right_most);
 
 						buffer.rect_point(bottom_left, mid_right, self.buy_colour);
-						let top_left = buffer.data_to_coords(t / max_vol, left_most);
+						let top_left = buffer.data_to_coords(height, left_most);
 						buffer.rect_point(mid_right, top_left, self.sell_colour);
 					} else {
-						let top_right = buffer.data_to_coords(t / max_vol, right_most);
+						let top_right = buffer.data_to_coords(height, right_most);
 						buffer.rect_point(bottom_left, top_right, self.generic_colour);
 					}
 				}
@@ -86,6 +175,10 @@ impl<C: Candle> RendererExtension for Volume<C> {
 	fn name(&self) -> String {
 		"Volume".to_string()
 	}
+
+	fn config_key(&self) -> String {
+		format!("Volume{:?}", (self.label_colour, self.buy_colour, self.sell_colour, self.generic_colour, self.scale, self.direction_colours))
+	}
 }
 
 fn keep_msf(num: f64, sigfigs: usize) -> f64 {
@@ -99,6 +192,41 @@ fn keep_msf(num: f64, sigfigs: usize) -> f64 {
 	(num / 10_f64.powf(factor)).round() * 10_f64.powf(factor)
 }
 
+#[cfg(test)]
+#[test]
+fn volume_scale_test() {
+	assert_eq!(VolumeScale::Linear.height(25., 100.), 0.25);
+	assert_eq!(VolumeScale::Log.height(9., 999.), 1. / 3.);
+	assert_eq!(VolumeScale::Log.height(999., 999.), 1.);
+	assert_eq!(VolumeScale::Log.height(0., 999.), 0.);
+	assert_eq!(VolumeScale::Log.height(-5., 999.), 0.);
+	assert_eq!(VolumeScale::Log.height(0., 0.), 0.);
+	assert!((VolumeScale::Log.volume_at(1. / 3., 999.) - 9.).abs() < 1e-9);
+	assert_eq!(VolumeScale::Linear.volume_at(0.5, 100.), 50.);
+
+	// The average at half the height and a spike cut off at the top
+	let top = VolumeScale::Average.top(1000., 20.);
+	assert_eq!(top, 40.);
+	assert_eq!(VolumeScale::Average.height(20., top), 0.5);
+	assert_eq!(VolumeScale::Average.height(1000., top), 1.);
+	assert_eq!(VolumeScale::Average.volume_at(0.5, top), 20.);
+	assert_eq!(VolumeScale::Linear.top(1000., 20.), 1000.);
+}
+
+#[cfg(test)]
+#[test]
+fn colour_mode_test() {
+	use model::data::OHLC;
+
+	let candle = |o, c| OHLC { o, h: o.max(c), l: o.min(c), c, t: None, v: None };
+	let data = [candle(2., 3.), candle(4., 3.), candle(3., 3.5), candle(3.5, 3.5)];
+	let picks = |mode: ColourMode| (0..data.len()).map(|i| mode.pick(&data, i, 1, 2, 3)).collect::<Vec<u32>>();
+
+	assert_eq!(picks(ColourMode::OpenClose), vec![1, 2, 1, 3]);
+	// The second candle falls from its open but closes flat from the first
+	assert_eq!(picks(ColourMode::PreviousClose), vec![1, 3, 1, 3]);
+}
+
 #[cfg(test)]
 #[test]
 fn keep_msf_test() {
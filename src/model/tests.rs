@@ -0,0 +1,852 @@
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use super::*;
+use fonts::Typeface;
+use manifest::DrawnElement;
+use model::data::OHLC;
+use model::rex::{CandleStyle, GradientCandles, GridLines, GridStyle, HighlightBox, HollowMode, OHLCCandles, PriceLineMarks, WickCap, WickStyle};
+
+const BACKGROUND: u32 = 0x000000FF;
+const COLOUR: u32 = 0xFFFFFFFF;
+
+fn buffer() -> ChartBuffer {
+	ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 1., 0., 1, BACKGROUND)
+}
+
+fn pixel(buffer: &ChartBuffer, p: Point) -> [u8; 3] {
+	let i = (p.0 + p.1 * buffer.width()) * 3;
+	[buffer.buffer[i], buffer.buffer[i + 1], buffer.buffer[i + 2]]
+}
+
+#[test]
+fn spline_passes_through_points() {
+	let mut buffer = buffer();
+	let points = [(5, 50), (40, 10), (80, 90), (120, 30), (190, 60)];
+
+	buffer.spline(&points, 0., COLOUR);
+
+	// Lines don't colour their end point, so the last point is left out
+	for p in &points[..points.len() - 1] {
+		assert_eq!(pixel(&buffer, *p), [0xFF; 3], "{:?} was not drawn", p);
+	}
+}
+
+#[test]
+fn spline_with_full_tension_matches_polyline() {
+	let points = [(5, 50), (40, 10), (80, 90), (120, 30), (190, 60)];
+
+	let mut curved = buffer();
+	curved.spline(&points, 1., COLOUR);
+
+	let mut straight = buffer();
+	straight.polyline(&points, COLOUR);
+
+	for p in &points {
+		assert_eq!(pixel(&curved, *p), pixel(&straight, *p));
+	}
+}
+
+#[test]
+fn spline_falls_back_to_lines_for_two_points() {
+	let mut curved = buffer();
+	curved.spline(&[(10, 10), (100, 60)], 0., COLOUR);
+
+	let mut straight = buffer();
+	straight.line((10, 10), (100, 60), COLOUR);
+
+	assert!(curved.buffer == straight.buffer);
+}
+
+#[test]
+fn invert_colours_round_trips() {
+	let mut buffer = buffer();
+	buffer.rect(10, 10, 50, 50, 0x336699FF);
+	let original = buffer.buffer.clone();
+
+	buffer.invert_colours();
+	assert_eq!(pixel(&buffer, (20, 20)), [0xCC, 0x99, 0x66]);
+	assert_eq!(pixel(&buffer, (0, 0)), [0xFF; 3]);
+
+	buffer.invert_colours();
+	assert!(buffer.buffer == original);
+}
+
+#[test]
+fn grayscale_uses_luma_weights() {
+	let mut buffer = buffer();
+	buffer.rect(10, 10, 50, 50, 0xFF0000FF);
+	buffer.rect(60, 10, 90, 50, 0xFFFFFFFF);
+
+	buffer.grayscale();
+
+	assert_eq!(pixel(&buffer, (20, 20)), [76; 3]);
+	assert_eq!(pixel(&buffer, (70, 20)), [0xFF; 3]);
+	assert_eq!(pixel(&buffer, (0, 0)), [0; 3]);
+}
+
+#[test]
+fn background_gradient_interpolates_rows() {
+	let mut buffer = buffer();
+	buffer.background_gradient(0x000000FF, 0xFF6300FF);
+
+	assert_eq!(pixel(&buffer, (0, 0)), [0, 0, 0]);
+	assert_eq!(pixel(&buffer, (199, 99)), [0xFF, 0x63, 0]);
+	assert_eq!(pixel(&buffer, (0, 33)), [85, 33, 0]);
+	assert_eq!(pixel(&buffer, (0, 33)), pixel(&buffer, (150, 33)));
+}
+
+#[test]
+fn text_width_counts_the_longest_line() {
+	assert_eq!(::fonts::text_width("", 1), 0);
+	assert_eq!(::fonts::text_width("8004.6", 1), 60);
+	assert_eq!(::fonts::text_width("8004.6", 2), 120);
+	assert_eq!(::fonts::text_width("ab\nabcd\nc", 1), 40);
+}
+
+#[test]
+fn text_anchored_right_ends_at_anchor() {
+	let mut anchored = buffer();
+	anchored.text_anchored((100, 50), "ABC", COLOUR, TextStyle::Regular, HAlign::Right, VAlign::Middle);
+
+	let mut plain = buffer();
+	plain.text((70, 42), "ABC", COLOUR);
+
+	assert!(anchored.buffer == plain.buffer);
+}
+
+#[test]
+fn text_anchored_centre_straddles_anchor() {
+	let mut anchored = buffer();
+	anchored.text_anchored((100, 50), "ABCD", COLOUR, TextStyle::Regular, HAlign::Centre, VAlign::Bottom);
+
+	let mut plain = buffer();
+	plain.text((80, 33), "ABCD", COLOUR);
+
+	assert!(anchored.buffer == plain.buffer);
+}
+
+#[test]
+fn text_rotated_transposes_glyphs() {
+	let mut plain = buffer();
+	plain.text((0, 0), "Fg", COLOUR);
+
+	for rotation in &[Rotation90::Deg90, Rotation90::Deg270] {
+		let mut rotated = buffer();
+		rotated.text_rotated((100, 10), "Fg", COLOUR, *rotation);
+
+		for x in 0..20 {
+			for y in 0..17 {
+				let (rx, ry) = match *rotation {
+					Rotation90::Deg90 => (100 + 16 - y, 10 + x),
+					Rotation90::Deg270 => (100 + y, 10 + 19 - x),
+				};
+
+				assert_eq!(pixel(&plain, (x, y)), pixel(&rotated, (rx, ry)));
+			}
+		}
+	}
+}
+
+#[test]
+fn text_handles_characters_outside_the_font() {
+	let mut buffer = buffer();
+	buffer.text((0, 0), "BTC 🚀 ₿ → Привет", COLOUR);
+	buffer.text_rotated((0, 0), "🚀", COLOUR, Rotation90::Deg90);
+
+	assert_eq!(::fonts::text_width("🚀 ₿", 1), 30);
+}
+
+#[test]
+fn text_draws_extended_glyphs() {
+	let mut euro = buffer();
+	euro.text((0, 0), "€1,234", COLOUR);
+
+	let mut blank = buffer();
+	blank.text((0, 0), " 1,234", COLOUR);
+
+	// The euro sign is drawn in the first cell rather than skipped
+	assert!((0..10).any(|x| (0..17).any(|y| pixel(&euro, (x, y)) != pixel(&blank, (x, y)))));
+	assert!(::fonts::glyph('€') != ::fonts::glyph('□'));
+	assert!(::fonts::glyph('é') != ::fonts::glyph('□'));
+}
+
+#[test]
+fn unknown_characters_use_the_replacement_glyph() {
+	assert!(::fonts::glyph('🚀') == ::fonts::glyph('□'));
+	assert!(::fonts::glyph('П') == ::fonts::glyph('□'));
+	assert!(::fonts::glyph('\u{7F}') == ::fonts::glyph('□'));
+	assert!(::fonts::glyph('\t').iter().all(|a| *a == 0));
+}
+
+#[test]
+fn unparseable_font_falls_back_to_bitmap() {
+	let mut buffer = buffer();
+	buffer.typeface = ::fonts::Typeface::from_bytes(vec![0, 1, 2, 3], 16.);
+
+	assert_eq!(buffer.typeface.measure("BTC", 1), (30, 17));
+	assert_eq!(buffer.typeface.line_height(1), 17);
+}
+
+#[cfg(feature = "ttf")]
+#[test]
+fn truetype_text_is_measured_in_the_font() {
+	let bytes = match ::std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") {
+		Ok(bytes) => bytes,
+		Err(_) => return,
+	};
+
+	let mut buffer = buffer();
+	buffer.typeface = ::fonts::Typeface::from_bytes(bytes, 24.);
+
+	let (width, height) = buffer.typeface.measure("iii", 1);
+	assert!(width < buffer.typeface.measure("WWW", 1).0);
+	assert!(height >= 24);
+
+	buffer.text((0, 0), "WWW", COLOUR);
+	assert!((0..width).any(|x| (0..height).any(|y| pixel(&buffer, (x, y)) != [0, 0, 0])));
+}
+
+#[test]
+fn bold_text_is_struck_twice() {
+	let mut regular = buffer();
+	regular.text((0, 0), "l", COLOUR);
+
+	let mut bold = buffer();
+	bold.text_styled((0, 0), "l", COLOUR, 1, TextStyle::Bold);
+
+	for x in 0..10 {
+		for y in 0..17 {
+			// Each pixel of the glyph is repeated one pixel to the right
+			assert!(pixel(&bold, (x + 1, y))[0] >= pixel(&regular, (x, y))[0]);
+			assert!(pixel(&bold, (x, y))[0] >= pixel(&regular, (x, y))[0]);
+		}
+	}
+
+	assert_eq!(bold.typeface.measure_styled("BTC", 1, TextStyle::Bold), (31, 17));
+}
+
+#[test]
+fn supersampled_bold_text_is_struck_a_chart_pixel_apart() {
+	let lit_columns = |typeface: &Typeface, style: TextStyle| {
+		let mut columns = vec![];
+		typeface.rasterise_styled("l", 1, style, |x, _, a| if a > 0 && !columns.contains(&x) { columns.push(x) });
+		columns.sort();
+		columns
+	};
+
+	let typeface = Typeface::Bitmap.supersampled(3);
+	let regular = lit_columns(&typeface, TextStyle::Regular);
+	let bold = lit_columns(&typeface, TextStyle::Bold);
+
+	// Three times as wide as the bold of the finished chart, so the same once scaled down
+	assert_eq!(bold.len(), regular.len() + 3);
+	assert_eq!(bold[0], regular[0]);
+	assert_eq!(typeface.measure_styled("BTC", 1, TextStyle::Bold), (31 * 3, 17 * 3));
+}
+
+#[test]
+fn rect_gradient_fades_from_top_to_bottom() {
+	let mut buffer = buffer();
+	buffer.rect_gradient(10, 10, 20, 30, 0xFFFFFFFF, 0xFFFFFF00);
+
+	assert_eq!(pixel(&buffer, (15, 10)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (15, 20)), [128, 128, 128]);
+	assert_eq!(pixel(&buffer, (15, 30)), [0, 0, 0]);
+	assert_eq!(interpolate_rgba(0x00000000, 0xFF80400F, 0.5), 0x80402008);
+}
+
+#[test]
+fn dashed_line_alternates_dashes_and_gaps() {
+	let mut buffer = buffer();
+	buffer.dashed_line((0, 10), (40, 10), 6, 4, COLOUR);
+
+	for x in 0..40 {
+		let expected = if x % 10 < 6 { [255, 255, 255] } else { [0, 0, 0] };
+		assert_eq!(pixel(&buffer, (x, 10)), expected, "x = {}", x);
+	}
+}
+
+#[test]
+fn measured_text_fits_what_is_drawn() {
+	for &style in [TextStyle::Regular, TextStyle::Bold].iter() {
+		for &scale in [1, 2].iter() {
+			let text = "W_|@ é€";
+			let (width, height) = ::fonts::measure_text(text, scale, style);
+
+			let mut buffer = buffer();
+			buffer.text_styled((20, 10), text, COLOUR, scale, style);
+
+			for y in 0..buffer.height() {
+				assert_eq!(pixel(&buffer, (20 + width, y)), [0, 0, 0]);
+			}
+			for x in 0..buffer.width() {
+				assert_eq!(pixel(&buffer, (x, 10 + height)), [0, 0, 0]);
+			}
+			// The last glyph is drawn in the last cell, so the measurement isn't too wide either
+			let mut last_cell = (20 + width - ::fonts::CHAR_WIDTH * scale as usize)..(20 + width);
+			assert!(last_cell.any(|x| (10..10 + height).any(|y| pixel(&buffer, (x, y)) != [0, 0, 0])));
+		}
+	}
+}
+
+#[test]
+fn drawing_is_clipped() {
+	let mut buffer = buffer();
+	let clip = Rect { left: 10, top: 10, right: 20, bottom: 20 };
+	buffer.set_clip(Some(clip));
+
+	buffer.rect(0, 0, 199, 99, COLOUR);
+	buffer.line((0, 15), (199, 15), COLOUR);
+	buffer.text((5, 5), "WW", COLOUR);
+
+	for x in 0..buffer.width() {
+		for y in 0..buffer.height() {
+			let expected = if clip.contains(x, y) { [255, 255, 255] } else { [0, 0, 0] };
+			assert_eq!(pixel(&buffer, (x, y)), expected);
+		}
+	}
+
+	buffer.without_clip(|buffer| buffer.colour(0, 0, COLOUR));
+	assert_eq!(pixel(&buffer, (0, 0)), [255, 255, 255]);
+	assert_eq!(buffer.clip(), Some(clip));
+}
+
+#[test]
+fn triangle_is_filled() {
+	let mut buffer = buffer();
+	buffer.triangle((50, 10), (40, 30), (60, 30), COLOUR);
+
+	assert_eq!(pixel(&buffer, (50, 10)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (50, 20)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (40, 30)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (60, 30)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (42, 15)), [0, 0, 0]);
+	assert_eq!(pixel(&buffer, (50, 31)), [0, 0, 0]);
+}
+
+#[test]
+fn coords_to_data_inverts_data_to_coords() {
+	let buffer = ChartBuffer::new(1310, 650, Margin { top: 60, bottom: 35, left: 12, right: 113 }, 8004.6, 5627.2, 168 * 3600, BACKGROUND);
+	let plot = buffer.plot_area();
+
+	// One pixel's worth of price and time
+	let price_step = (buffer.max_price - buffer.min_price) / (plot.bottom - plot.top) as f64;
+	let time_step = buffer.timeframe as f64 / (plot.right - plot.left) as f64;
+
+	for i in 0..=100 {
+		for j in 0..=100 {
+			let price = buffer.min_price + (buffer.max_price - buffer.min_price) * i as f64 / 100.;
+			let time = buffer.timeframe * j / 100;
+
+			let (inverse_price, inverse_time) = buffer.coords_to_data(buffer.data_to_coords(price, time));
+
+			assert!((inverse_price - price).abs() <= price_step, "price {} came back as {}", price, inverse_price);
+			assert!(((inverse_time - time) as f64).abs() <= time_step + 1., "time {} came back as {}", time, inverse_time);
+		}
+	}
+
+	// Corners of the plot area map to the extremes of the chart exactly
+	assert_eq!(buffer.coords_to_data((plot.left, plot.bottom)), (buffer.min_price, 0));
+	assert_eq!(buffer.coords_to_data((plot.right, plot.top)), (buffer.max_price, buffer.timeframe));
+}
+
+#[test]
+fn fill_between_fills_columns_between_lines() {
+	let mut buffer = buffer();
+	buffer.fill_between(&[(10, 20), (30, 40)], &[(0, 60), (50, 60)], COLOUR);
+
+	assert_eq!(pixel(&buffer, (10, 20)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (20, 30)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (20, 60)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (20, 29)), [0, 0, 0]);
+	assert_eq!(pixel(&buffer, (20, 61)), [0, 0, 0]);
+	// Only where both lines are
+	assert_eq!(pixel(&buffer, (5, 60)), [0, 0, 0]);
+	assert_eq!(pixel(&buffer, (31, 60)), [0, 0, 0]);
+}
+
+#[test]
+fn data_to_coords_pins_to_the_plot_area() {
+	let buffer = ChartBuffer::new(400, 300, Margin { top: 20, bottom: 30, left: 10, right: 40 }, 110., 100., 3600, BACKGROUND);
+	let plot = buffer.plot_area();
+
+	assert_eq!(buffer.data_to_coords(1e12, 0), (plot.left, plot.top));
+	assert_eq!(buffer.data_to_coords(-1e12, buffer.timeframe * 1000), (plot.right, plot.bottom));
+	assert_eq!(buffer.data_to_coords(105., -buffer.timeframe), (plot.left, 145));
+
+	// Signed coordinates carry on past the plot area instead
+	assert_eq!(buffer.data_to_coords_signed(120., buffer.timeframe * 2), (360 * 2 - 10, 20 - 250));
+	assert_eq!(buffer.data_to_coords_signed(90., -buffer.timeframe), (10 - 350, 270 + 250));
+	assert!(buffer.data_to_coords_signed(1e12, 0).1 < -1_000_000_000);
+	assert!(buffer.data_to_coords_signed(-1e12, 0).1 > 1_000_000_000);
+
+	// Within the chart, both agree
+	for &(price, time) in [(100., 0), (110., 3600), (104.2, 1234)].iter() {
+		let (x, y) = buffer.data_to_coords_signed(price, time);
+		assert_eq!(buffer.data_to_coords(price, time), (x as usize, y as usize));
+	}
+}
+
+#[test]
+fn primitives_ignore_coordinates_off_the_image() {
+	let mut buffer = buffer();
+	let far = usize::MAX;
+
+	buffer.rect(150, 50, far, far, COLOUR);
+	buffer.rect_gradient(150, 50, far, far, COLOUR, COLOUR);
+	buffer.rect_outline(0, 0, far, far, 2, COLOUR);
+	buffer.triangle((150, 50), (far, 50), (150, far), COLOUR);
+	buffer.line((0, 0), (far, far / 2), COLOUR);
+	buffer.dashed_line((0, 99), (far, 99), 6, 4, COLOUR);
+	buffer.fill_between(&[(190, 0), (far, 0)], &[(190, far), (far, far)], COLOUR);
+	buffer.text((far - 5, far - 5), "WW", COLOUR);
+
+	assert_eq!(pixel(&buffer, (199, 99)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (0, 0)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (10, 5)), [255, 255, 255]);
+	assert_eq!(pixel(&buffer, (100, 20)), [0, 0, 0]);
+}
+
+#[test]
+fn random_primitives_stay_in_bounds() {
+	let mut buffer = buffer();
+	let (width, height) = (buffer.width(), buffer.height());
+
+	// Linear congruential generator, so the test doesn't need a dependency and is the same on every run
+	let mut seed: u64 = 0x2545F4914F6CDD1D;
+	let mut random = |max: usize| {
+		seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		(seed >> 33) as usize % max
+	};
+
+	for _ in 0..5000 {
+		let p1 = (random(width * 2), random(height * 2));
+		let p2 = (random(width * 2), random(height * 2));
+		let p3 = (random(width * 2), random(height * 2));
+
+		buffer.line(p1, p2, COLOUR);
+		buffer.dashed_line(p1, p2, 3, 2, 0xFF00007F);
+		buffer.rect(p1.0, p1.1, p2.0, p2.1, 0x00FF0010);
+		buffer.rect_outline(p1.0, p1.1, p2.0, p2.1, 3, COLOUR);
+		buffer.triangle(p1, p2, p3, 0x0000FF10);
+		buffer.spline(&[p1, p2, p3], 0.5, COLOUR);
+		buffer.text(p3, "WW", COLOUR);
+	}
+
+	assert_eq!(buffer.buffer.len(), width * height * 3);
+}
+
+#[test]
+fn overlapping_translucent_rects_blend() {
+	let mut buffer = buffer();
+	buffer.rect(10, 10, 30, 30, 0xFF000080);
+	buffer.rect(20, 20, 40, 40, 0x0000FF80);
+
+	assert_eq!(pixel(&buffer, (15, 15)), [128, 0, 0]);
+	assert_eq!(pixel(&buffer, (35, 35)), [0, 0, 128]);
+	// Blue over the red over black
+	assert_eq!(pixel(&buffer, (25, 25)), [64, 0, 128]);
+}
+
+#[test]
+fn translucent_candles_are_blended_once() {
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 200, BACKGROUND);
+	let data = vec![OHLC { o: 2., h: 10., l: 0., c: 8., t: None, v: None }, OHLC { o: 8., h: 9., l: 1., c: 3., t: None, v: None }];
+
+	OHLCCandles::new(0x00FF0080, 0xFF000080, None, None, WickStyle::Full).wick_cap(WickCap::Tick, 30).apply(&mut buffer, &data);
+
+	let mut colours: Vec<[u8; 3]> = buffer.buffer.chunks(3).map(|rgb| [rgb[0], rgb[1], rgb[2]]).collect();
+	colours.sort();
+	colours.dedup();
+
+	// Body, wick and cap pixels of each candle are all the same single blend over the background
+	assert_eq!(colours, vec![[0, 0, 0], [0, 128, 0], [128, 0, 0]]);
+}
+
+#[test]
+fn unfaded_gradient_candles_match_the_standard_candles() {
+	// A lone candle is kept narrower than it'd otherwise be drawn
+	for &count in &[1, 37] {
+		let data: Vec<OHLC> = (0..count).map(|i| {
+			let (o, c) = (2. + (i % 5) as f64, 3. + (i % 3) as f64);
+			OHLC { o, h: o.max(c) + 1., l: o.min(c) - 1., c, t: None, v: None }
+		}).collect();
+		let draw = |candles: &dyn RendererExtension<Candle = OHLC>| {
+			let mut buffer = ChartBuffer::new(300, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 3600 * count as i64, BACKGROUND);
+			candles.apply(&mut buffer, &data);
+			buffer.buffer
+		};
+
+		let standard = draw(&OHLCCandles::new(0x00FF00FF, 0xFF0000FF, None, None, WickStyle::Full));
+		let gradient = draw(&GradientCandles::new(0x00FF00FF, 0xFF0000FF, 0.));
+		assert!(standard == gradient, "{} candles", count);
+	}
+}
+
+#[test]
+fn wick_styles_draw_only_their_wicks() {
+	let data = vec![OHLC { o: 4., h: 9., l: 1., c: 6., t: None, v: None }];
+	let drawn_rows = |wick_style| {
+		let mut buffer = ChartBuffer::new(100, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+		OHLCCandles::new(COLOUR, COLOUR, None, None, wick_style).apply(&mut buffer, &data);
+
+		(0..100).filter(|&y| (0..100).any(|x| pixel(&buffer, (x, y)) != [0; 3])).collect::<Vec<usize>>()
+	};
+
+	let full = drawn_rows(WickStyle::Full);
+	let upper = drawn_rows(WickStyle::UpperOnly);
+	let lower = drawn_rows(WickStyle::LowerOnly);
+	let body = drawn_rows(WickStyle::BodyOnly);
+
+	assert_eq!((full[0], full[full.len() - 1]), (upper[0], lower[lower.len() - 1]));
+	assert_eq!(upper[upper.len() - 1], body[body.len() - 1]);
+	assert_eq!(lower[0], body[0]);
+	assert!(body[0] > full[0] && body[body.len() - 1] < full[full.len() - 1]);
+	assert_eq!(body, drawn_rows(WickStyle::None));
+}
+
+#[test]
+fn guide_lines_span_the_plot_area() {
+	let margin = Margin { top: 10, bottom: 20, left: 30, right: 40 };
+	let mut buffer = ChartBuffer::new(200, 100, margin, 10., 0., 100, BACKGROUND);
+	let plot = buffer.plot_area();
+
+	buffer.hline(5., COLOUR, LineStyle::Solid);
+	buffer.vline(50, COLOUR, LineStyle::Solid);
+	let (x, y) = buffer.data_to_coords(5., 50);
+
+	let row: Vec<usize> = (0..200).filter(|&x| pixel(&buffer, (x, y)) != [0; 3]).collect();
+	let column: Vec<usize> = (0..100).filter(|&y| pixel(&buffer, (x, y)) != [0; 3]).collect();
+
+	// Lines leave out their rightmost or bottom pixel
+	assert_eq!((row[0], row[row.len() - 1]), (plot.left, plot.right - 1));
+	assert_eq!((column[0], column[column.len() - 1]), (plot.top, plot.bottom - 1));
+}
+
+#[test]
+fn guide_lines_off_the_chart_are_skipped() {
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 10, bottom: 20, left: 30, right: 40 }, 10., 0., 100, BACKGROUND);
+	let blank = buffer.buffer.clone();
+
+	buffer.hline(10.5, COLOUR, LineStyle::Solid);
+	buffer.hline(-0.5, COLOUR, LineStyle::Dashed);
+	buffer.hline(f64::NAN, COLOUR, LineStyle::Solid);
+	buffer.vline(-1, COLOUR, LineStyle::Solid);
+	buffer.vline(101, COLOUR, LineStyle::Dashed);
+
+	assert!(buffer.buffer == blank);
+}
+
+/// The byte at a time fill `colour_buffer` replaced
+fn per_byte_fill(buffer: &mut Vec<u8>, area: usize, rgba: u32) {
+	for i in 0..area * 3 {
+		buffer.push((rgba >> (24 - 8 * (i % 3))) as u8);
+	}
+}
+
+#[test]
+fn colour_buffer_matches_per_byte_fill() {
+	for &(area, rgba) in [(1310 * 650, 0x36393EFF), (1310 * 650, 0xDDDDDDFF), (1025, 0x12345600), (1, 0xFF0000FF), (0, 0x00FF00FF)].iter() {
+		let mut expected = vec![];
+		per_byte_fill(&mut expected, area, rgba);
+
+		let mut buffer = vec![];
+		ChartBuffer::colour_buffer(&mut buffer, area, rgba);
+
+		assert!(buffer == expected, "{:08X} over {} pixels differs", rgba, area);
+	}
+}
+
+#[test]
+#[ignore]
+fn colour_buffer_is_faster_than_per_byte_fill() {
+	// Timings are only meaningful in release builds, run with `cargo test --release -- --ignored`
+	let time = |fill: fn(&mut Vec<u8>, usize, u32)| {
+		let start_time = SystemTime::now();
+		for _ in 0..20 {
+			let mut buffer = vec![];
+			fill(&mut buffer, 1310 * 650, 0x36393EFF);
+			assert_eq!(buffer.len(), 1310 * 650 * 3);
+		}
+		start_time.elapsed().unwrap()
+	};
+
+	let (per_byte, runs) = (time(per_byte_fill), time(ChartBuffer::colour_buffer));
+	assert!(runs < per_byte, "Filling in runs took {:?}, a byte at a time {:?}", runs, per_byte);
+}
+
+#[test]
+fn wicks_can_have_their_own_colours() {
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 200, BACKGROUND);
+	let data = vec![OHLC { o: 2., h: 10., l: 0., c: 8., t: None, v: None }, OHLC { o: 8., h: 9., l: 1., c: 3., t: None, v: None }];
+
+	OHLCCandles::new(0x00FF00FF, 0xFF0000FF, Some(0x808080FF), None, WickStyle::Full).apply(&mut buffer, &data);
+
+	// Middle of each candle, above and in the body
+	let up = buffer.data_to_coords(9., 40);
+	let down = buffer.data_to_coords(8.5, 140);
+	assert_eq!(pixel(&buffer, up), [0x80; 3]);
+	assert_eq!(pixel(&buffer, buffer.data_to_coords(5., 40)), [0, 0xFF, 0]);
+	assert_eq!(pixel(&buffer, down), [0xFF, 0, 0]);
+	assert_eq!(pixel(&buffer, buffer.data_to_coords(5., 140)), [0xFF, 0, 0]);
+}
+
+#[test]
+fn hollow_bodies_are_outlined() {
+	// Rising, falling below the previous close, then falling but still above the previous close
+	let data = vec![
+		OHLC { o: 2., h: 10., l: 0., c: 8., t: None, v: None },
+		OHLC { o: 8., h: 9., l: 5., c: 6., t: None, v: None },
+		OHLC { o: 7.5, h: 8., l: 5., c: 6.5, t: None, v: None },
+	];
+
+	let filled = |mode: HollowMode| {
+		let mut buffer = ChartBuffer::new(150, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 300, BACKGROUND);
+		OHLCCandles::new(0x00FF00FF, 0xFF0000FF, None, None, WickStyle::Full).hollow(mode).apply(&mut buffer, &data);
+
+		data.iter().enumerate().map(|(i, candle)| {
+			let middle = buffer.data_to_coords((candle.open() + candle.close()) / 2., i as i64 * 100 + 40);
+			let edge = buffer.data_to_coords((candle.open() + candle.close()) / 2., i as i64 * 100);
+			assert_ne!(pixel(&buffer, edge), [0; 3], "candle {} has no outline", i);
+
+			pixel(&buffer, middle) != [0; 3]
+		}).collect::<Vec<bool>>()
+	};
+
+	assert_eq!(filled(HollowMode::Filled), vec![true, true, true]);
+	assert_eq!(filled(HollowMode::OpenClose), vec![false, true, true]);
+	assert_eq!(filled(HollowMode::PrevClose), vec![true, true, false]);
+}
+
+#[test]
+fn chart_area_getters() {
+	let buffer = ChartBuffer::new(200, 100, Margin { top: 10, bottom: 20, left: 30, right: 40 }, 10., 2., 3600, BACKGROUND);
+	let plot = buffer.plot_area();
+
+	assert_eq!((buffer.chart_width(), buffer.chart_height()), (130, 70));
+	assert_eq!((buffer.chart_width() as usize, buffer.chart_height() as usize), (plot.right - plot.left, plot.bottom - plot.top));
+	assert_eq!(buffer.price_range(), (2., 10.));
+	assert_eq!(buffer.time_range(), (0, 3600));
+	assert_eq!(buffer.margin(), Margin { top: 10, bottom: 20, left: 30, right: 40 });
+
+	// The corners of the chart area are the ends of the ranges
+	assert_eq!(buffer.data_to_coords(10., 0), (plot.left, plot.top));
+	assert_eq!(buffer.data_to_coords(2., 3600), (plot.right, plot.bottom));
+}
+
+#[test]
+fn highlight_box_is_clamped_to_the_plot() {
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+
+	// Starts before the chart and goes above it
+	HighlightBox::<OHLC>::new(-50, 50, 2., 20., COLOUR, 0xFF000080).apply(&mut buffer, &[]);
+
+	let (right, bottom) = buffer.data_to_coords(2., 50);
+	assert_eq!(pixel(&buffer, (0, 0)), [0xFF; 3]);
+	assert_eq!(pixel(&buffer, (right, bottom)), [0xFF; 3]);
+	assert_eq!(pixel(&buffer, (right / 2, bottom / 2)), [0x80, 0, 0]);
+	assert_eq!(pixel(&buffer, (right + 1, bottom / 2)), [0; 3]);
+	assert_eq!(pixel(&buffer, (right / 2, bottom + 1)), [0; 3]);
+
+	// Wholly off the chart
+	let blank = buffer.buffer.clone();
+	HighlightBox::<OHLC>::new(150, 200, 2., 8., COLOUR, COLOUR).apply(&mut buffer, &[]);
+	HighlightBox::<OHLC>::new(0, 50, 11., 12., COLOUR, COLOUR).apply(&mut buffer, &[]);
+	assert!(buffer.buffer == blank);
+}
+
+#[test]
+fn antialiased_bodies_cover_the_same_width() {
+	// Slots 17.8 pixels wide, so bodies land at all sorts of sub-pixel offsets
+	let data = vec![OHLC { o: 2., h: 9., l: 1., c: 8., t: None, v: None }; 11];
+	let body_widths = |antialias: bool| {
+		let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 4, right: 0 }, 10., 0., 11 * 100, BACKGROUND);
+		OHLCCandles::new(COLOUR, COLOUR, None, None, WickStyle::None)
+			.antialias(antialias)
+			.apply(&mut buffer, &data);
+
+		// Sums the coverage of each run of lit pixels along a row through the bodies
+		let mut widths = vec![];
+		let mut run = 0.;
+		for x in 0..buffer.width() {
+			let coverage = pixel(&buffer, (x, 50))[0] as f64 / 255.;
+			if coverage > 0. {
+				run += coverage;
+			} else if run > 0. {
+				widths.push(run);
+				run = 0.;
+			}
+		}
+		widths
+	};
+
+	// Whole pixels, but each body is rounded from its own left edge so none is a pixel wider
+	let fast = body_widths(false);
+	assert_eq!(fast.len(), data.len());
+	assert!(fast.iter().all(|w| *w == fast[0] && w.fract() == 0.), "{:?}", fast);
+
+	let smooth = body_widths(true);
+	assert_eq!(smooth.len(), data.len());
+	for width in &smooth {
+		assert!((width - smooth[0]).abs() < 0.01, "{:?}", smooth);
+	}
+}
+
+#[test]
+fn draw_commands_match_drawing_directly() {
+	let commands = vec![
+		DrawCommand::Line { from: (5, 5), to: (60, 40), rgba: 0xFF0000FF },
+		DrawCommand::Polyline { points: vec![(10, 90), (50, 20), (90, 70)], rgba: 0x00FF00FF },
+		DrawCommand::Spline { points: vec![(100, 90), (140, 20), (190, 70)], tension: 0., rgba: 0x0000FFFF },
+		DrawCommand::Rect { from: (120, 10), to: (150, 30), rgba: 0xFFFF007F },
+		DrawCommand::Text { topleft: (20, 60), text: "Text".to_string(), rgba: COLOUR },
+		DrawCommand::Marker { centre: (170, 50), size: 5, rgba: COLOUR },
+	];
+
+	let mut replayed = buffer();
+	replayed.draw_all(&commands);
+
+	let mut direct = buffer();
+	direct.line((5, 5), (60, 40), 0xFF0000FF);
+	direct.polyline(&[(10, 90), (50, 20), (90, 70)], 0x00FF00FF);
+	direct.spline(&[(100, 90), (140, 20), (190, 70)], 0., 0x0000FFFF);
+	direct.rect(120, 10, 150, 30, 0xFFFF007F);
+	direct.text((20, 60), "Text", COLOUR);
+	direct.rect(168, 48, 172, 52, COLOUR);
+
+	assert!(replayed.buffer == direct.buffer);
+}
+
+#[test]
+fn time_lines_align_to_the_start_time() {
+	let lit_columns = |start_time: Option<i64>| {
+		let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+		GridLines::<OHLC>::new(COLOUR, false, 100., 30)
+			.start_time(start_time)
+			.apply(&mut buffer, &[]);
+
+		(0..buffer.width()).filter(|x| pixel(&buffer, (*x, 50)) != [0; 3]).collect::<Vec<_>>()
+	};
+
+	// Counted back from the end of the chart, where the last line is off the image
+	assert_eq!(lit_columns(None), vec![20, 80, 140]);
+	// On multiples of 30 since the epoch, 1000 + 20 being the first
+	assert_eq!(lit_columns(Some(1000)), vec![40, 100, 160]);
+}
+
+#[test]
+fn price_ticks_replace_the_price_interval() {
+	let lit_rows = |grid_lines: GridLines<OHLC>| {
+		let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+		grid_lines.apply(&mut buffer, &[]);
+
+		(0..buffer.height()).filter(|y| pixel(&buffer, (5, *y)) != [0; 3]).collect::<Vec<_>>()
+	};
+
+	assert_eq!(lit_rows(GridLines::new(COLOUR, false, 4., 30)), vec![20, 60]);
+	// Prices off the chart are left off
+	assert_eq!(lit_rows(GridLines::new(COLOUR, false, 4., 30).price_ticks(Arc::new(|_, _| vec![2.5, 5., 20.]))), vec![50, 75]);
+}
+
+#[test]
+fn price_lines_are_capped_at_a_line_per_row() {
+	for interval in &[-4., 1e-12] {
+		let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+		buffer.manifest = Some(vec![]);
+		GridLines::<OHLC>::new(COLOUR, true, *interval, 30).apply(&mut buffer, &[]);
+
+		let labels: Vec<f64> = buffer.manifest.unwrap().iter().filter_map(|element| match *element {
+			DrawnElement::PriceLabel { price, .. } => Some(price),
+			_ => None,
+		}).collect();
+		assert!(labels.len() <= 101, "{} labels every {}", labels.len(), interval);
+	}
+
+	// Too small an interval is widened rather than cut off part way up
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+	buffer.manifest = Some(vec![]);
+	GridLines::<OHLC>::new(COLOUR, true, 0.01, 30).apply(&mut buffer, &[]);
+
+	let labels: Vec<f64> = buffer.manifest.unwrap().iter().filter_map(|element| match *element {
+		DrawnElement::PriceLabel { price, .. } => Some(price),
+		_ => None,
+	}).collect();
+	assert_eq!(labels.len(), 100);
+	assert!((labels[labels.len() - 1] - 10.).abs() < 1e-9, "Lines stop at {}", labels[labels.len() - 1]);
+}
+
+#[test]
+fn grid_lines_are_stroked_in_their_style() {
+	let lit_columns = |style: GridStyle| {
+		let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 0 }, 10., 0., 100, BACKGROUND);
+		GridLines::<OHLC>::new(COLOUR, false, 5., 1000)
+			.style(style)
+			.apply(&mut buffer, &[]);
+
+		// Along the price line at 5, in the middle
+		(0..buffer.width()).filter(|x| pixel(&buffer, (*x, 50)) != [0; 3]).collect::<Vec<_>>()
+	};
+
+	assert_eq!(lit_columns(GridStyle::Solid), (0..200).collect::<Vec<_>>());
+	assert_eq!(lit_columns(GridStyle::Dotted), (0..100).map(|x| x * 2).collect::<Vec<_>>());
+	assert_eq!(lit_columns(GridStyle::Dashed { dash: 3, gap: 2 }).len(), 120);
+}
+
+#[test]
+fn price_lines_can_be_marked_by_ticks() {
+	const TICK: u32 = 0xFF0000FF;
+	let lit_columns = |marks: PriceLineMarks| {
+		let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 40 }, 10., 0., 100, BACKGROUND);
+		GridLines::<OHLC>::new(COLOUR, false, 5., 1000)
+			.marks(marks)
+			.apply(&mut buffer, &[]);
+
+		// Along the price line at 5, the time line at the end of the chart being at 160
+		let columns = (0..buffer.width()).filter(|x| pixel(&buffer, (*x, 50)) != [0; 3]).collect::<Vec<_>>();
+		(columns, pixel(&buffer, (163, 50)))
+	};
+
+	assert_eq!(lit_columns(PriceLineMarks::Lines), ((0..=160).collect(), [0; 3]));
+	assert_eq!(lit_columns(PriceLineMarks::Ticks { length: 6, colour: TICK }), ((160..166).collect(), [0xFF, 0, 0]));
+	assert_eq!(lit_columns(PriceLineMarks::LinesAndTicks { length: 6, colour: TICK }), ((0..166).collect(), [0xFF, 0, 0]));
+
+	// The builders
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 0, bottom: 0, left: 0, right: 40 }, 10., 0., 100, BACKGROUND);
+	GridLines::<OHLC>::new(COLOUR, false, 5., 1000).with_ticks_only(6, TICK).apply(&mut buffer, &[]);
+	assert_eq!(pixel(&buffer, (100, 50)), [0; 3]);
+	assert_eq!(pixel(&buffer, (165, 50)), [0xFF, 0, 0]);
+}
+
+#[test]
+fn downsample_averages_each_square() {
+	let mut buffer = ChartBuffer::new(4, 2, Margin { top: 2, bottom: 0, left: 0, right: 2 }, 1., 0., 1, BACKGROUND);
+	buffer.scale = 2;
+	buffer.colour(0, 0, COLOUR);
+	buffer.colour(2, 0, COLOUR);
+	buffer.colour(3, 1, COLOUR);
+	buffer.downsample();
+
+	assert_eq!((buffer.width(), buffer.height(), buffer.buffer.len()), (2, 1, 6));
+	assert_eq!((buffer.margin.top, buffer.margin.right), (1, 1));
+	assert_eq!(pixel(&buffer, (0, 0)), [64; 3]);
+	assert_eq!(pixel(&buffer, (1, 0)), [128; 3]);
+}
+
+#[test]
+fn last_candle_coords_are_on_the_close_line() {
+	let data = vec![
+		OHLC { o: 2., h: 9., l: 1., c: 3., t: None, v: None },
+		OHLC { o: 3., h: 9., l: 1., c: 6., t: None, v: None },
+		OHLC { o: 6., h: 9., l: 1., c: 7., t: None, v: None },
+	];
+	let mut buffer = ChartBuffer::new(200, 100, Margin { top: 10, bottom: 10, left: 10, right: 10 }, 10., 0., 300, BACKGROUND);
+	OHLCCandles::new(COLOUR, COLOUR, None, None, WickStyle::None)
+		.style(CandleStyle::Line, 0)
+		.apply(&mut buffer, &data);
+
+	let last = buffer.last_candle_coords(&data).unwrap();
+	assert_eq!(last, buffer.data_to_coords(7., 240));
+	assert_eq!(pixel(&buffer, last), [255; 3]);
+	assert_eq!(buffer.last_candle_coords::<OHLC>(&[]), None);
+}
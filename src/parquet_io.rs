@@ -0,0 +1,111 @@
+//! Reading and writing candles as Parquet files, needs the `parquet` feature
+//!
+//! The schema has the columns `timestamp: int64`, `open: float64`, `high: float64`, `low: float64`, `close: float64` and `volume: float64`.
+//! Timestamps and volumes are null for candles that don't have them.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use parquet::data_type::{DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::writer::SerializedFileWriter;
+use parquet::record::Field;
+use parquet::schema::parser::parse_message_type;
+
+use OHLCError;
+use model::data::OHLC;
+
+const SCHEMA: &str = "
+	message ohlc {
+		OPTIONAL INT64 timestamp;
+		REQUIRED DOUBLE open;
+		REQUIRED DOUBLE high;
+		REQUIRED DOUBLE low;
+		REQUIRED DOUBLE close;
+		OPTIONAL DOUBLE volume;
+	}
+";
+
+fn parquet_error<E: ::std::fmt::Display>(err: E) -> OHLCError {
+	OHLCError::Parquet(err.to_string())
+}
+
+/// Writes the candles to a new Parquet file at the path, in a single row group
+pub fn write_parquet(data: &[OHLC], path: &Path) -> Result<(), OHLCError> {
+	let schema = Arc::new(parse_message_type(SCHEMA).map_err(parquet_error)?);
+	let file = File::create(path).map_err(parquet_error)?;
+	let mut writer = SerializedFileWriter::new(file, schema, Arc::new(WriterProperties::builder().build())).map_err(parquet_error)?;
+
+	{
+		let mut row_group = writer.next_row_group().map_err(parquet_error)?;
+
+		// Nullable columns only hold the values that are present, with a definition level of 1 for those and 0 for nulls
+		let timestamps: Vec<i64> = data.iter().filter_map(|candle| candle.t).collect();
+		let timestamp_levels: Vec<i16> = data.iter().map(|candle| candle.t.is_some() as i16).collect();
+		let volumes: Vec<f64> = data.iter().filter_map(|candle| candle.v).collect();
+		let volume_levels: Vec<i16> = data.iter().map(|candle| candle.v.is_some() as i16).collect();
+
+		if let Some(mut column) = row_group.next_column().map_err(parquet_error)? {
+			column.typed::<Int64Type>().write_batch(&timestamps, Some(&timestamp_levels), None).map_err(parquet_error)?;
+			column.close().map_err(parquet_error)?;
+		}
+
+		let prices: [fn(&OHLC) -> f64; 4] = [|c| c.o, |c| c.h, |c| c.l, |c| c.c];
+		for price in prices.iter() {
+			let values: Vec<f64> = data.iter().map(price).collect();
+
+			if let Some(mut column) = row_group.next_column().map_err(parquet_error)? {
+				column.typed::<DoubleType>().write_batch(&values, None, None).map_err(parquet_error)?;
+				column.close().map_err(parquet_error)?;
+			}
+		}
+
+		if let Some(mut column) = row_group.next_column().map_err(parquet_error)? {
+			column.typed::<DoubleType>().write_batch(&volumes, Some(&volume_levels), None).map_err(parquet_error)?;
+			column.close().map_err(parquet_error)?;
+		}
+
+		row_group.close().map_err(parquet_error)?;
+	}
+
+	writer.close().map_err(parquet_error)?;
+
+	Ok(())
+}
+
+/// Reads candles from a Parquet file with the same columns as `write_parquet` writes, in any order
+pub fn read_parquet(path: &Path) -> Result<Vec<OHLC>, OHLCError> {
+	let file = File::open(path).map_err(parquet_error)?;
+	let reader = SerializedFileReader::new(file).map_err(parquet_error)?;
+
+	let mut data = vec![];
+
+	for row in reader.get_row_iter(None).map_err(parquet_error)? {
+		let row = row.map_err(parquet_error)?;
+		let mut candle = OHLC::default();
+		let mut found = 0;
+
+		for (name, field) in row.get_column_iter() {
+			match (name.as_str(), field) {
+				("timestamp", &Field::Long(t)) => candle.t = Some(t),
+				("volume", &Field::Double(v)) => candle.v = Some(v),
+				("timestamp", &Field::Null) | ("volume", &Field::Null) => {}
+				("open", &Field::Double(o)) => { candle.o = o; found += 1; }
+				("high", &Field::Double(h)) => { candle.h = h; found += 1; }
+				("low", &Field::Double(l)) => { candle.l = l; found += 1; }
+				("close", &Field::Double(c)) => { candle.c = c; found += 1; }
+				(name, field) => return Err(OHLCError::Parquet(format!("Unexpected value {} in column {}", field, name))),
+			}
+		}
+
+		if found < 4 {
+			return Err(OHLCError::Parquet(format!("Row {} is missing some of the open, high, low and close", data.len())));
+		}
+
+		data.push(candle);
+	}
+
+	Ok(data)
+}
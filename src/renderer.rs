@@ -0,0 +1,61 @@
+use {Candle, OHLCError, OHLCRenderOptions, RenderStats};
+use model::Painter;
+
+/// Renders charts into a pixel buffer that's kept between renders, for drawing the same chart over and over without allocating it each time
+///
+/// The buffer grows to fit if the options make the chart bigger, such as by adding an extension with its own strip.
+pub struct Renderer<C: Candle> {
+	options: OHLCRenderOptions<C>,
+	pixels: Vec<u8>,
+	width: u32,
+	height: u32,
+}
+
+impl<C: Candle> Renderer<C> {
+	pub fn new(options: OHLCRenderOptions<C>) -> Renderer<C> {
+		Renderer { options, pixels: vec![], width: 0, height: 0 }
+	}
+
+	pub fn options(&self) -> &OHLCRenderOptions<C> {
+		&self.options
+	}
+
+	/// Changes the options for the next render
+	pub fn options_mut(&mut self) -> &mut OHLCRenderOptions<C> {
+		&mut self.options
+	}
+
+	pub fn into_options(self) -> OHLCRenderOptions<C> {
+		self.options
+	}
+
+	/// Renders the chart over the last one, returning its raw RGB(8) pixels
+	///
+	/// The last chart is kept if the render fails.
+	pub fn render(&mut self, data: &[C]) -> Result<&[u8], OHLCError> {
+		let mut stats = RenderStats::default();
+
+		match self.options.render_into(data, &mut self.pixels, &mut stats) {
+			Ok(chart_buffer) => {
+				stats.log();
+
+				self.width = chart_buffer.width() as u32;
+				self.height = chart_buffer.height() as u32;
+				self.pixels = chart_buffer.buffer;
+
+				Ok(&self.pixels[..])
+			}
+			Err(err) => Err(err),
+		}
+	}
+
+	/// Pixels of the last chart rendered, empty if there hasn't been one
+	pub fn pixels(&self) -> &[u8] {
+		&self.pixels[..]
+	}
+
+	/// Returns: (width, height) of the last chart rendered
+	pub fn dimensions(&self) -> (u32, u32) {
+		(self.width, self.height)
+	}
+}
@@ -0,0 +1,158 @@
+//! Pixel level regression tests, each render is compared against a golden image in `tests/snapshots`.
+//! Every built-in extension is also pinned by the digest of its render in `tests/snapshots/digests.txt`.
+//!
+//! After an intentional visual change, regenerate the golden images and digests with
+//! `OHLC_UPDATE_SNAPSHOTS=1 cargo test snapshot` and review the new images before committing them.
+
+extern crate serde_json;
+
+use std::env;
+use std::fs;
+
+use model::data::OHLC;
+use model::painting::LineStyle;
+use testing::{diff_images_within, render_digest};
+
+use super::*;
+
+/// Maximum difference allowed in any channel of a pixel before it counts as changed
+const CHANNEL_TOLERANCE: u8 = 2;
+
+fn snapshot_data() -> Vec<OHLC> {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	data[..48].to_vec()
+}
+
+fn snapshot_options() -> OHLCRenderOptions<OHLC> {
+	let mut options = OHLCRenderOptions::new();
+	options.title("ohlc-rs snapshot", 0x007F7FFF)
+		.line(0xCCCCCCFF, 100., 12)
+		.background_colour(0x36393EFF);
+
+	options
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join(format!("{}.png", name))
+}
+
+/// Regenerates the golden image when `OHLC_UPDATE_SNAPSHOTS` is set, otherwise compares the render against it
+fn assert_snapshot(name: &str, options: &OHLCRenderOptions<OHLC>) {
+	let buffer = options.render_buffer(snapshot_data()).unwrap();
+	let (width, height) = (buffer.width() as u32, buffer.height() as u32);
+	let path = snapshot_path(name);
+
+	if env::var_os("OHLC_UPDATE_SNAPSHOTS").is_some() {
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		image::save_buffer(&path, &buffer.buffer[..], width, height, image::RGB(8)).unwrap();
+		return;
+	}
+
+	let golden = image::open(&path)
+		.unwrap_or_else(|err| panic!("Missing golden image {:?} ({:?}), regenerate with OHLC_UPDATE_SNAPSHOTS=1", path, err))
+		.to_rgb();
+
+	assert_eq!((golden.width(), golden.height()), (width, height), "{} changed dimensions", name);
+
+	let diff = diff_images_within(&golden.into_raw(), &buffer.buffer, width, height, CHANNEL_TOLERANCE);
+
+	assert!(diff.is_identical(), "{} pixels of {} differ from the golden image, within {:?}", diff.differing_pixels, name, diff.bounds);
+}
+
+#[test]
+fn snapshot_default() {
+	assert_snapshot("default", &snapshot_options());
+}
+
+#[test]
+fn snapshot_overlays() {
+	let mut options = snapshot_options();
+	options.add_extension(BollingerBands::new(20, 2, 0xFF0000FF))
+		.add_extension(EMA::new(10, 0.1, 0x00FFFFFF));
+
+	assert_snapshot("overlays", &options);
+}
+
+#[test]
+fn snapshot_strips() {
+	let mut options = snapshot_options();
+	options.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF))
+		.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1));
+
+	assert_snapshot("strips", &options);
+}
+
+fn digests_path() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join("digests.txt")
+}
+
+/// The charts pinned by digest, the candles on their own and then each built-in extension on the default chart
+fn digest_charts() -> Vec<(&'static str, OHLCRenderOptions<OHLC>)> {
+	let with = |extension: Box<dyn RendererExtension<Candle=OHLC>>| {
+		let mut options = snapshot_options();
+		options.render_extensions.push(extension);
+
+		options
+	};
+
+	let mut candles_only = OHLCRenderOptions::new();
+	candles_only.line(0x00000000, 100., 12)
+		.background_colour(0x36393EFF);
+
+	vec![
+		("default", snapshot_options()),
+		("candles_only", candles_only),
+		("annotation", with(Box::new(Annotation::new((20 * 3600, 6600.), "Note", 0xFFFFFFFF, ArrowDirection::Down)))),
+		("arrow", with(Box::new(ArrowAnnotation::new(10, ArrowDirection::Up, 0x27A819FF, "Buy")))),
+		("bollinger_bands", with(Box::new(BollingerBands::new(20, 2, 0xFF0000FF)))),
+		("border", with(Box::new(ImageBorder::new(8, 0xFFFFFFFF)))),
+		("correlation_overlay", with(Box::new(CorrelationOverlay::new("Self", snapshot_data(), CornerPosition::TopRight, 0xFFFFFFFF).max_lag(2)))),
+		("dema", with(Box::new(DEMA::new(EMA::new(10, 0.1, 0xFF7F00FF))))),
+		("ema", with(Box::new(EMA::new(10, 0.1, 0x00FFFFFF)))),
+		("gradient_candles", with(Box::new(GradientCandles::new(0x27A819FF, 0xD33040FF, 0.8)))),
+		("highlight_box", with(Box::new(HighlightBox::new(10 * 3600, 20 * 3600, 6000., 6400., 0xFFCC00FF, 0xFFCC0030)))),
+		("macd", with(Box::new(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1)))),
+		("momentum", with(Box::new(Momentum::new(10, 0x27A819FF, 0xD33040FF)))),
+		("price_alert", with(Box::new(PriceAlert::new(6500., "Take profit", 0x27A819FF, LineStyle::Dashed)))),
+		("rsi", with(Box::new(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF)))),
+		("sd_bands", with(Box::new(StandardDeviationBands::new(&[0., 1., 2.], 0xFF7F00FF)))),
+		("stats_summary", with(Box::new(StatsSummary::new(CornerPosition::BottomRight, 0xFFFFFFFF)))),
+		("trend_line", with(Box::new(TrendLine::new((5 * 3600, 6700.), (40 * 3600, 5800.), 0xFFFF00FF, true)))),
+		("volume", with(Box::new(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF)))),
+	]
+}
+
+#[test]
+fn snapshot_digests() {
+	let digests: Vec<String> = digest_charts().iter()
+		.map(|&(name, ref options)| format!("{} {:016x}", name, render_digest(options, &snapshot_data()).unwrap()))
+		.collect();
+	let path = digests_path();
+
+	if env::var_os("OHLC_UPDATE_SNAPSHOTS").is_some() {
+		fs::write(&path, digests.join("\n") + "\n").unwrap();
+		return;
+	}
+
+	let golden = fs::read_to_string(&path)
+		.unwrap_or_else(|err| panic!("Missing digests {:?} ({:?}), regenerate with OHLC_UPDATE_SNAPSHOTS=1", path, err));
+	let changed: Vec<&String> = digests.iter().filter(|digest| !golden.lines().any(|line| line == digest.as_str())).collect();
+
+	assert!(changed.is_empty(), "Renders no longer match their digests: {:?}", changed);
+}
+
+#[test]
+fn charts_of_one_or_two_candles_render_with_every_extension() {
+	for candles in 1..3 {
+		let data: Vec<OHLC> = snapshot_data().into_iter().take(candles).collect();
+
+		for (name, options) in digest_charts() {
+			let (png, stats) = options.render_to_png_with_stats(data.clone())
+				.unwrap_or_else(|err| panic!("{} with {} candles failed: {}", name, candles, err));
+
+			assert!(!png.is_empty());
+			assert_eq!(stats.candle_count, candles);
+		}
+	}
+}
@@ -0,0 +1,121 @@
+use std::time::{Duration, Instant};
+
+/// How long each part of a render took and what it produced, for monitoring rendering
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+	/// Checking the data
+	pub validation: Duration,
+	/// Allocating the image and filling in the backgrounds
+	pub background: Duration,
+	/// Drawing the grid lines and their labels
+	pub grid: Duration,
+	/// Drawing the candles
+	pub candles: Duration,
+	/// Drawing the indicative lines, title, subtitle and axis caption
+	pub labels: Duration,
+	/// Each extension drawn, in the order they were drawn, along with its lore
+	pub extensions: Vec<(String, Duration)>,
+	/// Scaling down from supersampling, the frame and the colour filters
+	pub finishing: Duration,
+	/// Encoding the image, zero if it wasn't encoded
+	pub encode: Duration,
+	/// Number of candles rendered
+	pub candle_count: usize,
+	/// Invalid candles repaired by `ValidationMode::Clamp`
+	pub repaired: usize,
+	/// Invalid candles left off by `ValidationMode::Skip`
+	pub skipped: usize,
+	/// (min, max) prices of the chart area
+	pub price_range: (f64, f64),
+	/// Width of the finished image in pixels, after scaling down from supersampling
+	pub width: u32,
+	/// Height of the finished image in pixels, including any extension strips
+	pub height: u32,
+	/// Size of the encoded image, zero if it wasn't encoded
+	pub bytes: usize,
+}
+
+impl RenderStats {
+	/// Time taken by all the parts together
+	pub fn total(&self) -> Duration {
+		self.extensions.iter().fold(
+			self.validation + self.background + self.grid + self.candles + self.labels + self.finishing + self.encode,
+			|total, &(_, duration)| total + duration,
+		)
+	}
+
+	#[cfg(feature = "tracing")]
+	pub(crate) fn log(&self) {
+		// Timings of the parts are on their spans, the total is for a histogram of render times
+		::tracing::info!(
+			render_duration_us = self.total().as_micros() as u64,
+			candles = self.candle_count,
+			bytes = self.bytes,
+			"Chart rendered"
+		);
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	pub(crate) fn log(&self) {
+		debug!("Validated {} candles in {:?}", self.candle_count, self.validation);
+		debug!("Allocated image and populated background in {:?}", self.background);
+		debug!("Rendered grid lines in {:?}", self.grid);
+		debug!("Rendered candles in {:?}", self.candles);
+		debug!("Rendered basic indicator lines and text in {:?}", self.labels);
+		for &(ref name, duration) in &self.extensions {
+			debug!("Rendered extension: {} in {:?}", name, duration);
+		}
+		debug!("Finished the image in {:?}", self.finishing);
+		if self.bytes > 0 {
+			debug!("Encoded {} bytes in {:?}", self.bytes, self.encode);
+		}
+
+		debug!("Chart rendered in {:?}", self.total());
+	}
+}
+
+/// A `tracing` span for a part of the render, nothing without the `tracing` feature
+#[cfg(feature = "tracing")]
+pub(crate) type Span = ::tracing::Span;
+#[cfg(not(feature = "tracing"))]
+pub(crate) type Span = ();
+
+/// Times the parts of a render one after another, keeping each one's span entered while it runs
+pub(crate) struct Phase {
+	start: Instant,
+	#[cfg(feature = "tracing")]
+	entered: Option<::tracing::span::EnteredSpan>,
+}
+
+impl Phase {
+	#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+	pub(crate) fn start(span: Span) -> Phase {
+		Phase {
+			start: Instant::now(),
+			#[cfg(feature = "tracing")]
+			entered: Some(span.entered()),
+		}
+	}
+
+	/// Ends the current part, returning how long it took, and starts the next in the span
+	///
+	/// The span is only made once the current one is exited so that it isn't nested inside it.
+	#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+	pub(crate) fn next<F: FnOnce() -> Span>(&mut self, span: F) -> Duration {
+		let elapsed = self.start.elapsed();
+
+		#[cfg(feature = "tracing")] {
+			self.entered = None;
+			self.entered = Some(span().entered());
+		}
+
+		self.start = Instant::now();
+
+		elapsed
+	}
+
+	/// Ends the last part, returning how long it took
+	pub(crate) fn end(self) -> Duration {
+		self.start.elapsed()
+	}
+}
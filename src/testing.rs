@@ -0,0 +1,73 @@
+//! Helpers for pinning the output of charts in regression tests, such as checking a render still hashes to the digest it had when it was last reviewed.
+
+use {Candle, OHLCError, OHLCRenderOptions};
+use model::{Painter, Rect};
+
+/// Hash of the pixels and dimensions of the chart, the same on every run and build as long as the chart looks exactly the same
+///
+/// Nothing is written to disk and nothing that changes between runs, like the time, is drawn. Floating point functions such as `powf` can round differently between platforms, so pin digests per platform or compare images with a tolerance where that matters.
+pub fn render_digest<C: Candle + Clone>(options: &OHLCRenderOptions<C>, data: &[C]) -> Result<u64, OHLCError> {
+	let buffer = options.render_buffer(data.to_vec())?;
+
+	Ok(digest(buffer.width() as u32, buffer.height() as u32, &buffer.buffer))
+}
+
+/// 64 bit FNV-1a of the dimensions and the pixels, which unlike `DefaultHasher` doesn't change between Rust versions
+fn digest(width: u32, height: u32, pixels: &[u8]) -> u64 {
+	const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+	const PRIME: u64 = 0x0100_0000_01B3;
+
+	let mut hash = OFFSET_BASIS;
+	for byte in width.to_le_bytes().iter().chain(height.to_le_bytes().iter()).chain(pixels) {
+		hash ^= *byte as u64;
+		hash = hash.wrapping_mul(PRIME);
+	}
+
+	hash
+}
+
+/// How two images of the same size differ
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiffReport {
+	/// Number of pixels that differ in any channel
+	pub differing_pixels: usize,
+	/// Smallest area holding every pixel that differs, `None` if the images are the same
+	pub bounds: Option<Rect>,
+}
+
+impl DiffReport {
+	pub fn is_identical(&self) -> bool {
+		self.differing_pixels == 0
+	}
+}
+
+/// Compares two RGB(8) images of the given size pixel by pixel
+pub fn diff_images(a: &[u8], b: &[u8], width: u32, height: u32) -> DiffReport {
+	diff_images_within(a, b, width, height, 0)
+}
+
+/// Same as `diff_images`, only counting pixels where a channel differs by more than the tolerance, eg. to allow for rounding between platforms
+///
+/// Panics if either image isn't `width * height` RGB(8) pixels.
+pub fn diff_images_within(a: &[u8], b: &[u8], width: u32, height: u32, tolerance: u8) -> DiffReport {
+	let (width, height) = (width as usize, height as usize);
+	assert_eq!(a.len(), width * height * 3, "first image isn't {}x{} RGB(8) pixels", width, height);
+	assert_eq!(b.len(), width * height * 3, "second image isn't {}x{} RGB(8) pixels", width, height);
+
+	let mut report = DiffReport { differing_pixels: 0, bounds: None };
+
+	for (i, (a, b)) in a.chunks(3).zip(b.chunks(3)).enumerate() {
+		if !a.iter().zip(b).any(|(a, b)| (*a as i16 - *b as i16).abs() > tolerance as i16) {
+			continue;
+		}
+
+		let (x, y) = (i % width, i / width);
+		report.differing_pixels += 1;
+		report.bounds = Some(match report.bounds {
+			Some(bounds) => Rect { left: bounds.left.min(x), top: bounds.top.min(y), right: bounds.right.max(x), bottom: bounds.bottom.max(y) },
+			None => Rect { left: x, top: y, right: x, bottom: y },
+		});
+	}
+
+	report
+}
@@ -1,7 +1,9 @@
 extern crate env_logger;
 extern crate serde_json;
 
-use serde::export::PhantomData;
+use std::marker::PhantomData;
+
+use image::GenericImage;
 
 use model::data::OHLC;
 use model::rex::*;
@@ -12,6 +14,14 @@ use model::rex::test_text::TestText;
 use super::*;
 
 fn draw_with_extension<T: RendererExtension<Candle=OHLC> + 'static>(ext: Option<T>, suffix: &str) {
+	draw_with_options(|options| {
+		if let Some(ext) = ext {
+			options.add_extension(ext);
+		}
+	}, suffix);
+}
+
+fn draw_with_options<F: FnOnce(&mut OHLCRenderOptions<OHLC>)>(f: F, suffix: &str) {
 	let _ = env_logger::try_init();
 
 	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
@@ -22,9 +32,7 @@ fn draw_with_extension<T: RendererExtension<Candle=OHLC> + 'static>(ext: Option<
 			.line(0xCCCCCCFF, 200., 24)
 			.background_colour(0x36393EFF);
 
-		if let Some(ext) = ext {
-			options.add_extension(ext);
-		}
+		(f)(&mut options);
 
 		options.render_and_save(
 			data.clone(),
@@ -53,11 +61,45 @@ fn render_draw_sample_data_plus_ema() {
 	draw_with_extension(Some(EMA::new(20, 0.1, 0xFF0000FF)), "+ema");
 }
 
+#[test]
+fn render_draw_sample_data_plus_smooth_ema() {
+	draw_with_extension(Some(EMA::new(20, 0.1, 0xFF0000FF).smooth(Some(0.))), "+smooth_ema");
+}
+
+#[test]
+fn ema_tension_changes_the_curve() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let render = |tension: Option<f64>| {
+		let mut options = OHLCRenderOptions::new();
+		options.add_extension(EMA::new(20, 0.1, 0xFF0000FF).smooth(tension));
+		options.render_buffer(data.clone()).unwrap().buffer
+	};
+
+	let (round, tight) = (render(Some(0.)), render(Some(0.5)));
+	assert!(round != tight);
+	assert!(tight != render(None));
+}
+
 #[test]
 fn render_draw_sample_data_plus_macd() {
 	draw_with_extension(Some(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1)), "+macd");
 }
 
+#[test]
+fn render_draw_sample_data_plus_macd_gradient() {
+	let style = MACDHistogramStyle::Gradient { positive_growing: 0x26A69AFF, positive_shrinking: 0xB2DFDBFF, negative_growing: 0xFF5252FF, negative_shrinking: 0xFFCDD2FF };
+	draw_with_extension(Some(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1).histogram_style(style)), "+macd_gradient");
+}
+
+#[test]
+fn macd_histogram_gradient_colours_by_direction() {
+	let style = MACDHistogramStyle::Gradient { positive_growing: 1, positive_shrinking: 2, negative_growing: 3, negative_shrinking: 4 };
+	let histogram = [1., 2., 1.5, -0.5, -1., -0.8, 0.2];
+
+	assert_eq!(MACD::<OHLC>::new(0, 0, 9, 0, 0.1).histogram_colours(&histogram), vec![9; 7]);
+	assert_eq!(MACD::<OHLC>::new(0, 0, 9, 0, 0.1).histogram_style(style).histogram_colours(&histogram), vec![1, 1, 2, 3, 3, 4, 1]);
+}
+
 #[test]
 fn render_draw_sample_data_plus_rsi() {
 	draw_with_extension(Some(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF)), "+rsi");
@@ -65,7 +107,42 @@ fn render_draw_sample_data_plus_rsi() {
 
 #[test]
 fn render_draw_sample_data_plus_volume() {
-	draw_with_extension(Some(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF)), "+volume");
+	draw_with_extension(Some(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF)), "+volume");
+}
+
+/// Sample data lacks volume, this gives it quiet volumes with a few spikes a thousand times bigger
+fn draw_with_volume(volume: Volume<OHLC>, suffix: &str) {
+	let mut data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	for (i, candle) in data.iter_mut().enumerate() {
+		candle.v = Some(if i % 40 == 20 { 100_000. } else { 50. + (i % 7) as f64 * 10. });
+	}
+
+	let mut options = OHLCRenderOptions::new();
+	options.title(&format!("BTCUSD | ohlc-rs{}", suffix), 0x007F7FFF)
+		.line(0xCCCCCCFF, 200., 24)
+		.background_colour(0x36393EFF)
+		.add_extension(volume);
+
+	options.render_and_save(data, Path::new(&format!("test-draw-sample-data{}.png", suffix))).unwrap();
+}
+
+#[test]
+fn render_draw_sample_data_plus_log_volume() {
+	draw_with_volume(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF).scale(VolumeScale::Log), "+log_volume");
+}
+
+#[test]
+fn render_draw_sample_data_plus_average_volume() {
+	draw_with_volume(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF).scale(VolumeScale::Average), "+average_volume");
+}
+
+#[test]
+fn render_draw_sample_data_plus_direction_volume() {
+	let volume = Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF)
+		.scale(VolumeScale::Log)
+		.direction_colours(0x27A819FF, 0xD33040FF, 0x7F7F7FFF, ColourMode::OpenClose);
+
+	draw_with_volume(volume, "+direction_volume");
 }
 
 #[test]
@@ -87,3 +164,1449 @@ fn render_draw_sample_data_with_test_fill_alpha() {
 fn render_draw_sample_data_with_test_line() {
 	draw_with_extension(Some(TestLine(PhantomData)), "_with_test_line");
 }
+
+#[test]
+fn render_draw_sample_data_with_plot_frame() {
+	draw_with_options(|options| { options.frame(FrameKind::Plot, 0xCCCCCCFF, 2); }, "_with_plot_frame");
+}
+
+#[test]
+fn render_draw_sample_data_with_canvas_frame() {
+	draw_with_options(|options| {
+		options.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF))
+			.frame(FrameKind::Canvas, 0x007F7FFF, 4);
+	}, "_with_canvas_frame");
+}
+
+#[test]
+fn render_draw_sample_data_inverted() {
+	draw_with_options(|options| { options.invert_colours(true); }, "_inverted");
+}
+
+#[test]
+fn render_draw_sample_data_grayscale() {
+	draw_with_options(|options| { options.grayscale(true); }, "_grayscale");
+}
+
+#[test]
+fn render_draw_sample_data_with_background_gradient() {
+	draw_with_options(|options| { options.background_gradient(0x36393EFF, 0x101216FF); }, "_with_background_gradient");
+}
+
+#[test]
+fn render_draw_sample_data_with_border() {
+	draw_with_options(|options| {
+		options.add_extension(ImageBorder::new(12, 0xFFFFFFFF))
+			.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1));
+	}, "_with_border");
+}
+
+#[test]
+fn render_draw_sample_data_with_axis_caption() {
+	draw_with_options(|options| { options.axis_caption("Price, USD", 0xCCCCCCFF); }, "_with_axis_caption");
+}
+
+#[test]
+fn disabled_extensions_are_skipped() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	let plain = options.render_buffer(data.clone()).unwrap().buffer;
+
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF))
+		.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF));
+	assert_eq!(options.extension_names(), vec!["EMA(20, sf=0.1)".to_string(), "RSI(10)".to_string()]);
+
+	options.set_extension_enabled("EMA(20, sf=0.1)", false)
+		.set_extension_enabled("RSI(10)", false);
+	assert!(!options.is_extension_enabled("RSI(10)"));
+	assert!(options.render_buffer(data.clone()).unwrap().buffer == plain);
+
+	options.set_extension_enabled("RSI(10)", true);
+	assert!(options.render_buffer(data.clone()).unwrap().buffer.len() > plain.len());
+}
+
+#[test]
+fn extensions_can_be_removed() {
+	let mut options: OHLCRenderOptions<OHLC> = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF))
+		.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF))
+		.add_extension(EMA::new(20, 0.1, 0x00FF00FF));
+	assert_eq!(options.extensions_count(), 3);
+
+	assert!(options.remove_extension_by_name("EMA(20, sf=0.1)"));
+	assert!(!options.remove_extension_by_name("EMA(50, sf=0.1)"));
+	assert_eq!(options.extension_names(), vec!["RSI(10)".to_string(), "EMA(20, sf=0.1)".to_string()]);
+
+	options.clear_extensions();
+	assert_eq!(options.extensions_count(), 0);
+}
+
+#[test]
+fn renderer_reuses_its_buffer() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1));
+	let expected = options.render_buffer(data.clone()).unwrap();
+
+	let mut renderer = Renderer::new(options);
+	let first = renderer.render(&data).unwrap().as_ptr();
+	let second = renderer.render(&data).unwrap().as_ptr();
+
+	assert_eq!(first, second);
+	assert!(renderer.pixels() == &expected.buffer[..]);
+	assert_eq!(renderer.dimensions(), (expected.width() as u32, expected.height() as u32));
+
+	// Dropping the MACD's strip makes the chart shorter
+	renderer.options_mut().clear_extensions();
+	let expected = renderer.options().render_buffer(data.clone()).unwrap();
+
+	assert!(renderer.render(&data).unwrap() == &expected.buffer[..]);
+	assert_eq!(renderer.dimensions(), (expected.width() as u32, expected.height() as u32));
+
+	// A failed render leaves the last chart and its allocation
+	let last = renderer.pixels().as_ptr();
+	assert_eq!(renderer.render(&[]).err(), Some(OHLCError::EmptyData));
+	assert!(renderer.pixels() == &expected.buffer[..]);
+	assert_eq!(renderer.pixels().as_ptr(), last);
+	assert_eq!(renderer.dimensions(), (expected.width() as u32, expected.height() as u32));
+}
+
+#[test]
+fn candles_and_extensions_need_not_be_sync() {
+	// Neither can be shared between threads, with or without the rayon feature
+	struct RcCandle {
+		ohlc: OHLC,
+		_rc: ::std::rc::Rc<()>,
+	}
+
+	impl Candle for RcCandle {
+		fn open(&self) -> f64 { self.ohlc.o }
+		fn high(&self) -> f64 { self.ohlc.h }
+		fn low(&self) -> f64 { self.ohlc.l }
+		fn close(&self) -> f64 { self.ohlc.c }
+		fn buy_volume(&self) -> Option<f64> { None }
+		fn total_volume(&self) -> f64 { 0. }
+	}
+
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF));
+	let expected = options.render_buffer(data.clone()).unwrap();
+
+	let mut shared = OHLCRenderOptions::new();
+	shared.add_extension(EMA::new(20, 0.1, 0xFF0000FF));
+	let data: Vec<RcCandle> = data.into_iter().map(|ohlc| RcCandle { ohlc, _rc: ::std::rc::Rc::new(()) }).collect();
+	assert!(shared.render_buffer(data).unwrap().buffer == expected.buffer);
+}
+
+#[test]
+fn render_to_image_matches_buffer() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1));
+
+	let image = options.render_to_image(data.clone()).unwrap();
+	let buffer = options.render_buffer(data).unwrap();
+
+	assert_eq!((image.width(), image.height()), (buffer.width() as u32, buffer.height() as u32));
+	assert!(image.to_rgb().into_raw() == buffer.buffer);
+}
+
+#[test]
+fn render_passes_callback_result_out() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let mut paths = vec![];
+
+	let bytes = OHLCRenderOptions::new().render(data.clone(), |path| {
+		paths.push(path.to_path_buf());
+		::std::fs::read(path)
+	}).unwrap();
+
+	assert_eq!(&bytes[1..4], b"PNG");
+	assert!(!paths[0].exists());
+
+	let err = OHLCRenderOptions::new().render(data, |_| Err::<(), _>("upload failed")).err().unwrap();
+	assert_eq!(err, OHLCError::Callback("upload failed".to_string()));
+}
+
+#[test]
+fn overlapping_renders_of_the_same_chart_use_separate_files() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let options = OHLCRenderOptions::new();
+
+	// The outer chart's file is still in use while the same chart is rendered again
+	let (outer, inner) = options.render(data.clone(), |outer| {
+		options.render(data.clone(), |inner| Ok::<_, String>(inner.to_path_buf()))
+			.map(|inner| (outer.to_path_buf(), inner))
+	}).unwrap();
+
+	assert_ne!(outer, inner);
+}
+
+#[test]
+fn concurrent_renders_of_the_same_chart_succeed() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let renders: Vec<_> = (0..4).map(|_| {
+		let data = data.clone();
+		::std::thread::spawn(move || {
+			OHLCRenderOptions::new().render(data, |path| fs::read(path))
+		})
+	}).collect();
+
+	let pngs: Vec<Vec<u8>> = renders.into_iter().map(|render| render.join().unwrap().unwrap()).collect();
+	assert!(pngs.iter().all(|png| !png.is_empty() && *png == pngs[0]));
+}
+
+#[test]
+fn extensions_without_enough_candles_are_skipped() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let data = data[..12].to_vec();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(BollingerBands::new(20, 2, 0xFF00FFFF))
+		.add_extension(RSI::new(0xFFFFFFFF, 0xFF0000FF, 0x00FF00FF, 0x0000FFFF))
+		.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1))
+		.add_extension(EMA::new(20, 0.1, 0xFF7F00FF));
+
+	// The RSI strip is the only one drawn
+	let with_strip = options.render_buffer(data.clone()).unwrap();
+	assert_eq!(with_strip.height(), 650 + 175);
+
+	let mut without_rsi = OHLCRenderOptions::new();
+	without_rsi.add_extension(BollingerBands::new(20, 2, 0xFF00FFFF))
+		.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1))
+		.add_extension(EMA::new(20, 0.1, 0xFF7F00FF));
+	let plain = OHLCRenderOptions::new().render_buffer(data.clone()).unwrap();
+
+	assert!(without_rsi.render_buffer(data).unwrap().buffer == plain.buffer);
+}
+
+#[test]
+fn png_compression_levels_decode_to_the_same_pixels() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(MACD::new(0xFF007FFF, 0xFFFFFFFF, 0x00FFFFFF, 0xFF0000FF, 0.1));
+	let expected = options.render_buffer(data.clone()).unwrap();
+
+	for level in [CompressionLevel::Fast, CompressionLevel::Default, CompressionLevel::Best].iter() {
+		let (png, stats) = options.png_compression(*level).render_to_png_with_stats(data.clone()).unwrap();
+		println!("{:?}: {} bytes in {:?}", level, stats.bytes, stats.encode);
+
+		let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::PNG).unwrap().to_rgb();
+
+		assert_eq!((decoded.width(), decoded.height()), (expected.width() as u32, expected.height() as u32));
+		assert!(decoded.into_raw() == expected.buffer, "{:?} changed the pixels", level);
+	}
+}
+
+#[test]
+fn render_stats_describe_the_render() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let ohlc_of_set = aggregate(&data[..]);
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF))
+		.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF));
+	let (png, stats) = options.render_to_png_with_stats(data.clone()).unwrap();
+
+	assert_eq!(stats.candle_count, data.len());
+	assert_eq!(stats.price_range, (ohlc_of_set.l, ohlc_of_set.h));
+	assert_eq!(stats.bytes, png.len());
+	// The RSI's strip is under the chart
+	assert_eq!((stats.width, stats.height), (1310, 650 + 175));
+	let names: Vec<&str> = stats.extensions.iter().map(|ext| &ext.0[..]).collect();
+	assert_eq!(names, vec!["EMA(20, sf=0.1)", "RSI(10)"]);
+	assert!(stats.total() >= stats.encode + stats.candles);
+
+	let dir = TempDir::new("ohlc_stats").unwrap();
+	let path = dir.path().join("chart.png");
+	let stats = options.render_and_save_with_stats(data, &path).unwrap();
+	assert_eq!(stats.bytes, ::std::fs::metadata(&path).unwrap().len() as usize);
+}
+
+#[test]
+fn render_draw_sample_data_with_stats_summary() {
+	draw_with_options(|options| {
+		options.add_extension(StatsSummary::new(CornerPosition::BottomRight, 0xFFFFFFFF));
+	}, "_with_stats_summary");
+}
+
+#[test]
+fn render_draw_sample_data_with_correlation_overlay() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	// The same closes a candle later, as if following this chart's asset
+	let mut other = data.clone();
+	other.rotate_right(1);
+
+	draw_with_options(move |options| {
+		options.add_extension(CorrelationOverlay::new("ETHUSD", other, CornerPosition::TopRight, 0xFFFFFFFF).max_lag(3));
+	}, "_with_correlation_overlay");
+}
+
+#[test]
+fn correlation_overlay_lines() {
+	let candle = |c| OHLC { o: c, h: c, l: c, c, t: None, v: None };
+	let data = vec![candle(1.), candle(3.), candle(2.), candle(4.)];
+	// Longer than the chart, lined up by the last candles
+	let other = vec![candle(9.), candle(2.), candle(6.), candle(4.), candle(8.)];
+
+	let overlay = CorrelationOverlay::new("Other", other, CornerPosition::TopLeft, 0xFFFFFFFF);
+	assert_eq!(overlay.lines(&data, &NumberLocale::default()), vec!["Correlation with Other: +1.00"]);
+	assert_eq!(overlay.clone().max_lag(1).lines(&data, &NumberLocale::default()), vec!["Correlation with Other: +1.00", "Strongest at lag +0: +1.00"]);
+	assert_eq!(overlay.lines(&[candle(1.)], &NumberLocale::default()), vec!["Correlation with Other: n/a"]);
+}
+
+#[test]
+fn stats_summary_lines() {
+	let data = vec![
+		OHLC { o: 100., h: 104.5, l: 99., c: 102., t: None, v: None },
+		OHLC { o: 102., h: 103., l: 95.25, c: 96.9, t: None, v: None },
+		OHLC { o: 96.9, h: 111., l: 96., c: 110.5, t: None, v: None },
+	];
+
+	// Returns of -5% and +14.035%
+	assert_eq!(StatsSummary::new(CornerPosition::TopLeft, 0).lines(&data, &NumberLocale::default()), vec![
+		"Open: 100.00", "High: 111.00", "Low: 95.25", "Close: 110.50", "Change: +10.50%", "Volatility: 13.46%",
+	]);
+}
+
+#[test]
+fn render_rejects_invalid_data() {
+	let data = vec![
+		OHLC { o: 1., h: 2., l: 0., c: 1., t: None, v: None },
+		OHLC { o: 2.5, h: 1.5, l: 0., c: 1., t: None, v: None },
+	];
+
+	let err = OHLCRenderOptions::new().render_to_image(data).err().unwrap();
+
+	assert_eq!(err, OHLCError::InvalidCandle(InvalidCandle { index: 1, open: 2.5, high: 1.5, low: 0., close: 1., reason: "Opening value is higher than high value." }));
+	assert_eq!(err.to_string(), "Data validation error: Opening value is higher than high value. Candle 1 has open 2.5, high 1.5, low 0 and close 1.");
+}
+
+#[test]
+fn ohlc_builder_validates() {
+	let candle = OHLC::builder().open(1.).high(3.).low(0.5).close(2.).volume(10.).timestamp(1_500_000_000).build().unwrap();
+
+	assert_eq!(candle, OHLC { o: 1., h: 3., l: 0.5, c: 2., t: Some(1_500_000_000), v: Some(10.) });
+	assert_eq!(OHLC::new(1., 3., 0.5, 2.).unwrap(), OHLC { o: 1., h: 3., l: 0.5, c: 2., t: None, v: None });
+
+	assert_eq!(OHLC::new(1., 3., 0.5, 4.).err().unwrap(), OHLCError::Validation("Closing value is higher than high value.".to_string()));
+	assert_eq!(OHLC::new(1., 3., 0.5, f64::NAN).err().unwrap(), OHLCError::Validation("Data contains a price that isn't a finite number.".to_string()));
+	assert_eq!(OHLC::builder().open(1.).high(3.).close(2.).build().err().unwrap(), OHLCError::Validation("Candle is missing some of the open, high, low and close.".to_string()));
+}
+
+#[test]
+fn render_draw_sample_data_with_unicode_title() {
+	draw_with_options(|options| { options.title("₿/€ → 7 630,8 € | Zürich café ½ ± © 🚀", 0x007F7FFF); }, "_with_unicode_title");
+}
+
+#[test]
+fn render_draw_sample_data_with_subtitle() {
+	draw_with_options(|options| { options.subtitle("1h | Bitfinex | 2018-05-01", 0xAAAAAAFF); }, "_with_subtitle");
+}
+
+#[test]
+fn subtitle_renders_below_title() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.title("BTCUSD", 0xFF0000FF).subtitle("1h", 0x0000FFFF);
+	let buffer = options.render_buffer(data).unwrap();
+
+	let rows_of = |rgb: [u8; 3]| (0..buffer.height()).filter(|y| (0..200).any(|x| {
+		let i = (x + y * buffer.width()) * 3;
+		buffer.buffer[i..i + 3] == rgb
+	})).collect::<Vec<_>>();
+	let (title, subtitle) = (rows_of([255, 0, 0]), rows_of([0, 0, 255]));
+
+	assert!(!subtitle.is_empty());
+	assert!(title.last() < subtitle.first());
+	assert!(*subtitle.last().unwrap() < buffer.margin.top);
+}
+
+#[test]
+fn render_draw_sample_data_with_antialiased_candles() {
+	draw_with_options(|options| { options.antialias(true); }, "_with_antialiased_candles");
+}
+
+#[test]
+fn render_draw_sample_data_with_supersampling() {
+	draw_with_options(|options| { options.supersample(2); }, "_with_supersampling");
+}
+
+#[test]
+fn supersampled_render_keeps_its_size() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF));
+	let expected = options.render_buffer(data.clone()).unwrap();
+
+	for factor in 2..4 {
+		let buffer = options.supersample(factor).render_buffer(data.clone()).unwrap();
+
+		assert_eq!((buffer.width(), buffer.height()), (expected.width(), expected.height()), "{}x changed the size", factor);
+		assert_eq!(buffer.margin.top, expected.margin.top);
+	}
+
+	let (_, stats) = options.render_to_png_with_stats(data).unwrap();
+	assert_eq!((stats.width, stats.height), (expected.width() as u32, expected.height() as u32));
+}
+
+#[test]
+fn supersampled_bar_ticks_keep_their_length() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let candle_widths = |factor: u8| {
+		let mut options = OHLCRenderOptions::new();
+		options.candle_style(CandleStyle::OhlcBar, 6).supersample(factor);
+
+		let (_, manifest) = options.render_to_png_with_manifest(data.clone()).unwrap();
+		manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::Candle { bounds, .. } => Some(bounds.right - bounds.left),
+			_ => None,
+		}).collect::<Vec<_>>()
+	};
+
+	for (plain, supersampled) in candle_widths(1).iter().zip(candle_widths(2).iter()) {
+		assert!(plain.max(supersampled) - plain.min(supersampled) <= 1, "{} pixels wide at 1x, {} at 2x", plain, supersampled);
+	}
+}
+
+#[test]
+fn render_draw_sample_data_with_european_numbers() {
+	draw_with_options(|options| {
+		options.number_locale(NumberLocale::european())
+			.add_extension(StatsSummary::new(CornerPosition::BottomRight, 0xFFFFFFFF));
+	}, "_with_european_numbers");
+}
+
+#[test]
+fn max_candles_renders_the_latest_candles() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(5, 0.1, 0xFF0000FF));
+	let expected = options.render_buffer(data[data.len() - 30..].to_vec()).unwrap();
+
+	options.max_candles(30);
+	let truncated = options.render_buffer(data.clone()).unwrap();
+	assert!(truncated.buffer == expected.buffer);
+	assert_eq!(truncated.price_range(), expected.price_range());
+
+	// Candles that are left off are still validated
+	let mut invalid = data.clone();
+	invalid[0].o = invalid[0].h + 1.;
+	assert!(options.render_buffer(invalid).is_err());
+}
+
+#[test]
+fn windows_chart_part_of_the_data() {
+	const START: i64 = 1_500_000_000;
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.start_time(Some(START + 10 * 3600));
+	let expected = options.render_buffer(data[10..40].to_vec()).unwrap();
+
+	// The start time is that of all the data
+	options.start_time(Some(START)).window(10, 40);
+	assert!(options.render_buffer(data.clone()).unwrap().buffer == expected.buffer);
+
+	// Candles starting in the window, placed by the start time or the end of the data
+	options.time_window(START + 9 * 3600 + 1, START + 40 * 3600);
+	assert!(options.render_buffer(data.clone()).unwrap().buffer == expected.buffer);
+	options.start_time(None).reference_time(Some(START + data.len() as i64 * 3600));
+	assert!(options.render_buffer(data.clone()).unwrap().buffer == expected.buffer);
+
+	options.reference_time(None);
+	match options.render_buffer(data.clone()) {
+		Err(OHLCError::Validation(_)) => {}
+		other => panic!("Expected a time window without times to fail, got {:?}", other.map(|buffer| buffer.height())),
+	}
+
+	options.window(40, 10);
+	assert_eq!(options.render_buffer(data.clone()).err(), Some(OHLCError::EmptyData));
+	options.window(data.len(), data.len() + 10);
+	assert_eq!(options.render_buffer(data).err(), Some(OHLCError::EmptyData));
+}
+
+#[test]
+fn warming_up_on_history_draws_indicators_from_earlier_candles() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let ema = EMA::new(20, 0.1, 0xFF0000FF);
+	let history = ema.compute(&data[..150]);
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(ema).window(100, 150);
+
+	let line = |options: &OHLCRenderOptions<OHLC>| {
+		let (_, manifest) = options.render_to_png_with_manifest(data.clone()).unwrap();
+		manifest.elements.into_iter().filter_map(|element| match element {
+			DrawnElement::ExtensionLine { points, .. } => Some(points),
+			_ => None,
+		}).next().expect("EMA wasn't drawn")
+	};
+	let candle_width = (1310 - 12 - 113) as f64 / 50.;
+
+	// The average only starts 20 candles into the window
+	let cold = line(&options);
+	assert!(cold[0].0 as f64 > 12. + 19. * candle_width, "{:?}", cold[0]);
+
+	// From the left edge, at the average of all the candles before it
+	options.warm_up_on_history(true);
+	let warm = line(&options);
+	let buffer = options.render_buffer(data.clone()).unwrap();
+	assert_eq!(warm[0], (12, buffer.data_to_coords(history[100], 0).1));
+	assert_eq!(warm.len(), 50);
+
+	// Enough history makes up for a window too short for the extension's warmup
+	options.window(145, 150);
+	assert!(!line(&options).is_empty());
+}
+
+#[test]
+fn warmed_up_summaries_only_describe_the_window() {
+	let mut data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	// The history is busier than the window, so volumes scaled to it would be shorter
+	for (i, candle) in data.iter_mut().enumerate() {
+		candle.v = Some((200 - i) as f64);
+	}
+	let other: Vec<OHLC> = data.iter().rev().cloned().collect();
+
+	let render = |warm_up: bool| {
+		let mut options = OHLCRenderOptions::new();
+		options.window(80, 100)
+			.warm_up_on_history(warm_up)
+			.add_extension(StatsSummary::new(CornerPosition::TopLeft, 0xFFFFFFFF))
+			.add_extension(CorrelationOverlay::new("Reversed", other.clone(), CornerPosition::BottomRight, 0xFFFFFFFF))
+			.add_extension(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF));
+		options.render_buffer(data.clone()).unwrap().buffer
+	};
+
+	let window_open = format!("Open: {:.2}", data[80].o);
+	assert_eq!(StatsSummary::new(CornerPosition::TopLeft, 0xFFFFFFFF).lines(&data[80..100], &NumberLocale::default())[0], window_open);
+	assert!(render(true) == render(false));
+}
+
+#[test]
+fn extreme_times_are_drawn_while_warming_up() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.window(80, 100)
+		.warm_up_on_history(true)
+		.add_extension(HighlightBox::new(i64::MIN, 90 * 3600, 6000., 7000., 0xFFFFFFFF, 0xFFFFFF40))
+		.add_extension(HighlightBox::new(95 * 3600, i64::MAX, 6000., 7000., 0xFFFFFFFF, 0xFFFFFF40))
+		.add_extension(TrendLine::new((i64::MIN, 6000.), (i64::MAX, 7000.), 0xFF0000FF, true))
+		.add_extension(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF));
+
+	assert!(options.render_buffer(data).is_ok());
+}
+
+#[test]
+fn render_draw_sample_data_with_warmed_up_window() {
+	draw_with_options(|options| {
+		options.add_extension(EMA::new(20, 0.1, 0xFF0000FF))
+			.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF))
+			.window(100, 150)
+			.warm_up_on_history(true);
+	}, "_with_warmed_up_window");
+}
+
+#[test]
+fn render_draw_sample_data_with_price_ticks_only() {
+	draw_with_options(|options| { options.price_line_marks(PriceLineMarks::Ticks { length: 5, colour: 0xCCCCCCFF }); }, "_with_price_ticks_only");
+}
+
+#[test]
+fn render_draw_sample_data_with_whole_price_labels() {
+	draw_with_options(|options| { options.price_decimal_places(Some(0)); }, "_with_whole_price_labels");
+}
+
+#[cfg(feature = "ttf")]
+#[test]
+fn render_draw_sample_data_with_truetype_font() {
+	let bytes = match ::std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") {
+		Ok(bytes) => bytes,
+		Err(_) => return,
+	};
+
+	draw_with_options(|options| {
+		options.title("BTC/USD 1h", 0x007F7FFF)
+			.subtitle("Bitfinex | 2018-05-01", 0xAAAAAAFF)
+			.font_bytes(bytes, 18.)
+			.add_extension(EMA::new(20, 0.1, 0xFF7F00FF));
+	}, "_with_truetype_font");
+}
+
+#[cfg(feature = "ttf")]
+#[test]
+fn fonts_too_big_for_the_chart_fail_to_render() {
+	let bytes = match ::std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") {
+		Ok(bytes) => bytes,
+		Err(_) => return,
+	};
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.subtitle("Bitfinex", 0xAAAAAAFF)
+		.font_bytes(bytes, 400.);
+
+	match options.render_buffer(data) {
+		Err(OHLCError::Layout(_)) => {}
+		other => panic!("Expected no room for the plot, got {:?}", other.map(|buffer| buffer.plot_area())),
+	}
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn parquet_round_trip() {
+	let mut data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	for (i, candle) in data.iter_mut().enumerate() {
+		candle.t = Some(1_500_000_000 + i as i64 * 3600);
+		// Leave some volumes out to check the nulls
+		candle.v = if i % 3 == 0 { None } else { Some(i as f64 * 1.5) };
+	}
+	data[1].t = None;
+
+	let dir = TempDir::new("ohlc_parquet").unwrap();
+	let path = dir.path().join("candles.parquet");
+
+	write_parquet(&data, &path).unwrap();
+	assert_eq!(read_parquet(&path).unwrap(), data);
+}
+
+#[test]
+fn render_draw_sample_data_with_candle_colour_fn() {
+	draw_with_options(|options| {
+		options.candle_colour_fn(|candle: &OHLC, i| {
+			let body = if candle.c > candle.o { 0x27A819FF } else { 0xD33040FF };
+			(if i % 2 == 0 { body } else { body & 0xFFFFFF7F }, 0xCCCCCCFF)
+		});
+	}, "_with_candle_colour_fn");
+}
+
+#[test]
+fn render_draw_sample_data_with_price_ticks() {
+	draw_with_options(|options| {
+		// Every 250, as if they were option strikes
+		options.price_ticks_fn(|low, high| {
+			((low / 250.).ceil() as i64..(high / 250.).floor() as i64 + 1).map(|strike| strike as f64 * 250.).collect()
+		});
+	}, "_with_price_ticks");
+}
+
+#[test]
+fn candle_colour_fn_colours_bodies_and_wicks() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.candle_colour_fn(|_: &OHLC, _| (0x0000FFFF, 0xFF00FFFF));
+
+	let buffer = options.render_buffer(data.clone()).unwrap();
+	let colours: Vec<&[u8]> = buffer.buffer.chunks(3).collect();
+
+	assert!(colours.iter().any(|rgb| *rgb == [0, 0, 255]));
+	assert!(colours.iter().any(|rgb| *rgb == [255, 0, 255]));
+}
+
+#[test]
+fn render_draw_sample_data_with_dense_time_lines() {
+	draw_with_options(|options| { options.line(0xCCCCCCFF, 200., 2); }, "_with_dense_time_lines");
+}
+
+#[test]
+fn bold_text_can_be_disabled() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.title("BTCUSD", 0x007F7FFF);
+	let bold = options.render_buffer(data.clone()).unwrap();
+
+	options.bold_text(false);
+	let regular = options.render_buffer(data).unwrap();
+
+	assert!(bold.buffer != regular.buffer);
+}
+
+#[test]
+fn render_draw_sample_data_with_gradient_candles() {
+	draw_with_options(|options| {
+		options.no_candles()
+			.add_extension(GradientCandles::new(0x27A819FF, 0xD33040FF, 0.8));
+	}, "_with_gradient_candles");
+}
+
+
+fn zero_crossing_data() -> Vec<OHLC> {
+	(0..48).map(|i| {
+		let o = 4.5 * (i as f64 / 6.).sin();
+		let c = 4.5 * ((i + 1) as f64 / 6.).sin();
+		OHLC { o, h: o.max(c) + 0.5, l: o.min(c) - 0.5, c, t: None, v: None }
+	}).collect()
+}
+
+#[test]
+fn render_draw_zero_crossing_data() {
+	let mut options = OHLCRenderOptions::new();
+	options.title("Spread | ohlc-rs", 0x007F7FFF)
+		.line(0xCCCCCCFF, 1., 12)
+		.background_colour(0x36393EFF);
+
+	options.render_and_save(zero_crossing_data(), Path::new("test-draw-zero-crossing-data.png")).unwrap();
+}
+
+#[test]
+fn zero_line_is_emphasised_when_prices_cross_zero() {
+	let mut options = OHLCRenderOptions::new();
+	options.line(0x0000FF7F, 1., 12);
+
+	let buffer = options.render_buffer(zero_crossing_data()).unwrap();
+	assert!(buffer.min_price < -4.5 && buffer.max_price > 4.5);
+
+	let (left, y) = buffer.data_to_coords(0., 0);
+	let (right, _) = buffer.data_to_coords(0., buffer.timeframe);
+	let opaque = |y: usize| (left..right).filter(|x| {
+		let i = (x + y * buffer.width()) * 3;
+		buffer.buffer[i..i + 3] == [0, 0, 255]
+	}).count();
+
+	// Candles cover some of the line, but most of both rows are in the opaque grid colour
+	assert!(opaque(y) > (right - left) / 2);
+	assert!(opaque(y + 1) > (right - left) / 2);
+}
+
+#[test]
+fn render_draw_sample_data_with_wick_caps() {
+	draw_with_options(|options| { options.wick_cap(WickCap::Tick, 7); }, "_with_wick_caps");
+}
+
+#[test]
+fn render_draw_sample_data_with_upper_wicks_only() {
+	draw_with_options(|options| { options.wick_style(WickStyle::UpperOnly); }, "_with_upper_wicks_only");
+}
+
+#[test]
+fn render_draw_sample_data_with_price_alerts() {
+	draw_with_options(|options| {
+		options.add_extension(PriceAlert::new(6900., "Take profit", 0x27A819FF, LineStyle::Dashed))
+			.add_extension(PriceAlert::new(6350., "Stop loss", 0xD33040FF, LineStyle::Solid));
+	}, "_with_price_alerts");
+}
+
+#[test]
+fn extensions_are_clipped_to_the_plot_area() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let at = |buffer: &ChartBuffer, x: usize, y: usize| {
+		let i = (x + y * buffer.width()) * 3;
+		[buffer.buffer[i], buffer.buffer[i + 1], buffer.buffer[i + 2]]
+	};
+
+	let mut options = OHLCRenderOptions::new();
+	let plain = options.render_buffer(data.clone()).unwrap();
+
+	options.add_extension(TestFill { _c: PhantomData, colour: 0xFF00FFFF });
+	let clipped = options.render_buffer(data.clone()).unwrap();
+	let plot_area = clipped.plot_area();
+
+	for y in 0..clipped.height() {
+		for x in 0..clipped.width() {
+			if !plot_area.contains(x, y) {
+				assert_eq!(at(&clipped, x, y), at(&plain, x, y));
+			}
+		}
+	}
+	assert_eq!(at(&clipped, 100, 100), [255, 0, 255]);
+
+	options.clip_extensions(false);
+	assert_eq!(at(&options.render_buffer(data).unwrap(), 0, 0), [255, 0, 255]);
+}
+
+#[test]
+fn render_draw_sample_data_as_ohlc_bars() {
+	draw_with_options(|options| { options.candle_style(CandleStyle::OhlcBar, 2); }, "_as_ohlc_bars");
+}
+
+#[test]
+fn render_draw_sample_data_with_trend_lines() {
+	// The sample data is hourly
+	draw_with_options(|options| {
+		options.add_extension(TrendLine::new((20 * 3600, 6200.), (80 * 3600, 7400.), 0xFFFF00FF, true).channel(Some(600.)))
+			.add_extension(TrendLine::new((100 * 3600, 8000.), (160 * 3600, 7600.), 0x00FFFFFF, false));
+	}, "_with_trend_lines");
+}
+
+#[test]
+fn render_draw_sample_data_with_highlight_box() {
+	draw_with_options(|options| {
+		options.add_extension(HighlightBox::new(40 * 3600, 70 * 3600, 6500., 6900., 0xFFCC00FF, 0xFFCC0030));
+	}, "_with_highlight_box");
+}
+
+#[test]
+fn render_draw_sample_data_with_annotations() {
+	draw_with_options(|options| {
+		options.add_extension(Annotation::new((54 * 3600, 6470.), "Local low", 0xFFFFFFFF, ArrowDirection::Up))
+			.add_extension(Annotation::new((130 * 3600, 8050.), "Rejected at 8000", 0xFFCC00FF, ArrowDirection::Down))
+			// Near the right edge, the text is pushed back into the plot
+			.add_extension(Annotation::new((167 * 3600, 7550.), "Latest", 0x00FFFFFF, ArrowDirection::Up));
+	}, "_with_annotations");
+}
+
+#[test]
+fn render_draw_sample_data_with_arrows() {
+	draw_with_options(|options| {
+		options.add_extension(ArrowAnnotation::new(30, ArrowDirection::Up, 0x27A819FF, "Buy"))
+			.add_extension(ArrowAnnotation::new(140, ArrowDirection::Down, 0xD33040FF, "Sell"))
+			.add_extension(ArrowAnnotation::new(100, ArrowDirection::Up, 0xFFFFFFFF, ""));
+	}, "_with_arrows");
+}
+
+#[test]
+fn render_draw_sample_data_as_line() {
+	draw_with_options(|options| { options.candle_style(CandleStyle::Line, 0); }, "_as_line");
+}
+
+#[test]
+fn render_draw_sample_data_as_area() {
+	draw_with_options(|options| {
+		options.candle_style(CandleStyle::Area, 0)
+			.area_fill_colour(0x2E44EA40);
+	}, "_as_area");
+}
+
+#[test]
+fn render_draw_sample_data_as_baseline() {
+	draw_with_options(|options| {
+		options.candle_style(CandleStyle::Baseline, 0)
+			.baseline(6600., 0x27A819FF, 0xD33040FF);
+	}, "_as_baseline");
+}
+
+#[test]
+fn render_rejects_values_that_are_not_finite() {
+	let fields: [fn(&mut OHLC, f64); 5] = [|c, x| c.o = x, |c, x| c.h = x, |c, x| c.l = x, |c, x| c.c = x, |c, x| c.v = Some(x)];
+
+	for (field, set) in fields.iter().enumerate() {
+		for &value in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+			let mut data = vec![OHLC { o: 1., h: 2., l: 0., c: 1., t: None, v: Some(10.) }; 3];
+			set(&mut data[2], value);
+
+			let reason = if field == 4 { "Data contains a volume that isn't a finite number." } else { "Data contains a price that isn't a finite number." };
+			match OHLCRenderOptions::new().render_to_image(data).err() {
+				Some(OHLCError::InvalidCandle(err)) => assert_eq!((err.index, err.reason), (2, reason), "{} in field {}", value, field),
+				err => panic!("Expected {} in field {} to be invalid, got {:?}", value, field, err),
+			}
+		}
+	}
+}
+
+#[test]
+fn lenient_validation_repairs_or_skips_invalid_candles() {
+	let candle = OHLC { o: 1., h: 2., l: 0., c: 1., t: None, v: None };
+	// A close a rounding error above the high, then a candle with a price that can't be repaired
+	let data = vec![candle, OHLC { c: 2.0000001, ..candle }, candle, OHLC { l: f64::NAN, ..candle }, candle];
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(2, 0.1, 0xFF0000FF));
+	match options.render_to_png(data[..3].to_vec()).err() {
+		Some(OHLCError::InvalidCandle(err)) => assert_eq!((err.index, err.reason), (1, "Closing value is higher than high value.")),
+		err => panic!("Expected strict validation to fail, got {:?}", err),
+	}
+
+	options.validation_mode(ValidationMode::Clamp);
+	let (repaired, stats) = options.render_to_png_with_stats(data[..3].to_vec()).unwrap();
+	assert_eq!((stats.repaired, stats.skipped, stats.candle_count), (1, 0, 3));
+	// Drawn the same as if the high had been right, extensions included
+	options.validation_mode(ValidationMode::Strict);
+	assert_eq!(repaired, options.render_to_png(vec![candle, OHLC { h: 2.0000001, c: 2.0000001, ..candle }, candle]).unwrap());
+
+	options.validation_mode(ValidationMode::Clamp);
+	match options.render_to_png(data.clone()).err() {
+		Some(OHLCError::InvalidCandle(err)) => assert_eq!(err.index, 3),
+		err => panic!("Expected a price that isn't finite to fail, got {:?}", err),
+	}
+
+	options.validation_mode(ValidationMode::Skip);
+	let (skipped, stats) = options.render_to_png_with_stats(data).unwrap();
+	assert_eq!((stats.repaired, stats.skipped, stats.candle_count), (0, 2, 3));
+	options.validation_mode(ValidationMode::Strict);
+	assert_eq!(skipped, options.render_to_png(vec![candle; 3]).unwrap());
+
+	options.validation_mode(ValidationMode::Skip);
+	match options.render_to_png(vec![OHLC { o: 3., ..candle }]).err() {
+		Some(OHLCError::EmptyData) => {}
+		err => panic!("Expected no candles left to fail, got {:?}", err),
+	}
+}
+
+#[test]
+fn render_draw_sample_data_with_rsi_divergence() {
+	draw_with_options(|options| {
+		options.add_extension(RSIDivergenceAnnotations::new(3, 0x27A819FF, 0xD33040FF))
+			.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF));
+	}, "_with_rsi_divergence");
+}
+
+#[test]
+fn manifest_records_where_things_are_drawn() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF));
+
+	let (png, manifest) = options.render_to_png_with_manifest(data.clone()).unwrap();
+	assert_eq!(png, options.render_to_png(data.clone()).unwrap());
+
+	let candles: Vec<(usize, Rect)> = manifest.elements.iter().filter_map(|element| match *element {
+		DrawnElement::Candle { index, bounds, .. } => Some((index, bounds)),
+		_ => None,
+	}).collect();
+	assert_eq!(candles.iter().map(|&(index, _)| index).collect::<Vec<_>>(), (0..data.len()).collect::<Vec<_>>());
+
+	// The candle's pixels are inside its bounds and hit-test back to it
+	let image = options.render_to_image(data.clone()).unwrap();
+	let (index, bounds) = candles[100];
+	let middle = ((bounds.left + bounds.right) / 2, (bounds.top + bounds.bottom) / 2);
+	assert_eq!(manifest.candle_at(middle.0, middle.1), Some(index));
+	assert_eq!(image.get_pixel(middle.0 as u32, bounds.top as u32).data[..3], [0x27, 0xA8, 0x19][..]);
+	assert_eq!(manifest.candle_at(0, 0), None);
+
+	assert!(manifest.elements.iter().any(|element| match *element {
+		DrawnElement::PriceLabel { ref text, price, .. } => text == "7000.00" && price == 7000.,
+		_ => false,
+	}));
+	assert!(manifest.elements.iter().any(|element| match *element {
+		DrawnElement::ExtensionLine { ref extension, ref points } => extension == "EMA(20, sf=0.1)" && points.len() == data.len() - 20,
+		_ => false,
+	}));
+
+	// Scaled down with the chart when supersampling
+	options.supersample(2);
+	let (_, supersampled) = options.render_to_png_with_manifest(data).unwrap();
+	assert!(supersampled.elements.iter().all(|element| element.bounds().right < 1310 && element.bounds().bottom < 650));
+
+	let json = self::serde_json::to_string(&manifest).unwrap();
+	assert_eq!(self::serde_json::from_str::<RenderManifest>(&json).unwrap(), manifest);
+}
+
+#[test]
+fn empty_data_fails_before_rendering() {
+	let mut options: OHLCRenderOptions<OHLC> = OHLCRenderOptions::new();
+	options.add_extension(RSI::new(0xCCCCCCFF, 0xFFFF007F, 0x27A819FF, 0xD33040FF));
+
+	assert_eq!(options.render_to_png(vec![]).err(), Some(OHLCError::EmptyData));
+	assert_eq!(Renderer::new(options).render(&[]).err(), Some(OHLCError::EmptyData));
+}
+
+#[test]
+fn neighbouring_time_labels_do_not_overlap() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	for &interval in &[1, 2, 5, 24] {
+		let mut options = OHLCRenderOptions::new();
+		options.line(0xCCCCCCFF, 200., interval);
+
+		let (_, manifest) = options.render_to_png_with_manifest(data.clone()).unwrap();
+		let mut labels: Vec<Rect> = manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::TimeLabel { bounds, .. } => Some(bounds),
+			_ => None,
+		}).collect();
+		labels.sort_by_key(|bounds| bounds.left);
+
+		assert!(labels.len() > 1, "{} labels every {} candles", labels.len(), interval);
+		for pair in labels.windows(2) {
+			assert!(pair[0].right < pair[1].left, "Labels every {} candles overlap: {:?}", interval, pair);
+		}
+	}
+}
+
+#[test]
+fn single_candles_are_drawn_narrow_with_price_lines() {
+	let candle = OHLC { o: 6600., h: 6700., l: 6500., c: 6650., t: None, v: None };
+	let mut options = OHLCRenderOptions::new();
+	options.line(0xFFFFFFAA, 50., 12);
+
+	let (_, manifest) = options.render_to_png_with_manifest(vec![candle]).unwrap();
+	let bounds = manifest.elements.iter().filter_map(|element| match *element {
+		DrawnElement::Candle { bounds, .. } => Some(bounds),
+		_ => None,
+	}).next().expect("candle wasn't drawn");
+	assert!(bounds.right - bounds.left <= 40, "{:?}", bounds);
+	// In the middle of the chart
+	let (middle, centre) = ((bounds.left + bounds.right) / 2, 12 + (1310 - 12 - 113) / 2);
+	assert!(middle.max(centre) - middle.min(centre) <= 1, "{:?}", bounds);
+
+	// A flat candle is opened up by half a percent either way, so its own price line is still drawn
+	let flat = OHLC { o: 6600., h: 6600., l: 6600., c: 6600., t: None, v: None };
+	let (_, manifest) = options.render_to_png_with_manifest(vec![flat]).unwrap();
+	let prices: Vec<f64> = manifest.elements.iter().filter_map(|element| match *element {
+		DrawnElement::PriceLabel { price, .. } => Some(price),
+		_ => None,
+	}).collect();
+	assert_eq!(prices, vec![6600.]);
+}
+
+#[test]
+fn flat_data_is_drawn_across_the_middle() {
+	let mut options = OHLCRenderOptions::new();
+	options.line(0xFFFFFFAA, 0.001, 3);
+
+	for &price in &[1., 0., -40.] {
+		let data = vec![OHLC { o: price, h: price, l: price, c: price, t: None, v: None }; 100];
+		let (_, manifest) = options.render_to_png_with_manifest(data).unwrap();
+
+		let middle = (60 + 650 - 35) / 2;
+		let candles: Vec<Rect> = manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::Candle { bounds, .. } => Some(bounds),
+			_ => None,
+		}).collect();
+		assert_eq!(candles.len(), 100);
+		for bounds in &candles {
+			assert!(bounds.top.max(middle) - bounds.top.min(middle) <= 1 && bounds.bottom - bounds.top <= 1, "{} at {:?}", price, bounds);
+		}
+
+		let prices: Vec<f64> = manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::PriceLabel { price, .. } => Some(price),
+			_ => None,
+		}).collect();
+		assert!(!prices.is_empty(), "no price lines about {}", price);
+	}
+}
+
+#[test]
+fn prices_across_zero_are_labelled_within_the_chart() {
+	// A spread from -50 to +50, then the same shape in prices that need every decimal place
+	let spread: Vec<OHLC> = (0..=50).map(|i| {
+		let (o, c) = (i as f64 * 2. - 50., (i as f64 * 2. - 48.).min(50.));
+		OHLC { o, h: c, l: o, c, t: None, v: None }
+	}).collect();
+	let wavy: Vec<OHLC> = (0..60).map(|i| {
+		let (o, c) = (51. * (i as f64 / 9.).sin(), 51. * ((i + 1) as f64 / 9.).sin());
+		OHLC { o, h: o.max(c), l: o.min(c), c, t: None, v: None }
+	}).collect();
+
+	let steps = |from: i32| (from..=5).map(|step| step as f64 * 10.).collect::<Vec<f64>>();
+
+	for (data, expected) in &[(spread, steps(-4)), (wavy, steps(-5))] {
+		let mut options = OHLCRenderOptions::new();
+		options.line(0xCCCCCCFF, 10., 12);
+
+		let (_, manifest) = options.render_to_png_with_manifest(data.clone()).unwrap();
+		let labels: Vec<(f64, String, Rect)> = manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::PriceLabel { price, ref text, bounds } => Some((price, text.clone(), bounds)),
+			_ => None,
+		}).collect();
+
+		// Stepped from zero rather than from the lowest price
+		let prices: Vec<f64> = labels.iter().map(|&(price, _, _)| price).collect();
+		assert_eq!(prices, *expected);
+
+		for &(price, ref text, bounds) in &labels {
+			assert_eq!(text.starts_with('-'), price < 0., "{}", text);
+			assert!(bounds.left > 1310 - 113 && bounds.right < 1310, "{} at {:?}", text, bounds);
+		}
+	}
+}
+
+#[test]
+fn price_labels_can_take_their_places_from_the_interval() {
+	// A forex pair with prices to 8 places
+	let data: Vec<OHLC> = (0..60).map(|i| {
+		let (o, c) = (1.1 + 0.005 * (i as f64 / 9.).sin(), 1.1 + 0.005 * ((i + 1) as f64 / 9.).sin());
+		OHLC { o, h: o.max(c), l: o.min(c), c, t: None, v: None }
+	}).collect();
+
+	let label_places = |auto_decimals: AutoDecimals, places: Option<u8>| {
+		let mut options = OHLCRenderOptions::new();
+		options.line(0xCCCCCCFF, 0.001, 12)
+			.auto_decimals(auto_decimals)
+			.price_decimal_places(places);
+
+		let (_, manifest) = options.render_to_png_with_manifest(data.clone()).unwrap();
+		let places: Vec<usize> = manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::PriceLabel { ref text, .. } => Some(text.len() - text.find('.').unwrap() - 1),
+			_ => None,
+		}).collect();
+		assert!(!places.is_empty());
+
+		places
+	};
+
+	assert!(label_places(AutoDecimals::Data, None).iter().all(|places| *places > 5));
+	assert!(label_places(AutoDecimals::Interval, None).iter().all(|places| *places == 5));
+	// Places that are set win
+	assert!(label_places(AutoDecimals::Interval, Some(2)).iter().all(|places| *places == 2));
+}
+
+#[test]
+fn candles_are_evenly_spaced() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	// None of them divide the width of the plot evenly
+	for &candles in &[data.len(), 100, 37] {
+		let (_, manifest) = OHLCRenderOptions::new().render_to_png_with_manifest(data[..candles].to_vec()).unwrap();
+		let bounds: Vec<Rect> = manifest.elements.iter().filter_map(|element| match *element {
+			DrawnElement::Candle { bounds, .. } => Some(bounds),
+			_ => None,
+		}).collect();
+
+		let widths: Vec<usize> = bounds.iter().map(|bounds| bounds.right - bounds.left).collect();
+		let gaps: Vec<usize> = bounds.windows(2).map(|pair| pair[1].left - pair[0].right).collect();
+
+		assert!(widths.iter().all(|width| *width == widths[0]), "{} candles are {:?} wide", candles, widths);
+		assert!(gaps.iter().max().unwrap() - gaps.iter().min().unwrap() <= 1, "{} candles have gaps of {:?}", candles, gaps);
+	}
+}
+
+#[test]
+fn render_draw_sample_data_with_sd_bands() {
+	draw_with_extension(Some(StandardDeviationBands::new(&[0., 1., 2.], 0xFF7F00FF)), "_with_sd_bands");
+}
+
+#[test]
+fn sd_bands_are_spaced_by_the_standard_deviation() {
+	// Closes of 2, 4, 6 have a mean of 4 and a sample standard deviation of 2
+	let data: Vec<OHLC> = [2., 4., 6.].iter().map(|&c| OHLC { o: c, h: c, l: c, c, t: None, v: None }).collect();
+
+	let levels = StandardDeviationBands::new(&[0., 1.5], 0).compute(&data);
+
+	assert_eq!(levels, vec![4., 4., 7., 1.]);
+}
+
+#[test]
+fn indicators_compute_their_series() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let ema = EMA::new(20, 0.1, 0).compute(&data);
+	assert_eq!(ema.len(), data.len());
+	assert_eq!(DEMA::new(EMA::new(20, 0.1, 0)).compute(&data).len(), data.len());
+
+	let macd = MACD::new(0, 0, 0, 0, 0.1).compute(&data);
+	for i in 0..data.len() {
+		assert_eq!(macd.histogram[i], macd.divergence[i] - macd.signal[i]);
+	}
+
+	let rsi = RSI::new(0, 0, 0, 0).compute(&data);
+	assert_eq!(rsi.len(), data.len() - 10);
+	assert!(rsi.iter().all(|rsi| *rsi >= 0. && *rsi <= 100.));
+
+	assert_eq!(Momentum::new(5, 0, 0).compute(&data), calculate_momentum(&data, 5));
+}
+
+#[test]
+fn render_draw_sample_data_with_plot_background() {
+	draw_with_options(|options| { options.plot_background_colour(0x2A2D31FF); }, "_with_plot_background");
+}
+
+#[test]
+fn plot_background_only_fills_the_plot_area() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.background_colour(0x000000FF)
+		.plot_background_colour(0x0000FFFF)
+		.line(0xCCCCCCFF, 200., 24);
+
+	let buffer = options.render_buffer(data).unwrap();
+	let plot = buffer.plot_area();
+	let at = |x: usize, y: usize| {
+		let i = (x + y * buffer.width()) * 3;
+		[buffer.buffer[i], buffer.buffer[i + 1], buffer.buffer[i + 2]]
+	};
+
+	assert_eq!(at(plot.left + 2, plot.top + 10), [0, 0, 255]);
+	assert_eq!(at(plot.left - 1, plot.top - 1), [0, 0, 0]);
+	assert_eq!(at(buffer.width() - 1, buffer.height() - 1), [0, 0, 0]);
+}
+
+#[test]
+fn render_draw_sample_data_with_momentum() {
+	draw_with_extension(Some(Momentum::new(10, 0x27A819FF, 0xD33040FF)), "_with_momentum");
+}
+
+#[test]
+fn diff_images_bounds_the_changed_pixels() {
+	let a = vec![0u8; 4 * 3 * 3];
+	let mut b = a.clone();
+	b[(1 + 4) * 3] = 10;
+	b[(3 + 2 * 4) * 3 + 2] = 2;
+
+	let diff = testing::diff_images(&a, &b, 4, 3);
+	assert_eq!(diff.differing_pixels, 2);
+	assert_eq!(diff.bounds, Some(Rect { left: 1, top: 1, right: 3, bottom: 2 }));
+
+	let within = testing::diff_images_within(&a, &b, 4, 3, 2);
+	assert_eq!(within.differing_pixels, 1);
+	assert!(testing::diff_images(&a, &a, 4, 3).is_identical());
+}
+
+#[test]
+fn render_digest_only_changes_with_the_chart() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	let digest = testing::render_digest(&options, &data).unwrap();
+	assert_eq!(testing::render_digest(&options, &data).unwrap(), digest);
+
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF));
+	assert_ne!(testing::render_digest(&options, &data).unwrap(), digest);
+}
+
+#[test]
+fn renders_with_a_reference_time_are_identical() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.line(0xCCCCCCFF, 200., 24)
+		.reference_time(Some(1_500_000_000));
+
+	let first = options.render_buffer(data.clone()).unwrap();
+	let second = options.render_buffer(data.clone()).unwrap();
+	assert!(first.buffer == second.buffer);
+
+	// The same time lines as starting the chart one timeframe earlier
+	let timeframe = (options.time_units * data.len() as u64) as i64;
+	options.reference_time(None)
+		.start_time(Some(1_500_000_000 - timeframe));
+	assert!(options.render_buffer(data).unwrap().buffer == first.buffer);
+}
+
+#[test]
+fn options_hash_changes_with_how_the_chart_looks() {
+	use std::collections::hash_map::DefaultHasher;
+
+	let hash = |options: &OHLCRenderOptions<OHLC>| {
+		let mut hasher = DefaultHasher::new();
+		options.hash(&mut hasher);
+		hasher.finish()
+	};
+
+	let mut options = OHLCRenderOptions::new();
+	let plain = hash(&options);
+	assert_eq!(hash(&OHLCRenderOptions::new()), plain);
+
+	options.line(0xFFFFFFAA, 2., 24);
+	let lines = hash(&options);
+	assert_ne!(lines, plain);
+
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF));
+	assert_ne!(hash(&options), lines);
+
+	// Extensions of the same name set up differently
+	let with_extension = |add: &dyn Fn(&mut OHLCRenderOptions<OHLC>)| {
+		let mut options = OHLCRenderOptions::new();
+		add(&mut options);
+
+		hash(&options)
+	};
+	assert_ne!(
+		with_extension(&|options| { options.add_extension(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF)); }),
+		with_extension(&|options| { options.add_extension(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF).scale(VolumeScale::Log)); }),
+	);
+	assert_ne!(
+		with_extension(&|options| { options.add_extension(EMA::new(20, 0.1, 0xFF0000FF)); }),
+		with_extension(&|options| { options.add_extension(EMA::new(20, 0.1, 0xFF0000FF).smooth(Some(0.))); }),
+	);
+	assert_ne!(
+		with_extension(&|options| { options.add_extension(EMA::new(20, 0.1, 0xFF0000FF).smooth(Some(0.))); }),
+		with_extension(&|options| { options.add_extension(EMA::new(20, 0.1, 0xFF0000FF).smooth(Some(0.5))); }),
+	);
+	assert_ne!(
+		with_extension(&|options| { options.add_extension(PriceAlert::new(7000., "Target", 0xFF0000FF, LineStyle::Solid)); }),
+		with_extension(&|options| { options.add_extension(PriceAlert::new(7000., "Target", 0x00FF00FF, LineStyle::Solid)); }),
+	);
+}
+
+#[test]
+fn render_into_region_only_draws_inside_the_region() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.background_colour(0x000000FF);
+
+	let (width, height) = (1600, 700);
+	let region = Rect { left: 100, top: 50, right: 1099, bottom: 649 };
+	for volume in &[false, true] {
+		if *volume {
+			options.add_extension(Volume::new(0xCCCCCCFF, 0x27A819FF, 0xD33040FF, 0x7F7F7FFF));
+		}
+
+		let mut canvas = vec![7u8; width * height * 3];
+		let stats = options.render_into_region(data.clone(), &mut canvas, width, height, region).unwrap();
+		// Drawn at the size of the region, strips and all
+		assert_eq!((stats.width, stats.height), (1000, 600));
+
+		let at = |x: usize, y: usize| canvas[(x + y * width) * 3];
+		assert_eq!((at(99, 50), at(1100, 50), at(100, 49), at(100, 650)), (7, 7, 7, 7));
+		assert_eq!((at(100, 50), at(1099, 649)), (0, 0));
+		// The price labels are by the right edge of the region rather than cut off past it
+		let labels = (1000..1099).flat_map(|x| (50..550).map(move |y| (x, y))).filter(|&(x, y)| at(x, y) != 0).count();
+		assert!(labels > 0);
+	}
+
+	let outside = Rect { left: 1000, top: 0, right: 1600, bottom: 10 };
+	let mut canvas = vec![7u8; width * height * 3];
+	assert!(options.render_into_region(data.clone(), &mut canvas, width, height, outside).is_err());
+	// Too short for the volume strip
+	let short = Rect { left: 0, top: 0, right: 999, bottom: 99 };
+	match options.render_into_region(data, &mut canvas, width, height, short) {
+		Err(OHLCError::Layout(_)) => {}
+		other => panic!("Expected the strip not to fit, got {:?}", other.map(|stats| stats.height)),
+	}
+}
+
+#[test]
+fn config_hash_identifies_the_options() {
+	let mut a = OHLCRenderOptions::<OHLC>::new();
+	let mut b = OHLCRenderOptions::<OHLC>::new();
+	a.title("Chart", 0xFFFFFFFF);
+	b.title("Chart", 0xFFFFFFFF);
+	assert_eq!(a.config_hash(), b.config_hash());
+
+	b.price_decimal_places(Some(2));
+	assert_ne!(a.config_hash(), b.config_hash());
+}
+
+#[test]
+fn charts_beyond_the_limits_fail_early() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.limits(RenderLimits { max_candles: 50, max_pixels: MAX_SUPERSAMPLED_PIXELS });
+	match options.render_buffer(data.clone()) {
+		Err(OHLCError::TooLarge(_)) => {}
+		other => panic!("Expected the candle limit to be hit, got {:?}", other.map(|buffer| buffer.height())),
+	}
+
+	// Only the candles rendered count
+	options.max_candles(50);
+	assert!(options.render_buffer(data.clone()).is_ok());
+
+	options.limits(RenderLimits { max_candles: 50_000, max_pixels: 100_000 });
+	assert!(options.render_buffer(data).is_err());
+}
+
+#[test]
+fn long_charts_place_candles_exactly_or_fail_validation() {
+	const WEEK: u64 = 7 * 24 * 3600;
+	let data: Vec<OHLC> = (0..10_000).map(|i| OHLC::new(100., 110., 90., 100. + (i % 5) as f64).unwrap()).collect();
+
+	let mut options = OHLCRenderOptions::new();
+	options.time_units(WEEK)
+		.line(0xCCCCCCFF, 5., 520)
+		.add_extension(BollingerBands::new(20, 2, 0xFF00FFFF))
+		.add_extension(RSI::new(0xFFFFFFFF, 0xFF0000FF, 0x00FF00FF, 0x0000FFFF));
+
+	let buffer = options.render_buffer(data.clone()).unwrap();
+	assert_eq!(buffer.timeframe, (WEEK * 10_000) as i64);
+
+	// The last candle is the last of the weeks across the plot
+	let plot = buffer.plot_area();
+	let (x, _) = buffer.last_candle_coords(&data).unwrap();
+	assert!(x <= plot.right && x + 2 >= plot.right, "{} isn't at the right of {:?}", x, plot);
+
+	options.time_units(u64::MAX / 1000);
+	match options.render_buffer(data) {
+		Err(OHLCError::Validation(_)) => {}
+		other => panic!("Expected the timeframe to overflow, got {:?}", other.map(|buffer| buffer.timeframe)),
+	}
+}
+
+#[test]
+fn price_line_intervals_must_step_up() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	for interval in &[-200., 0., f64::NAN, f64::INFINITY] {
+		let mut options = OHLCRenderOptions::new();
+		options.line(0xCCCCCCFF, *interval, 12);
+
+		match options.render_buffer(data.clone()) {
+			Err(OHLCError::Validation(_)) => {}
+			other => panic!("Expected price lines every {} to fail, got {:?}", interval, other.map(|buffer| buffer.height())),
+		}
+	}
+}
+
+#[test]
+fn time_line_intervals_must_step_forward() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	for &(interval, time_units) in &[(0, 3600), (12, 0)] {
+		let mut options = OHLCRenderOptions::new();
+		options.line(0xCCCCCCFF, 100., interval).time_units(time_units);
+
+		match options.render_buffer(data.clone()) {
+			Err(OHLCError::Validation(_)) => {}
+			other => panic!("Expected time lines every {} candles of {} seconds to fail, got {:?}", interval, time_units, other.map(|buffer| buffer.height())),
+		}
+	}
+}
+
+#[test]
+fn plot_area_needs_a_pixel_inside_the_margins() {
+	let margin = Margin { top: 60, bottom: 35, left: 12, right: 113 };
+
+	assert!(check_plot_area(126, 96, &margin).is_ok());
+	assert_eq!(check_plot_area(125, 96, &margin).map_err(|err| match err { OHLCError::Layout(_) => "layout", _ => "other" }), Err("layout"));
+	assert!(check_plot_area(126, 95, &margin).is_err());
+	assert!(check_plot_area(0, 0, &Margin { top: 0, bottom: 0, left: 0, right: 0 }).is_err());
+}
+
+#[test]
+fn render_draw_sample_data_with_appended_title() {
+	draw_with_options(|options| { options.append_title("USDT", 0x27A819FF).append_title("1h", 0xAAAAAAFF); }, "_with_appended_title");
+}
+
+#[test]
+fn appended_title_parts_follow_the_title() {
+	let data: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+
+	let mut options = OHLCRenderOptions::new();
+	options.title("BTC", 0xFFFFFFFF)
+		.append_title("USDT", 0x00FF00FF)
+		.background_colour(0x000000FF)
+		.bold_text(false);
+	let buffer = options.render_buffer(data.clone()).unwrap();
+
+	let coloured = |rgb: [u8; 3]| (0..buffer.margin.top * buffer.width())
+		.filter(|i| buffer.buffer[i * 3..i * 3 + 3] == rgb)
+		.map(|i| i % buffer.width())
+		.collect::<Vec<_>>();
+	let part_start = 8 + buffer.typeface().measure("BTC / ", 1).0;
+
+	let green = coloured([0, 255, 0]);
+	assert!(!green.is_empty() && green.iter().all(|&x| x >= part_start));
+	assert!(coloured([255, 255, 255]).iter().all(|&x| x < part_start));
+
+	// Setting the title starts it over
+	options.title("ETH", 0xFFFFFFFF);
+	assert!(options.title_parts.is_empty());
+}
+
+#[test]
+fn csv_is_parsed_by_its_header() {
+	let data = parse_csv("Close,volume,High,open,low,timestamp\n2,10,3,1,0.5,1500000000\n\n2.5,,3,2,2,\n").unwrap();
+	assert_eq!(data, vec![
+		OHLC { o: 1., h: 3., l: 0.5, c: 2., t: Some(1_500_000_000), v: Some(10.) },
+		OHLC { o: 2., h: 3., l: 2., c: 2.5, t: None, v: None },
+	]);
+
+	assert_eq!(parse_csv("open,high,low,close\n1,2,x,1\n"), Err(OHLCError::Csv("Line 2 has \"x\" where a number should be".to_string())));
+	assert!(parse_csv("open,high,close\n1,2,1\n").is_err());
+
+	let sample = read_csv(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/sample_data.csv"))).unwrap();
+	let json: Vec<OHLC> = self::serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	assert_eq!(sample.iter().map(|candle| (candle.o, candle.h, candle.l, candle.c)).collect::<Vec<_>>(),
+		json.iter().map(|candle| (candle.o, candle.h, candle.l, candle.c)).collect::<Vec<_>>());
+}
+
+#[test]
+fn render_draw_sample_data_with_hollow_candles() {
+	draw_with_options(|options| { options.hollow_mode(HollowMode::PrevClose); }, "_with_hollow_candles");
+}
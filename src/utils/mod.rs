@@ -1,4 +1,6 @@
 use super::*;
+use model::data::{OHLC, Tick};
+use model::indicators::{correlation, rsi};
 
 #[cfg(test)]
 mod tests;
@@ -44,6 +46,9 @@ pub fn aggregate<C: Candle>(data: &[C]) -> SetAggregate {
 		let high = elem.high();
 		let low = elem.low();
 
+		// One NaN would make every comparison false and spread across the whole chart, candles are validated before they get here
+		debug_assert!(validate_candle(elem).is_ok(), "aggregate of a candle that isn't valid: {}, {}, {}, {}", elem.open(), high, low, elem.close());
+
 		if high > aggregate.h {
 			aggregate.h = high;
 		}
@@ -58,6 +63,314 @@ pub fn aggregate<C: Candle>(data: &[C]) -> SetAggregate {
 	aggregate
 }
 
+/// Groups the trades into candles of `interval_secs`, starting on multiples of it since the epoch
+///
+/// Each candle opens at its first trade and closes at its last, going by timestamp and then the order given, and its volume is the sum of the trades'.
+/// Intervals without any trades are left out rather than filled in, and an interval of 0 seconds gives no candles at all.
+pub fn aggregate_ticks(ticks: &[Tick], interval_secs: u64) -> Vec<OHLC> {
+	if interval_secs == 0 {
+		return vec![];
+	}
+
+	let interval = interval_secs as i64;
+	let mut sorted: Vec<&Tick> = ticks.iter().collect();
+	sorted.sort_by_key(|tick| tick.timestamp);
+
+	let mut candles: Vec<OHLC> = vec![];
+
+	for tick in sorted {
+		let start = tick.timestamp - tick.timestamp.rem_euclid(interval);
+
+		match candles.last_mut() {
+			Some(ref mut candle) if candle.t == Some(start) => {
+				candle.h = candle.h.max(tick.price);
+				candle.l = candle.l.min(tick.price);
+				candle.c = tick.price;
+				candle.v = Some(candle.v.unwrap_or(0.) + tick.volume);
+			}
+			_ => candles.push(OHLC { o: tick.price, h: tick.price, l: tick.price, c: tick.price, t: Some(start), v: Some(tick.volume) }),
+		}
+	}
+
+	candles
+}
+
+/// Change in close price over the periods, `close[n] - close[n - periods]`, which is `None` for the first periods
+pub fn calculate_momentum<C: Candle>(data: &[C], periods: usize) -> Vec<Option<f64>> {
+	(0..data.len())
+		.map(|n| if n >= periods { Some(data[n].close() - data[n - periods].close()) } else { None })
+		.collect()
+}
+
+/// Correlation of the closes of two series of candles at each lag from `-max_lag` to `max_lag`, returns: [(lag, correlation)]
+///
+/// The series are lined up by index. A positive lag compares `a` with `b` that many candles later, so a high correlation there means `a` leads `b`.
+/// Correlations are NaN where the overlap is under 2 candles or either side is flat.
+pub fn cross_correlation<A: Candle, B: Candle>(a: &[A], b: &[B], max_lag: usize) -> Vec<(i32, f64)> {
+	let a: Vec<f64> = a.iter().map(|candle| candle.close()).collect();
+	let b: Vec<f64> = b.iter().map(|candle| candle.close()).collect();
+	let len = a.len().min(b.len());
+
+	(-(max_lag as i32)..max_lag as i32 + 1)
+		.map(|lag| {
+			let shift = (lag.unsigned_abs() as usize).min(len);
+			let (a, b) = if lag >= 0 {
+				(&a[..len - shift], &b[shift..len])
+			} else {
+				(&a[shift..len], &b[..len - shift])
+			};
+
+			(lag, correlation(a, b))
+		})
+		.collect()
+}
+
+/// RSI of each candle over the periods before it, which is `None` for the first periods
+pub fn calculate_rsi<C: Candle>(data: &[C], periods: usize) -> Vec<Option<f64>> {
+	let mut values = vec![None; periods.min(data.len())];
+	values.extend(rsi(data, periods).into_iter().map(Some));
+
+	values
+}
+
+/// Whether a divergence points to the price turning up or down
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DivergenceType {
+	/// Price made a lower low while the RSI made a higher one
+	Bullish,
+	/// Price made a higher high while the RSI made a lower one
+	Bearish,
+}
+
+/// Candles where the price and the RSI disagree, such as a higher high in price with a lower RSI, returns: [(index, type)] in order of index
+///
+/// Peaks are highs above every high `lookback` candles either side and troughs are lows below every such low, so the last `lookback` candles can't have either yet.
+/// Each peak or trough is compared with the one before it, by the RSI at those candles, and peaks or troughs without an RSI are passed over. The RSI values are one for each candle, such as from `calculate_rsi`.
+pub fn detect_rsi_divergence<C: Candle>(data: &[C], rsi_values: &[Option<f64>], lookback: usize) -> Vec<(usize, DivergenceType)> {
+	let lookback = lookback.max(1);
+	let len = data.len().min(rsi_values.len());
+	if len < 2 * lookback + 1 {
+		return vec![];
+	}
+
+	let mut divergences = vec![];
+	let mut last_peak: Option<(f64, f64)> = None;
+	let mut last_trough: Option<(f64, f64)> = None;
+
+	for i in lookback..len - lookback {
+		let rsi = match rsi_values[i] {
+			Some(rsi) => rsi,
+			None => continue,
+		};
+		let around = || (i - lookback..i + lookback + 1).filter(move |j| *j != i).map(|j| &data[j]);
+		let (high, low) = (data[i].high(), data[i].low());
+
+		if around().all(|candle| candle.high() < high) {
+			if let Some((last_high, last_rsi)) = last_peak {
+				if high > last_high && rsi < last_rsi {
+					divergences.push((i, DivergenceType::Bearish));
+				}
+			}
+			last_peak = Some((high, rsi));
+		}
+
+		if around().all(|candle| candle.low() > low) {
+			if let Some((last_low, last_rsi)) = last_trough {
+				if low < last_low && rsi > last_rsi {
+					divergences.push((i, DivergenceType::Bullish));
+				}
+			}
+			last_trough = Some((low, rsi));
+		}
+	}
+
+	divergences
+}
+
+/// Goes through the candles, giving each one with up to `n` of the candles before it
+///
+/// The lookback is always `min(position, n)` candles long, so it's shorter than `n` at the start of the series and empty for the first candle.
+pub struct LookbackIter<'a, C: 'a> {
+	data: &'a [C],
+	n: usize,
+	position: usize,
+}
+
+impl<'a, C> Iterator for LookbackIter<'a, C> {
+	/// The current candle and the candles before it, oldest first
+	type Item = (&'a C, &'a [C]);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let current = self.data.get(self.position)?;
+		let lookback = &self.data[self.position.saturating_sub(self.n)..self.position];
+
+		self.position += 1;
+
+		Some((current, lookback))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.data.len() - self.position;
+
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a, C> ExactSizeIterator for LookbackIter<'a, C> {}
+
+/// Adds `lookback_iter` to slices, and so to `Vec`s, of candles
+pub trait Lookback<C> {
+	/// Each candle with up to `n` of the candles before it, eg. `for (current, history) in data.lookback_iter(20)`
+	fn lookback_iter(&self, n: usize) -> LookbackIter<'_, C>;
+}
+
+impl<C> Lookback<C> for [C] {
+	fn lookback_iter(&self, n: usize) -> LookbackIter<'_, C> {
+		LookbackIter { data: self, n, position: 0 }
+	}
+}
+
+/// Number of decimal places needed to write the value, up to 8
+pub fn decimal_places(value: f64) -> u8 {
+	if !value.is_finite() {
+		return 0;
+	}
+
+	for places in 0..8 {
+		let scaled = value * 10f64.powi(places);
+
+		// Allow for float error, such as 6735.73 * 100 = 673573.0000000001
+		if (scaled - scaled.round()).abs() <= scaled.abs() * 1e-9 {
+			return places as u8;
+		}
+	}
+
+	8
+}
+
+/// Number of decimal places needed to write every price of the data, such as 2 for BTC/USDT or 8 for SHIB/USDT
+pub fn detect_price_precision<C: Candle>(data: &[C]) -> u8 {
+	data.iter()
+		.flat_map(|candle| vec![candle.open(), candle.high(), candle.low(), candle.close()])
+		.map(decimal_places)
+		.max()
+		.unwrap_or(0)
+}
+
+/// How the decimal places of price labels are picked when they aren't set
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AutoDecimals {
+	/// As many as the prices of the data need, such as 2 for BTC/USDT or 8 for SHIB/USDT
+	Data,
+	/// From the order of magnitude of the price line interval, down to a hundredth of it, such as 2 for an interval of 1 or 6 for 0.0001
+	Interval,
+}
+
+impl AutoDecimals {
+	/// Decimal places of labels of the data's prices with price lines the interval apart
+	pub fn places<C: Candle>(&self, data: &[C], interval: f64) -> u8 {
+		match *self {
+			AutoDecimals::Interval if interval.is_finite() && interval > 0. => {
+				// Allow for float error, such as log10(0.001) coming out just under -3
+				let magnitude = (interval.log10() + 1e-9).floor() as i32;
+				(2 - magnitude).clamp(0, 8) as u8
+			}
+			// Without an interval to go by the data is all there is
+			AutoDecimals::Interval | AutoDecimals::Data => detect_price_precision(data),
+		}
+	}
+}
+
+impl Default for AutoDecimals {
+	/// As many as the prices need, what labels have always had
+	fn default() -> AutoDecimals {
+		AutoDecimals::Data
+	}
+}
+
+/// Characters that numbers in labels are written with
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumberLocale {
+	/// Separates the whole number from the decimals
+	pub decimal: char,
+	/// Separates each group of three digits of the whole number, if any
+	pub grouping: Option<char>,
+}
+
+impl NumberLocale {
+	pub fn new(decimal: char, grouping: Option<char>) -> NumberLocale {
+		NumberLocale { decimal, grouping }
+	}
+
+	/// `1.234,56` as written in much of Europe
+	pub fn european() -> NumberLocale {
+		NumberLocale::new(',', Some('.'))
+	}
+
+	/// Writes the value with the decimal places, eg. `format(1234.5, 2)` is `1234.50` by default
+	pub fn format(&self, value: f64, places: usize) -> String {
+		let formatted = format!("{:.*}", places, value);
+		if !value.is_finite() || *self == NumberLocale::default() {
+			return formatted;
+		}
+
+		let (whole, decimals) = match formatted.find('.') {
+			Some(i) => (&formatted[..i], Some(&formatted[i + 1..])),
+			None => (&formatted[..], None),
+		};
+		let (sign, digits) = match whole.strip_prefix('-') {
+			Some(digits) => ("-", digits),
+			None => ("", whole),
+		};
+
+		let mut localised = sign.to_string();
+		for (i, digit) in digits.chars().enumerate() {
+			if let Some(grouping) = self.grouping {
+				if i > 0 && (digits.len() - i) % 3 == 0 {
+					localised.push(grouping);
+				}
+			}
+			localised.push(digit);
+		}
+
+		if let Some(decimals) = decimals {
+			localised.push(self.decimal);
+			localised.push_str(decimals);
+		}
+
+		localised
+	}
+
+	/// Same as `format` with a `+` in front of positive values, for changes
+	pub fn format_signed(&self, value: f64, places: usize) -> String {
+		let formatted = self.format(value, places);
+
+		if value >= 0. { format!("+{}", formatted) } else { formatted }
+	}
+}
+
+impl Default for NumberLocale {
+	/// `1234.56`, what labels have always been written in
+	fn default() -> NumberLocale {
+		NumberLocale::new('.', None)
+	}
+}
+
+/// Source-over composites an RGBA colour onto an opaque RGB pixel, each channel being rounded to the nearest value
+pub fn blend(dst_rgb: [u8; 3], src_rgba: u32) -> [u8; 3] {
+	let alpha = src_rgba & 0xFF;
+	let mut out = dst_rgb;
+
+	for j in 0..3 {
+		let src = (src_rgba >> (24 - 8 * j)) & 0xFF;
+		let dst = dst_rgb[j] as u32;
+
+		out[j] = ((src * alpha + dst * (255 - alpha) + 127) / 255) as u8;
+	}
+
+	out
+}
+
 const LEN_OF_MINUTE: u64 = 60;
 const LEN_OF_HOUR: u64 = 60 * LEN_OF_MINUTE;
 const LEN_OF_DAY: u64 = 24 * LEN_OF_HOUR;
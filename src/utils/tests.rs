@@ -1,3 +1,5 @@
+use model::data::OHLC;
+
 use super::*;
 
 #[test]
@@ -17,3 +19,184 @@ fn duration_test() {
 	assert_eq!(duration_string(365 * 86400), "1y");
 	assert_eq!(duration_string(365 * 86400 + 2592000 + 604800 + 86400 + 3661), "1y1m1w1d1h1m1s");
 }
+
+#[test]
+fn momentum_of_flat_prices_is_zero() {
+	let data: Vec<OHLC> = (0..20).map(|_| OHLC { o: 5., h: 5., l: 5., c: 5., t: None, v: None }).collect();
+	let momentum = calculate_momentum(&data, 4);
+
+	assert_eq!(momentum.len(), 20);
+	assert!(momentum[..4].iter().all(|m| m.is_none()));
+	assert!(momentum[4..].iter().all(|m| *m == Some(0.)));
+}
+
+#[test]
+fn momentum_of_steadily_rising_prices_is_the_periods() {
+	let data: Vec<OHLC> = (0..20).map(|i| i as f64).map(|c| OHLC { o: c, h: c, l: c, c, t: None, v: None }).collect();
+
+	for periods in 1..6 {
+		let momentum = calculate_momentum(&data, periods);
+		assert!(momentum.iter().filter_map(|m| *m).all(|m| m == periods as f64));
+		assert_eq!(momentum.iter().filter(|m| m.is_some()).count(), 20 - periods);
+	}
+}
+
+#[test]
+fn rsi_divergence_is_found_where_price_and_rsi_disagree() {
+	let candles = |lows: &[f64]| -> Vec<OHLC> { lows.iter().map(|&l| OHLC { o: l + 0.5, h: l + 1., l, c: l + 0.5, t: None, v: None }).collect() };
+	let rsi_at = |values: &[(usize, f64)]| -> Vec<Option<f64>> { (0..9).map(|i| values.iter().find(|&&(j, _)| j == i).map(|&(_, rsi)| rsi)).collect() };
+
+	// Peaks at 2 and 6, the second higher
+	let rising = candles(&[4., 5., 9., 5., 4., 5., 11., 5., 4.]);
+	assert_eq!(detect_rsi_divergence(&rising, &rsi_at(&[(2, 70.), (6, 60.)]), 2), vec![(6, DivergenceType::Bearish)]);
+	assert_eq!(detect_rsi_divergence(&rising, &rsi_at(&[(2, 70.), (6, 80.)]), 2), vec![]);
+	// Peaks without an RSI are passed over
+	assert_eq!(detect_rsi_divergence(&rising, &rsi_at(&[(6, 60.)]), 2), vec![]);
+
+	// Troughs at 2 and 6, the second lower
+	let falling = candles(&[15., 14., 10., 14., 15., 14., 8., 14., 15.]);
+	assert_eq!(detect_rsi_divergence(&falling, &rsi_at(&[(2, 30.), (6, 40.)]), 2), vec![(6, DivergenceType::Bullish)]);
+	// Too few candles for a peak with 5 either side
+	assert_eq!(detect_rsi_divergence(&falling, &rsi_at(&[(2, 30.), (6, 40.)]), 5), vec![]);
+}
+
+#[test]
+fn rsi_is_none_before_its_periods() {
+	let data: Vec<OHLC> = (0..20).map(|i| i as f64).map(|o| OHLC { o, h: o + 2., l: o, c: o + 1., t: None, v: None }).collect();
+	let rsi = calculate_rsi(&data, 10);
+
+	assert_eq!(rsi.len(), 20);
+	assert!(rsi[..10].iter().all(|rsi| rsi.is_none()));
+	// Every candle rose
+	assert!(rsi[10..].iter().all(|rsi| *rsi == Some(100.)));
+	assert_eq!(calculate_rsi(&data[..5], 10), vec![None; 5]);
+}
+
+#[test]
+fn blend_test() {
+	// Opaque replaces and fully transparent leaves the pixel alone
+	assert_eq!(blend([0x12, 0x34, 0x56], 0xABCDEFFF), [0xAB, 0xCD, 0xEF]);
+	assert_eq!(blend([0x12, 0x34, 0x56], 0xABCDEF00), [0x12, 0x34, 0x56]);
+
+	// Half red over green, 255 * 128 / 255 = 128 and 255 * 127 / 255 = 127
+	assert_eq!(blend([0x00, 0xFF, 0x00], 0xFF000080), [0x80, 0x7F, 0x00]);
+	// 100 * 64 / 255 + 200 * 191 / 255 = 174.9, which rounds up
+	assert_eq!(blend([200, 200, 200], 0x64646440), [175, 175, 175]);
+	// 50 * 1 / 255 + 10 * 254 / 255 = 10.16, which rounds down
+	assert_eq!(blend([10, 10, 10], 0x32323201), [10, 10, 10]);
+	// Nearly opaque colours are still blended
+	assert_eq!(blend([0, 0, 0], 0xFFFFFFF6), [0xF6, 0xF6, 0xF6]);
+}
+
+#[test]
+fn decimal_places_test() {
+	assert_eq!(decimal_places(6735.), 0);
+	assert_eq!(decimal_places(6735.7), 1);
+	assert_eq!(decimal_places(6735.73), 2);
+	assert_eq!(decimal_places(-0.125), 3);
+	assert_eq!(decimal_places(0.00001234), 8);
+	// Anything finer is cut off at 8 places
+	assert_eq!(decimal_places(1e-12), 8);
+	assert_eq!(decimal_places(f64::NAN), 0);
+}
+
+#[test]
+fn detect_price_precision_test() {
+	let candle = |o, h, l, c| OHLC { o, h, l, c, t: None, v: None };
+
+	assert_eq!(detect_price_precision::<OHLC>(&[]), 0);
+	assert_eq!(detect_price_precision(&[candle(1., 2., 0.5, 1.), candle(1., 1.25, 1., 1.)]), 2);
+	assert_eq!(detect_price_precision(&[candle(0.00001234, 0.0000124, 0.0000122, 0.0000123)]), 8);
+}
+
+#[test]
+fn auto_decimals_test() {
+	let candle = |o, h, l, c| OHLC { o, h, l, c, t: None, v: None };
+	let data = [candle(1.10001234, 1.1002, 1.1, 1.1001)];
+
+	assert_eq!(AutoDecimals::Data.places(&data, 0.001), 8);
+	assert_eq!(AutoDecimals::Interval.places(&data, 0.001), 5);
+	assert_eq!(AutoDecimals::Interval.places(&data, 0.0001), 6);
+	assert_eq!(AutoDecimals::Interval.places(&data, 1.), 2);
+	assert_eq!(AutoDecimals::Interval.places(&data, 200.), 0);
+	assert_eq!(AutoDecimals::Interval.places(&data, 1e-12), 8);
+	// Falls back to the data without an interval
+	assert_eq!(AutoDecimals::Interval.places(&data, f64::INFINITY), 8);
+}
+
+#[test]
+fn aggregate_ticks_into_candles() {
+	let tick = |timestamp, price, volume| Tick { timestamp, price, volume };
+	let ticks = [
+		tick(125, 10., 1.),
+		tick(61, 8., 2.),
+		tick(90, 12., 0.5),
+		tick(119, 11., 1.),
+		tick(120, 9., 3.),
+		// An hour later, nothing in between
+		tick(3601, 7., 1.),
+		tick(3601, 7.5, 1.),
+	];
+
+	assert_eq!(aggregate_ticks(&ticks, 60), vec![
+		OHLC { o: 8., h: 12., l: 8., c: 11., t: Some(60), v: Some(3.5) },
+		OHLC { o: 9., h: 10., l: 9., c: 10., t: Some(120), v: Some(4.) },
+		OHLC { o: 7., h: 7.5, l: 7., c: 7.5, t: Some(3600), v: Some(2.) },
+	]);
+	assert_eq!(aggregate_ticks(&[tick(-30, 1., 1.)], 60)[0].t, Some(-60));
+	assert!(aggregate_ticks(&[], 60).is_empty());
+	assert!(aggregate_ticks(&ticks, 0).is_empty());
+}
+
+#[test]
+fn number_locale_format() {
+	let default = NumberLocale::default();
+	assert_eq!(default.format(1234567.891, 2), "1234567.89");
+	assert_eq!(default.format_signed(3.5, 1), "+3.5");
+
+	let european = NumberLocale::european();
+	assert_eq!(european.format(1234567.891, 2), "1.234.567,89");
+	assert_eq!(european.format(-123456., 0), "-123.456");
+	assert_eq!(european.format(999.5, 1), "999,5");
+	assert_eq!(european.format_signed(-0.25, 2), "-0,25");
+	assert_eq!(european.format_signed(1000., 2), "+1.000,00");
+	assert_eq!(european.format(f64::INFINITY, 2), "inf");
+
+	assert_eq!(NumberLocale::new('.', Some(',')).format(12345.678, 3), "12,345.678");
+}
+
+#[test]
+fn lookback_iter_gives_up_to_n_previous_candles() {
+	let data: Vec<OHLC> = (0..5).map(|i| OHLC { o: i as f64, h: i as f64, l: i as f64, c: i as f64, t: None, v: None }).collect();
+
+	let windows: Vec<(f64, Vec<f64>)> = data.lookback_iter(2)
+		.map(|(current, lookback)| (current.c, lookback.iter().map(|candle| candle.c).collect()))
+		.collect();
+
+	assert_eq!(windows, vec![
+		(0., vec![]),
+		(1., vec![0.]),
+		(2., vec![0., 1.]),
+		(3., vec![1., 2.]),
+		(4., vec![2., 3.]),
+	]);
+	assert_eq!(data.lookback_iter(2).len(), 5);
+}
+
+#[test]
+fn cross_correlation_finds_the_leading_series() {
+	let closes = [1., 4., 2., 8., 5., 7., 3., 6.];
+	let series = |closes: &[f64]| -> Vec<OHLC> { closes.iter().map(|&c| OHLC { o: c, h: c, l: c, c, t: None, v: None }).collect() };
+
+	// b is a's closes two candles later
+	let a = series(&closes[2..]);
+	let b = series(&closes[..6]);
+
+	let correlations = cross_correlation(&a, &b, 2);
+	let lags: Vec<i32> = correlations.iter().map(|&(lag, _)| lag).collect();
+	assert_eq!(lags, vec![-2, -1, 0, 1, 2]);
+	assert!((correlations[4].1 - 1.).abs() < 1e-9, "{:?}", correlations);
+	assert!(correlations[..4].iter().all(|&(_, correlation)| correlation < 1. - 1e-9), "{:?}", correlations);
+
+	assert!(cross_correlation(&a, &b, 10)[0].1.is_nan());
+}
@@ -0,0 +1,56 @@
+extern crate ohlc;
+
+use ohlc::model::data::OHLC;
+use ohlc::model::rex::BollingerBands;
+
+/// Flat candles so the median used by the bands equals the "close" price
+fn flat_candles(prices: &[f64]) -> Vec<OHLC> {
+	prices.iter().map(|p| OHLC { o: *p, h: *p, l: *p, c: *p, t: None, v: None }).collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+	assert!((actual - expected).abs() < 1e-9, "expected {}, got {}", expected, actual);
+}
+
+#[test]
+fn first_band_point_matches_hand_calculation() {
+	let prices: Vec<f64> = (1..26).map(|p| p as f64).collect();
+	let data = flat_candles(&prices);
+
+	let bands = BollingerBands::<OHLC>::new(20, 2, 0).compute(&data);
+
+	assert_eq!(bands.len(), 5);
+
+	// Over 1..=20: mean = 10.5, sample variance = 665 / 19 = 35
+	let std_dev = 35f64.sqrt();
+
+	assert_close(bands[0].median, 10.5);
+	assert_close(bands[0].higher, 10.5 + 2. * std_dev);
+	assert_close(bands[0].lower, 10.5 - 2. * std_dev);
+}
+
+#[test]
+fn bands_slide_with_the_window() {
+	let prices: Vec<f64> = (1..26).map(|p| p as f64).collect();
+	let data = flat_candles(&prices);
+
+	let bands = BollingerBands::<OHLC>::new(20, 2, 0).compute(&data);
+
+	// Shifting an arithmetic sequence moves the mean but keeps the deviation
+	for (i, band) in bands.iter().enumerate() {
+		assert_close(band.median, 10.5 + i as f64);
+		assert_close(band.higher - band.median, bands[0].higher - bands[0].median);
+		assert_close(band.median - band.lower, bands[0].median - bands[0].lower);
+	}
+}
+
+#[test]
+fn constant_prices_collapse_the_bands() {
+	let data = flat_candles(&[42.; 25]);
+
+	for band in BollingerBands::<OHLC>::new(20, 2, 0).compute(&data) {
+		assert_close(band.higher, 42.);
+		assert_close(band.median, 42.);
+		assert_close(band.lower, 42.);
+	}
+}
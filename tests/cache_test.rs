@@ -0,0 +1,32 @@
+#![cfg(feature = "cache")]
+
+extern crate ohlc;
+extern crate serde_json;
+
+use std::sync::{Arc, Mutex};
+
+use ohlc::{OHLCRenderOptions, RenderCache};
+use ohlc::model::data::OHLC;
+use ohlc::model::rex::EMA;
+
+#[test]
+fn repeated_renders_come_from_the_cache() {
+	let data: Vec<OHLC> = serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let cache = Arc::new(Mutex::new(RenderCache::new(2)));
+
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF))
+		.cache(cache.clone());
+
+	let png = options.render_to_png(data.clone()).unwrap();
+	assert_eq!(options.render_to_png(data.clone()).unwrap(), png);
+	assert_eq!(cache.lock().unwrap().len(), 1);
+
+	// Different data and different options are rendered again
+	options.render_to_png(data[..50].to_vec()).unwrap();
+	assert_eq!(cache.lock().unwrap().len(), 2);
+
+	options.title("Cached", 0xFFFFFFFF);
+	assert_ne!(options.render_to_png(data).unwrap(), png);
+	assert_eq!(cache.lock().unwrap().len(), 2);
+}
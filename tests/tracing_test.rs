@@ -0,0 +1,96 @@
+#![cfg(feature = "tracing")]
+
+extern crate ohlc;
+extern crate serde_json;
+extern crate tracing;
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use ohlc::OHLCRenderOptions;
+use ohlc::model::data::OHLC;
+use ohlc::model::rex::EMA;
+use tracing::{Event, Id, Metadata, Subscriber};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Record};
+
+/// Records the name of every span made, with the `name` field of extension spans after a colon, and the fields of every event
+#[derive(Clone, Default)]
+struct SpanNames {
+	names: Arc<Mutex<Vec<String>>>,
+	events: Arc<Mutex<Vec<String>>>,
+}
+
+struct NameField(Option<String>);
+
+impl Visit for NameField {
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		if field.name() == "name" {
+			self.0 = Some(format!("{:?}", value));
+		}
+	}
+}
+
+struct FieldNames(Vec<String>);
+
+impl Visit for FieldNames {
+	fn record_debug(&mut self, field: &Field, _value: &dyn fmt::Debug) {
+		self.0.push(field.name().to_string());
+	}
+}
+
+impl Subscriber for SpanNames {
+	fn enabled(&self, _metadata: &Metadata) -> bool {
+		true
+	}
+
+	fn new_span(&self, span: &Attributes) -> Id {
+		let mut field = NameField(None);
+		span.record(&mut field);
+
+		let mut names = self.names.lock().unwrap();
+		names.push(match field.0 {
+			Some(name) => format!("{}:{}", span.metadata().name(), name),
+			None => span.metadata().name().to_string(),
+		});
+
+		Id::from_u64(names.len() as u64)
+	}
+
+	fn record(&self, _span: &Id, _values: &Record) {}
+
+	fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+	fn event(&self, event: &Event) {
+		let mut fields = FieldNames(vec![]);
+		event.record(&mut fields);
+
+		self.events.lock().unwrap().push(fields.0.join(","));
+	}
+
+	fn enter(&self, _span: &Id) {}
+
+	fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn render_phases_are_traced() {
+	let data: Vec<OHLC> = serde_json::from_str(include_str!("../sample_data.json")).unwrap();
+	let mut options = OHLCRenderOptions::new();
+	options.add_extension(EMA::new(20, 0.1, 0xFF0000FF));
+
+	let subscriber = SpanNames::default();
+	tracing::subscriber::with_default(subscriber.clone(), || {
+		options.render_to_png(data).unwrap();
+	});
+
+	let names = subscriber.names.lock().unwrap();
+	assert_eq!(*names, vec![
+		"render", "validation", "background", "grid", "candles", "labels", "extensions",
+		"extension:EMA(20, sf=0.1)", "finishing", "encode",
+	]);
+
+	// The total render time, for a histogram
+	let events = subscriber.events.lock().unwrap();
+	assert!(events.iter().any(|fields| fields.contains("render_duration_us")), "{:?}", events);
+}